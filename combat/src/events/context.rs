@@ -1,6 +1,8 @@
 use derive_more::{Add, AddAssign, AsRef, Display, From, Mul, MulAssign, Sub, SubAssign};
 use models::hardness::Hardness;
 
+use crate::status::StatusKind;
+
 /// Percent expressed in hundredths (0..=10_000 means 0%..=100%).
 ///
 /// Stored as `u16` so a percentage value like 75% is `ImpactRatio(7_500)`.
@@ -36,11 +38,19 @@ pub enum DamageContext {
     /// Standard physical hit. `hardness_reduction` is the amount of damage
     /// already absorbed by the source's `Hardness` before the event was
     /// emitted (informational; further mitigation can be applied via
-    /// `DamageModifier`).
+    /// `DamageModifiers`).
     Physical { hardness_reduction: Hardness },
     /// Damage caused by impact / falling. `impact_ratio` records how
     /// severe the fall was (used by some `DamageModifier`s for scaling).
     Fall { impact_ratio: ImpactRatio },
+    /// A tick of damage-over-time from a status effect (disease, poison,
+    /// burning). Emitted by `status::tick_status_effects`.
+    Status { kind: StatusKind },
+    /// Non-lethal cleansing. A target brought to zero by it is still
+    /// reported through `DeathOccurred`, but observers that know how to
+    /// restore it (e.g. diseased creatures turning back into wildlife)
+    /// replace it instead of treating it as a kill.
+    Purify,
     /// Catch-all for bespoke damage sources (scripts, traps, environment).
     Custom,
 }
@@ -0,0 +1,24 @@
+use bevy::prelude::{Entity, Message};
+use derive_more::Display;
+use models::health::Health;
+
+/// Restore `amount` health to `target`. The counterpart of `DamageEvent`:
+/// `apply_heal` clamps the result to the target's `MaxHealth` and ignores
+/// targets that are already dead.
+#[derive(Message, Clone, Copy, Debug, Display)]
+#[display("{source} healed {target} for {amount}")]
+pub struct HealEvent {
+    pub target: Entity,
+    pub amount: Health,
+    pub source: Entity,
+}
+
+impl HealEvent {
+    pub fn new(target: Entity, amount: Health, source: Entity) -> Self {
+        Self {
+            target,
+            amount,
+            source,
+        }
+    }
+}
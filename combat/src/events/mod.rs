@@ -1,3 +1,4 @@
 pub mod context;
 pub mod damage;
 pub mod death;
+pub mod heal;
@@ -1,10 +1,19 @@
 pub mod events;
 pub mod modifier;
 pub mod plugin;
+pub mod projectile;
+pub mod reaction;
+pub mod regen;
+pub mod status;
 mod systems;
 
 pub use events::context::{DamageContext, ImpactRatio};
 pub use events::damage::{DamageEvent, DeathEvent};
 pub use events::death::DeathOccurred;
-pub use modifier::{DamageModifier, ModifyDamage, ResistPct, MAX_RESIST_PCT};
+pub use events::heal::HealEvent;
+pub use modifier::{DamageModifier, DamageModifiers, ModifyDamage, ResistPct, MAX_RESIST_PCT};
 pub use plugin::CombatPlugin;
+pub use projectile::{Hurtbox, Projectile, Team};
+pub use reaction::{HitFlash, HitResponse, HitStun, Invulnerable, Knockback};
+pub use regen::Regeneration;
+pub use status::{ApplyStatus, Stacking, StatusEffect, StatusEffects, StatusKind};
@@ -1,6 +1,8 @@
 use bevy::prelude::Component;
 use derive_more::{AsRef, Display};
+use models::damage_type::DamageType;
 use models::health::Health;
+use std::collections::HashMap;
 
 use crate::events::context::DamageContext;
 
@@ -66,9 +68,9 @@ pub trait ModifyDamage {
     fn modify(&self, base: Health, ctx: &DamageContext) -> Health;
 }
 
-/// Component-level damage modifier. Multiple modifiers can be composed
-/// at the system level if richer chains are needed later.
-#[derive(Component, Clone, Copy, Debug)]
+/// A single damage modifier. Entities carry any number of these in a
+/// [`DamageModifiers`] stack, which decides the order they run in.
+#[derive(Clone, Copy, Debug)]
 pub enum DamageModifier {
     /// Subtract a flat amount from incoming damage (saturating).
     Flat(Health),
@@ -77,6 +79,8 @@ pub enum DamageModifier {
     /// Subtract a percentage of incoming damage only when the context is
     /// `DamageContext::Physical { .. }`.
     PhysicalResist(ResistPct),
+    /// Never take more than this much from a single hit.
+    Cap(Health),
 }
 
 impl ModifyDamage for DamageModifier {
@@ -88,10 +92,113 @@ impl ModifyDamage for DamageModifier {
                 DamageContext::Physical { .. } => apply_percent_reduction(base, *pct),
                 _ => base,
             },
+            Self::Cap(max) => base.min(*max),
         }
     }
 }
 
+impl DamageModifier {
+    /// Percent this modifier contributes to the percent stage for a hit
+    /// with context `ctx`, or 0 if it isn't a percent modifier.
+    fn percent(&self, ctx: &DamageContext) -> u16 {
+        match (self, ctx) {
+            (Self::PercentReduction(pct), _)
+            | (Self::PhysicalResist(pct), DamageContext::Physical { .. }) => pct.get(),
+            _ => 0,
+        }
+    }
+}
+
+/// Ordered damage-modifier stack for an entity, plus per-`DamageType`
+/// resistances.
+///
+/// Incoming damage runs through a fixed pipeline regardless of the order
+/// modifiers were added in:
+///
+/// 1. **Flat** -- every `Flat` reduction is summed and subtracted.
+/// 2. **Percent** -- every applicable percent (`PercentReduction`,
+///    `PhysicalResist` on physical hits, and the resistance for the hit's
+///    `DamageType`) is summed, clamped to [`MAX_RESIST_PCT`], and applied
+///    once.
+/// 3. **Caps** -- the lowest `Cap` bounds the result.
+///
+/// Summing percents (rather than chaining them) keeps the stage
+/// order-independent and free of per-step rounding.
+#[derive(Component, Clone, Debug, Default)]
+pub struct DamageModifiers {
+    modifiers: Vec<DamageModifier>,
+    resistances: HashMap<DamageType, ResistPct>,
+}
+
+impl DamageModifiers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder: add a modifier to the stack.
+    pub fn with(mut self, modifier: DamageModifier) -> Self {
+        self.push(modifier);
+        self
+    }
+
+    /// Builder: set the resistance against one `DamageType`.
+    pub fn with_resist(mut self, damage_type: DamageType, pct: ResistPct) -> Self {
+        self.set_resist(damage_type, pct);
+        self
+    }
+
+    pub fn push(&mut self, modifier: DamageModifier) {
+        self.modifiers.push(modifier);
+    }
+
+    /// Set (replace) the resistance against `damage_type`.
+    pub fn set_resist(&mut self, damage_type: DamageType, pct: ResistPct) {
+        self.resistances.insert(damage_type, pct);
+    }
+
+    pub fn resist(&self, damage_type: DamageType) -> ResistPct {
+        self.resistances
+            .get(&damage_type)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Run `base` damage of `damage_type` through the flat -> percent ->
+    /// cap pipeline.
+    pub fn apply(&self, base: Health, damage_type: DamageType, ctx: &DamageContext) -> Health {
+        let flat = self
+            .modifiers
+            .iter()
+            .filter_map(|m| match m {
+                DamageModifier::Flat(h) => Some(h.0),
+                _ => None,
+            })
+            .fold(0u16, u16::saturating_add);
+        let after_flat = saturating_sub_health(base, Health(flat));
+
+        let percent = self
+            .modifiers
+            .iter()
+            .map(|m| m.percent(ctx))
+            .fold(self.resist(damage_type).get(), u16::saturating_add);
+        let after_percent = apply_percent_reduction(after_flat, ResistPct::new(percent));
+
+        self.modifiers
+            .iter()
+            .filter_map(|m| match m {
+                DamageModifier::Cap(max) => Some(*max),
+                _ => None,
+            })
+            .fold(after_percent, Health::min)
+    }
+}
+
+impl From<DamageModifier> for DamageModifiers {
+    fn from(modifier: DamageModifier) -> Self {
+        Self::new().with(modifier)
+    }
+}
+
 fn saturating_sub_health(base: Health, reduction: Health) -> Health {
     Health(base.0.saturating_sub(reduction.0))
 }
@@ -163,4 +270,99 @@ mod tests {
     fn new_clamps_over_cap() {
         assert_eq!(ResistPct::new(250).get(), MAX_RESIST_PCT);
     }
+
+    #[test]
+    fn flat_runs_before_percent_regardless_of_insertion_order() {
+        let percent_first = DamageModifiers::new()
+            .with(DamageModifier::PercentReduction(FIFTY_PCT))
+            .with(DamageModifier::Flat(Health(10)));
+        let flat_first = DamageModifiers::new()
+            .with(DamageModifier::Flat(Health(10)))
+            .with(DamageModifier::PercentReduction(FIFTY_PCT));
+        let ctx = physical_ctx();
+        // (100 - 10) * 50% = 45, not 100 * 50% - 10 = 40.
+        assert_eq!(
+            percent_first.apply(HUNDRED_HEALTH, DamageType::Physical, &ctx),
+            Health(45)
+        );
+        assert_eq!(
+            flat_first.apply(HUNDRED_HEALTH, DamageType::Physical, &ctx),
+            Health(45)
+        );
+    }
+
+    #[test]
+    fn flat_reductions_sum() {
+        let mods = DamageModifiers::new()
+            .with(DamageModifier::Flat(Health(10)))
+            .with(DamageModifier::Flat(Health(15)));
+        assert_eq!(
+            mods.apply(HUNDRED_HEALTH, DamageType::Physical, &physical_ctx()),
+            Health(75)
+        );
+    }
+
+    #[test]
+    fn percents_sum_and_clamp() {
+        let mods = DamageModifiers::new()
+            .with(DamageModifier::PercentReduction(FIFTY_PCT))
+            .with(DamageModifier::PhysicalResist(FIFTY_PCT))
+            .with(DamageModifier::PercentReduction(TEN_PCT));
+        assert_eq!(
+            mods.apply(HUNDRED_HEALTH, DamageType::Physical, &physical_ctx()),
+            Health(0)
+        );
+        // PhysicalResist drops out on a non-physical hit: 50 + 10 = 60%.
+        assert_eq!(
+            mods.apply(HUNDRED_HEALTH, DamageType::Fall, &DamageContext::Custom),
+            Health(40)
+        );
+    }
+
+    #[test]
+    fn resistance_only_applies_to_its_damage_type() {
+        let mods = DamageModifiers::new().with_resist(DamageType::Disease, FIFTY_PCT);
+        let ctx = DamageContext::Custom;
+        assert_eq!(
+            mods.apply(HUNDRED_HEALTH, DamageType::Disease, &ctx),
+            Health(50)
+        );
+        assert_eq!(
+            mods.apply(HUNDRED_HEALTH, DamageType::Physical, &ctx),
+            HUNDRED_HEALTH
+        );
+    }
+
+    #[test]
+    fn resistance_adds_to_percent_stage() {
+        let mods = DamageModifiers::new()
+            .with(DamageModifier::PercentReduction(TEN_PCT))
+            .with_resist(DamageType::Fire, ResistPct::new(40));
+        assert_eq!(
+            mods.apply(HUNDRED_HEALTH, DamageType::Fire, &DamageContext::Custom),
+            Health(50)
+        );
+    }
+
+    #[test]
+    fn cap_runs_last_and_lowest_wins() {
+        let mods = DamageModifiers::new()
+            .with(DamageModifier::Cap(Health(30)))
+            .with(DamageModifier::Cap(Health(20)))
+            .with(DamageModifier::Flat(Health(10)))
+            .with(DamageModifier::PercentReduction(FIFTY_PCT));
+        // (100 - 10) * 50% = 45, then capped to 20.
+        assert_eq!(
+            mods.apply(HUNDRED_HEALTH, DamageType::Physical, &physical_ctx()),
+            Health(20)
+        );
+    }
+
+    #[test]
+    fn empty_stack_passes_damage_through() {
+        assert_eq!(
+            DamageModifiers::new().apply(HUNDRED_HEALTH, DamageType::Physical, &physical_ctx()),
+            HUNDRED_HEALTH
+        );
+    }
 }
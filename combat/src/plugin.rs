@@ -1,7 +1,12 @@
 use bevy::prelude::*;
-use models::game_states::GameState;
+use models::game_states::{should_despawn_world, GameState};
 
 use crate::events::damage::{DamageEvent, DeathEvent};
+use crate::events::heal::HealEvent;
+use crate::projectile;
+use crate::reaction;
+use crate::regen;
+use crate::status::{self, ApplyStatus};
 use crate::systems;
 
 pub struct CombatPlugin;
@@ -11,9 +16,28 @@ impl Plugin for CombatPlugin {
         let _ = app
             .add_message::<DamageEvent>()
             .add_message::<DeathEvent>()
+            .add_message::<HealEvent>()
+            .add_message::<ApplyStatus>()
             .add_systems(
                 Update,
-                systems::apply_damage.run_if(in_state(GameState::Playing)),
+                (
+                    projectile::move_projectiles,
+                    projectile::collide_projectiles,
+                    status::apply_status_messages,
+                    status::tick_status_effects,
+                    regen::tick_regeneration,
+                    systems::apply_damage,
+                    systems::apply_heal,
+                    reaction::tick_hit_timers,
+                    reaction::apply_knockback,
+                    reaction::animate_hit_flash,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                OnExit(GameState::Playing),
+                projectile::despawn_projectiles.run_if(should_despawn_world),
             );
         app.add_observer(systems::default_death_observer);
     }
@@ -0,0 +1,222 @@
+//! Projectiles: straight-line shots that carry an [`Attack`] until they hit
+//! something, run out of range, or run out of pierce.
+//!
+//! A projectile stops on the first `SceneryCollider` it touches. Against
+//! entities with a [`Hurtbox`] (and `Health`) it emits a
+//! `DamageEvent::with_context` per target, then either keeps flying (while
+//! `pierce` remains) or despawns. Shots only hit hurtboxes on the other
+//! [`Team`] -- slime spit passes through other enemies, player spells pass
+//! through the player -- the owner is never hit by its own shot, and a
+//! piercing shot never hits the same target twice.
+
+use bevy::prelude::*;
+use models::attack::Attack;
+use models::health::Health;
+use models::scenery::SceneryCollider;
+
+use crate::events::context::DamageContext;
+use crate::events::damage::DamageEvent;
+
+/// Which side of a fight an entity is on. Projectiles never hit their own
+/// team.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Team {
+    Player,
+    Enemy,
+}
+
+/// Axis-aligned box, centred on the entity's `Transform`, that projectiles
+/// can hit. Anything that should take ranged damage needs one alongside
+/// its `Health`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Hurtbox {
+    pub half_extents: Vec2,
+    pub team: Team,
+}
+
+/// A flying shot. Spawn it with a `Transform` at the muzzle and a `Sprite`
+/// (or any other visual); the combat systems handle motion and hits.
+#[derive(Component, Debug)]
+#[require(Transform)]
+pub struct Projectile {
+    /// World-space velocity in pixels per second.
+    pub velocity: Vec2,
+    /// Despawns the shot when it finishes; sized so the shot travels
+    /// exactly the requested range.
+    pub lifetime: Timer,
+    /// The entity that fired the shot. Never hit by it.
+    pub owner: Entity,
+    /// The side that fired the shot; hurtboxes on the same team are ignored.
+    pub team: Team,
+    /// How many extra targets the shot can pass through after the first.
+    pub pierce: u8,
+    /// Collision radius of the shot itself (pixels).
+    pub radius: f32,
+    pub attack: Attack,
+    pub context: DamageContext,
+    /// Targets already struck, so a piercing shot only hits each once.
+    hit: Vec<Entity>,
+}
+
+/// Default collision radius for a projectile (pixels).
+const DEFAULT_RADIUS_PX: f32 = 2.0;
+
+impl Projectile {
+    /// A non-piercing physical shot from `owner` (on `team`) moving at
+    /// `velocity` that fizzles after `range_px` pixels.
+    pub fn new(owner: Entity, team: Team, attack: Attack, velocity: Vec2, range_px: f32) -> Self {
+        let speed = velocity.length().max(f32::EPSILON);
+        Self {
+            velocity,
+            lifetime: Timer::from_seconds(range_px / speed, TimerMode::Once),
+            owner,
+            team,
+            pierce: 0,
+            radius: DEFAULT_RADIUS_PX,
+            attack,
+            context: DamageContext::DEFAULT_PHYSICAL,
+            hit: Vec::new(),
+        }
+    }
+
+    pub fn with_pierce(mut self, pierce: u8) -> Self {
+        self.pierce = pierce;
+        self
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn with_context(mut self, context: DamageContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Whether this shot may strike `target`: it must be on the other team,
+    /// not the shooter, and not already hit.
+    fn can_hit(&self, target: Entity, hurtbox: &Hurtbox) -> bool {
+        hurtbox.team != self.team && target != self.owner && !self.hit.contains(&target)
+    }
+}
+
+/// True when a circle of `radius` at `point` overlaps the box.
+fn overlaps_box(point: Vec2, radius: f32, center: Vec2, half_extents: Vec2) -> bool {
+    let d = (point - center).abs();
+    d.x <= half_extents.x + radius && d.y <= half_extents.y + radius
+}
+
+/// Advance projectiles along their velocity and expire them once their
+/// range is spent.
+pub fn move_projectiles(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut Projectile, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut projectile, mut tf) in &mut q {
+        projectile.lifetime.tick(time.delta());
+        if projectile.lifetime.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        tf.translation += (projectile.velocity * dt).extend(0.0);
+    }
+}
+
+/// Resolve projectile hits against scenery and hurtboxes.
+#[allow(clippy::type_complexity)]
+pub fn collide_projectiles(
+    mut commands: Commands,
+    mut projectiles: Query<(Entity, &mut Projectile, &Transform)>,
+    scenery: Query<(&Transform, &SceneryCollider), Without<Projectile>>,
+    targets: Query<(Entity, &Transform, &Hurtbox), (With<Health>, Without<Projectile>)>,
+    mut damage: MessageWriter<DamageEvent>,
+) {
+    for (entity, mut projectile, tf) in &mut projectiles {
+        let pos = tf.translation.truncate();
+
+        let blocked = scenery.iter().any(|(obj_tf, col)| {
+            let center = obj_tf.translation.truncate() + col.center_offset;
+            overlaps_box(pos, projectile.radius, center, col.half_extents)
+        });
+        if blocked {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        for (target, target_tf, hurtbox) in &targets {
+            if !projectile.can_hit(target, hurtbox) {
+                continue;
+            }
+            let center = target_tf.translation.truncate();
+            if !overlaps_box(pos, projectile.radius, center, hurtbox.half_extents) {
+                continue;
+            }
+            damage.write(DamageEvent::with_context(
+                target,
+                projectile.attack,
+                projectile.owner,
+                projectile.context,
+            ));
+            projectile.hit.push(target);
+            if projectile.pierce == 0 {
+                commands.entity(entity).despawn();
+                break;
+            }
+            projectile.pierce -= 1;
+        }
+    }
+}
+
+/// Despawn every in-flight projectile on world teardown.
+pub fn despawn_projectiles(mut commands: Commands, q: Query<Entity, With<Projectile>>) {
+    for entity in &q {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifetime_covers_range() {
+        let p = Projectile::new(
+            Entity::PLACEHOLDER,
+            Team::Enemy,
+            Attack::default(),
+            Vec2::new(40.0, 0.0),
+            80.0,
+        );
+        assert!((p.lifetime.duration().as_secs_f32() - 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn overlap_includes_radius() {
+        let half = Vec2::splat(4.0);
+        assert!(overlaps_box(Vec2::new(5.5, 0.0), 2.0, Vec2::ZERO, half));
+        assert!(!overlaps_box(Vec2::new(6.5, 0.0), 2.0, Vec2::ZERO, half));
+    }
+
+    #[test]
+    fn shots_only_hit_the_other_team() {
+        let shooter = Entity::from_raw_u32(1).expect("valid index");
+        let target = Entity::from_raw_u32(2).expect("valid index");
+        let hurtbox = |team| Hurtbox {
+            half_extents: Vec2::splat(4.0),
+            team,
+        };
+        let spit = Projectile::new(shooter, Team::Enemy, Attack::default(), Vec2::X, 10.0);
+        assert!(spit.can_hit(target, &hurtbox(Team::Player)));
+        assert!(!spit.can_hit(target, &hurtbox(Team::Enemy)));
+        assert!(!spit.can_hit(shooter, &hurtbox(Team::Player)));
+
+        let mut spell = Projectile::new(shooter, Team::Player, Attack::default(), Vec2::X, 10.0);
+        assert!(spell.can_hit(target, &hurtbox(Team::Enemy)));
+        assert!(!spell.can_hit(target, &hurtbox(Team::Player)));
+        spell.hit.push(target);
+        assert!(!spell.can_hit(target, &hurtbox(Team::Enemy)));
+    }
+}
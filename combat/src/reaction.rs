@@ -0,0 +1,171 @@
+//! Hit reactions: invulnerability frames, knockback, hit-stun and a sprite
+//! flash, applied by `apply_damage` to targets that opt in with a
+//! [`HitResponse`].
+//!
+//! Damage-over-time ticks (`DamageContext::Status`) neither trigger a
+//! reaction nor are blocked by [`Invulnerable`] -- otherwise a single
+//! infection would grant the player permanent i-frames.
+
+use bevy::prelude::*;
+use models::palette;
+use models::scenery::SceneryCollider;
+
+use crate::projectile::Hurtbox;
+
+/// How long the knockback impulse lasts (seconds).
+const KNOCKBACK_SECS: f32 = 0.15;
+/// How long the hit tint stays on the sprite (seconds).
+const HIT_FLASH_SECS: f32 = 0.2;
+/// Body half-size used for knockback collision when the entity has no
+/// `Hurtbox` (pixels).
+const DEFAULT_BODY_HALF_PX: f32 = 6.0;
+
+/// Per-entity tuning for how it reacts to being hit. Entities without one
+/// just lose health.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct HitResponse {
+    /// Length of the post-hit invulnerability window (0 disables).
+    pub invulnerable_secs: f32,
+    /// Total distance the hit shoves the target away from the source
+    /// (pixels, 0 disables).
+    pub knockback_px: f32,
+    /// How long input / AI is suspended after the hit (0 disables).
+    pub stun_secs: f32,
+}
+
+/// Ignores incoming damage (except status ticks) until the timer finishes.
+#[derive(Component, Debug)]
+pub struct Invulnerable(pub Timer);
+
+/// Suspends player input and enemy AI until the timer finishes. Systems
+/// that drive an entity should skip it while this is present.
+#[derive(Component, Debug)]
+pub struct HitStun(pub Timer);
+
+/// Active knockback impulse.
+#[derive(Component, Debug)]
+pub struct Knockback {
+    /// World-space velocity in pixels per second.
+    pub velocity: Vec2,
+    pub remaining: Timer,
+}
+
+/// Tints the sprite with `palette::HIT_FLASH` until the timer finishes.
+#[derive(Component, Debug)]
+pub struct HitFlash(pub Timer);
+
+/// Insert the reaction components `response` asks for. `away` is the unit
+/// direction from the damage source to the target (zero if unknown).
+pub(crate) fn react_to_hit(
+    commands: &mut Commands,
+    target: Entity,
+    response: &HitResponse,
+    away: Vec2,
+) {
+    let mut entity = commands.entity(target);
+    entity.insert(HitFlash(Timer::from_seconds(
+        HIT_FLASH_SECS,
+        TimerMode::Once,
+    )));
+    if response.invulnerable_secs > 0.0 {
+        entity.insert(Invulnerable(Timer::from_seconds(
+            response.invulnerable_secs,
+            TimerMode::Once,
+        )));
+    }
+    if response.stun_secs > 0.0 {
+        entity.insert(HitStun(Timer::from_seconds(
+            response.stun_secs,
+            TimerMode::Once,
+        )));
+    }
+    if response.knockback_px > 0.0 && away != Vec2::ZERO {
+        entity.insert(Knockback {
+            velocity: away * (response.knockback_px / KNOCKBACK_SECS),
+            remaining: Timer::from_seconds(KNOCKBACK_SECS, TimerMode::Once),
+        });
+    }
+}
+
+/// Tick i-frame and stun timers and drop them once expired.
+pub fn tick_hit_timers(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut invulnerable: Query<(Entity, &mut Invulnerable)>,
+    mut stunned: Query<(Entity, &mut HitStun)>,
+) {
+    for (entity, mut timer) in &mut invulnerable {
+        if timer.0.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+        }
+    }
+    for (entity, mut timer) in &mut stunned {
+        if timer.0.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<HitStun>();
+        }
+    }
+}
+
+/// True when a box of `half` at `pos` overlaps any of `colliders`. Shared
+/// with anything else that slides bodies along scenery, e.g. enemy AI.
+pub fn blocked<'a>(
+    pos: Vec2,
+    half: Vec2,
+    colliders: impl IntoIterator<Item = (&'a Transform, &'a SceneryCollider)>,
+) -> bool {
+    colliders.into_iter().any(|(tf, col)| {
+        let d = (pos - (tf.translation.truncate() + col.center_offset)).abs();
+        d.x < half.x + col.half_extents.x && d.y < half.y + col.half_extents.y
+    })
+}
+
+/// Slide knocked-back entities along their impulse, one axis at a time so
+/// they stop against scenery instead of tunnelling into it.
+pub fn apply_knockback(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut Knockback, &mut Transform, Option<&Hurtbox>)>,
+    colliders: Query<(&Transform, &SceneryCollider), Without<Knockback>>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut knockback, mut tf, hurtbox) in &mut q {
+        let half = hurtbox.map_or(Vec2::splat(DEFAULT_BODY_HALF_PX), |h| h.half_extents);
+        let step = knockback.velocity * dt;
+        let pos = tf.translation.truncate();
+
+        let try_x = pos + Vec2::new(step.x, 0.0);
+        let pos = if blocked(try_x, half, colliders.iter()) {
+            pos
+        } else {
+            try_x
+        };
+        let try_y = pos + Vec2::new(0.0, step.y);
+        let pos = if blocked(try_y, half, colliders.iter()) {
+            pos
+        } else {
+            try_y
+        };
+        tf.translation.x = pos.x;
+        tf.translation.y = pos.y;
+
+        if knockback.remaining.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<Knockback>();
+        }
+    }
+}
+
+/// Hold the hit tint while the flash timer runs, then restore the sprite.
+pub fn animate_hit_flash(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut HitFlash, &mut Sprite)>,
+) {
+    for (entity, mut flash, mut sprite) in &mut q {
+        if flash.0.tick(time.delta()).is_finished() {
+            sprite.color = palette::OPAQUE_WHITE;
+            commands.entity(entity).remove::<HitFlash>();
+        } else {
+            sprite.color = palette::HIT_FLASH;
+        }
+    }
+}
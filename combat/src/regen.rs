@@ -0,0 +1,82 @@
+//! Passive regeneration: an entity with a [`Regeneration`] slowly heals
+//! back up once it has gone a while without taking damage. Heals go
+//! through `HealEvent` like any other source, so `MaxHealth` clamping and
+//! the dead-stay-dead rule apply.
+
+use bevy::prelude::*;
+use models::health::{Health, MaxHealth};
+
+use crate::events::heal::HealEvent;
+
+/// Heals `amount` every `interval_secs` after `delay_secs` without a hit.
+#[derive(Component, Debug, Clone)]
+pub struct Regeneration {
+    pub amount: Health,
+    /// Repeating; each finish heals once.
+    tick: Timer,
+    /// Counts down from the last hit; regen is paused until it finishes.
+    delay: Timer,
+    /// Health seen last frame, to spot hits without hooking `apply_damage`.
+    last: Health,
+}
+
+impl Regeneration {
+    pub fn new(amount: Health, interval_secs: f32, delay_secs: f32) -> Self {
+        Self {
+            amount,
+            tick: Timer::from_seconds(interval_secs, TimerMode::Repeating),
+            delay: Timer::from_seconds(delay_secs, TimerMode::Once),
+            last: Health(0),
+        }
+    }
+
+    /// Advance by `dt` given the entity's current health; true when a heal
+    /// is due this frame.
+    fn advance(&mut self, health: Health, dt: std::time::Duration) -> bool {
+        if health < self.last {
+            self.delay.reset();
+            self.tick.reset();
+        }
+        self.last = health;
+        if !self.delay.tick(dt).is_finished() {
+            return false;
+        }
+        self.tick.tick(dt).just_finished()
+    }
+}
+
+/// Emit a `HealEvent` for every regenerating entity that's due one.
+pub fn tick_regeneration(
+    time: Res<Time>,
+    mut q: Query<(Entity, &Health, &MaxHealth, &mut Regeneration)>,
+    mut heals: MessageWriter<HealEvent>,
+) {
+    for (entity, health, max, mut regen) in &mut q {
+        let due = regen.advance(*health, time.delta());
+        if due && health.0 > 0 && health.0 < max.0 {
+            heals.write(HealEvent::new(entity, regen.amount, entity));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn waits_out_delay_then_ticks() {
+        let mut regen = Regeneration::new(Health(1), 1.0, 2.0);
+        assert!(!regen.advance(Health(5), Duration::from_secs_f32(1.5)));
+        assert!(!regen.advance(Health(5), Duration::from_secs_f32(0.6)));
+        assert!(regen.advance(Health(5), Duration::from_secs_f32(1.0)));
+    }
+
+    #[test]
+    fn hit_restarts_delay() {
+        let mut regen = Regeneration::new(Health(1), 1.0, 2.0);
+        assert!(!regen.advance(Health(5), Duration::from_secs_f32(1.0)));
+        assert!(!regen.advance(Health(4), Duration::from_secs_f32(1.5)));
+        assert!(!regen.advance(Health(4), Duration::from_secs_f32(0.4)));
+    }
+}
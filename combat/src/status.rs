@@ -0,0 +1,367 @@
+//! Timed status effects: disease, poison, burning, regeneration, and pure
+//! debuffs such as slows.
+//!
+//! Effects live in a per-entity [`StatusEffects`] list. Each effect can
+//! deal damage on a fixed tick -- routed through the normal
+//! `DamageEvent` -> `apply_damage` path under `DamageContext::Status` so
+//! modifiers and death handling behave exactly as for a hit -- heal on
+//! that tick through `HealEvent`, and/or scale the bearer's movement
+//! speed (read by movement systems through [`StatusEffects::speed_mult`]).
+//!
+//! Other crates apply effects by writing an [`ApplyStatus`] message rather
+//! than touching the component, so the target doesn't need a
+//! `StatusEffects` ahead of time.
+
+use bevy::prelude::*;
+use models::attack::Attack;
+use models::damage_type::DamageType;
+use models::health::Health;
+use std::collections::HashMap;
+
+use crate::events::context::DamageContext;
+use crate::events::damage::DamageEvent;
+use crate::events::heal::HealEvent;
+
+/// What an effect is. One active entry per kind per entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// The purple blight spread by diseased creatures.
+    Disease,
+    Poison,
+    Burning,
+    /// Heal-over-time (hot springs, potions).
+    Regeneration,
+    /// Movement penalty with no damage component.
+    Slowed,
+}
+
+impl StatusKind {
+    /// `DamageType` carried by the ticks this effect deals.
+    pub fn damage_type(self) -> DamageType {
+        match self {
+            Self::Disease => DamageType::Disease,
+            Self::Poison => DamageType::Poison,
+            Self::Burning => DamageType::Fire,
+            Self::Regeneration | Self::Slowed => DamageType::Physical,
+        }
+    }
+}
+
+/// What happens when an effect is applied to an entity that already has
+/// one of the same kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stacking {
+    /// Restart the duration; intensity is unchanged.
+    Refresh,
+    /// Add a stack (up to `max`) and restart the duration. Tick damage
+    /// scales with the stack count.
+    Stack { max: u8 },
+    /// Keep the existing effect untouched.
+    Ignore,
+}
+
+/// One active effect on an entity.
+#[derive(Debug, Clone)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub stacking: Stacking,
+    /// Counts down the effect's total duration.
+    pub remaining: Timer,
+    /// Repeating timer; each finish deals one tick of damage and/or
+    /// healing.
+    pub tick: Timer,
+    /// Damage per tick per stack. `Health(0)` for pure debuffs.
+    pub damage_per_tick: Health,
+    /// Healing per tick per stack. `Health(0)` for anything harmful.
+    pub heal_per_tick: Health,
+    /// Movement speed multiplier while active (1.0 = unaffected).
+    pub speed_mult: f32,
+    pub stacks: u8,
+    /// Entity credited with the damage (the `DamageEvent::source`).
+    pub source: Entity,
+}
+
+impl StatusEffect {
+    /// A damage-over-time effect dealing `damage_per_tick` every
+    /// `tick_secs` for `duration_secs`.
+    pub fn damage_over_time(
+        kind: StatusKind,
+        source: Entity,
+        damage_per_tick: Health,
+        tick_secs: f32,
+        duration_secs: f32,
+    ) -> Self {
+        Self {
+            kind,
+            stacking: Stacking::Refresh,
+            remaining: Timer::from_seconds(duration_secs, TimerMode::Once),
+            tick: Timer::from_seconds(tick_secs, TimerMode::Repeating),
+            damage_per_tick,
+            heal_per_tick: Health(0),
+            speed_mult: 1.0,
+            stacks: 1,
+            source,
+        }
+    }
+
+    /// A non-damaging slow for `duration_secs`.
+    pub fn slow(source: Entity, speed_mult: f32, duration_secs: f32) -> Self {
+        Self {
+            speed_mult,
+            stacking: Stacking::Ignore,
+            ..Self::damage_over_time(
+                StatusKind::Slowed,
+                source,
+                Health(0),
+                duration_secs,
+                duration_secs,
+            )
+        }
+    }
+
+    /// Heal `heal_per_tick` every `tick_secs` for `duration_secs`.
+    /// Reapplying refreshes the duration without resetting the tick, so a
+    /// source that reapplies every frame (a hot spring) heals steadily.
+    pub fn heal_over_time(
+        source: Entity,
+        heal_per_tick: Health,
+        tick_secs: f32,
+        duration_secs: f32,
+    ) -> Self {
+        Self {
+            heal_per_tick,
+            ..Self::damage_over_time(
+                StatusKind::Regeneration,
+                source,
+                Health(0),
+                tick_secs,
+                duration_secs,
+            )
+        }
+    }
+
+    /// The infection diseased creatures inflict: a slow-burning tick that
+    /// also saps movement, stacking up to three times.
+    pub fn infection(source: Entity) -> Self {
+        Self {
+            stacking: Stacking::Stack { max: 3 },
+            speed_mult: 0.8,
+            ..Self::damage_over_time(StatusKind::Disease, source, Health(1), 3.0, 9.0)
+        }
+    }
+
+    pub fn with_stacking(mut self, stacking: Stacking) -> Self {
+        self.stacking = stacking;
+        self
+    }
+
+    pub fn with_speed_mult(mut self, speed_mult: f32) -> Self {
+        self.speed_mult = speed_mult;
+        self
+    }
+
+    fn tick_damage(&self) -> Health {
+        Health(
+            self.damage_per_tick
+                .0
+                .saturating_mul(u16::from(self.stacks)),
+        )
+    }
+
+    fn tick_heal(&self) -> Health {
+        Health(self.heal_per_tick.0.saturating_mul(u16::from(self.stacks)))
+    }
+}
+
+/// All active effects on an entity.
+#[derive(Component, Debug, Default, Clone)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    /// Add `effect`, resolving a clash with an active effect of the same
+    /// kind via the incoming effect's [`Stacking`] rule.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        let Some(existing) = self.effects.iter_mut().find(|e| e.kind == effect.kind) else {
+            self.effects.push(effect);
+            return;
+        };
+        match effect.stacking {
+            Stacking::Ignore => {}
+            Stacking::Refresh => {
+                existing.remaining = effect.remaining;
+                existing.source = effect.source;
+            }
+            Stacking::Stack { max } => {
+                existing.stacks = existing.stacks.saturating_add(1).min(max.max(1));
+                existing.remaining = effect.remaining;
+                existing.source = effect.source;
+            }
+        }
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.effects.iter().any(|e| e.kind == kind)
+    }
+
+    /// Current stack count for `kind` (0 when absent).
+    pub fn stacks(&self, kind: StatusKind) -> u8 {
+        self.effects
+            .iter()
+            .find(|e| e.kind == kind)
+            .map_or(0, |e| e.stacks)
+    }
+
+    /// Strongest movement penalty among active effects. Slows don't
+    /// multiply together -- the worst one wins.
+    pub fn speed_mult(&self) -> f32 {
+        self.effects
+            .iter()
+            .map(|e| e.speed_mult)
+            .fold(1.0, f32::min)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+}
+
+/// Message: apply `effect` to `target`, inserting `StatusEffects` if the
+/// target doesn't have one yet.
+#[derive(Message, Debug, Clone)]
+pub struct ApplyStatus {
+    pub target: Entity,
+    pub effect: StatusEffect,
+}
+
+/// Drain `ApplyStatus` messages into each target's `StatusEffects`.
+pub fn apply_status_messages(
+    mut commands: Commands,
+    mut reader: MessageReader<ApplyStatus>,
+    mut targets: Query<Option<&mut StatusEffects>>,
+) {
+    // Targets without the component collect here first so several
+    // messages for the same fresh target in one frame all land.
+    let mut fresh: HashMap<Entity, StatusEffects> = HashMap::new();
+    for msg in reader.read() {
+        match targets.get_mut(msg.target) {
+            Ok(Some(mut effects)) => effects.apply(msg.effect.clone()),
+            Ok(None) => fresh
+                .entry(msg.target)
+                .or_default()
+                .apply(msg.effect.clone()),
+            Err(_) => {}
+        }
+    }
+    for (target, effects) in fresh {
+        commands.entity(target).insert(effects);
+    }
+}
+
+/// Tick every active effect, emit a `DamageEvent` / `HealEvent` per
+/// elapsed tick, and drop expired effects.
+pub fn tick_status_effects(
+    time: Res<Time>,
+    mut q: Query<(Entity, &mut StatusEffects)>,
+    mut damage: MessageWriter<DamageEvent>,
+    mut heal: MessageWriter<HealEvent>,
+) {
+    for (entity, mut effects) in &mut q {
+        if effects.is_empty() {
+            continue;
+        }
+        for effect in &mut effects.effects {
+            effect.remaining.tick(time.delta());
+            effect.tick.tick(time.delta());
+            for _ in 0..effect.tick.times_finished_this_tick() {
+                if effect.damage_per_tick.0 > 0 {
+                    damage.write(DamageEvent::with_context(
+                        entity,
+                        Attack::over_time(effect.tick_damage(), effect.kind.damage_type()),
+                        effect.source,
+                        DamageContext::Status { kind: effect.kind },
+                    ));
+                }
+                if effect.heal_per_tick.0 > 0 {
+                    heal.write(HealEvent::new(entity, effect.tick_heal(), effect.source));
+                }
+            }
+        }
+        effects.effects.retain(|e| !e.remaining.is_finished());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poison() -> StatusEffect {
+        StatusEffect::damage_over_time(StatusKind::Poison, Entity::PLACEHOLDER, Health(1), 1.0, 5.0)
+    }
+
+    #[test]
+    fn refresh_keeps_single_stack() {
+        let mut effects = StatusEffects::default();
+        effects.apply(poison());
+        effects.apply(poison());
+        assert_eq!(effects.stacks(StatusKind::Poison), 1);
+        assert_eq!(effects.iter().count(), 1);
+    }
+
+    #[test]
+    fn stack_caps_at_max() {
+        let mut effects = StatusEffects::default();
+        for _ in 0..5 {
+            effects.apply(StatusEffect::infection(Entity::PLACEHOLDER));
+        }
+        assert_eq!(effects.stacks(StatusKind::Disease), 3);
+    }
+
+    #[test]
+    fn ignore_leaves_existing_effect() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::slow(Entity::PLACEHOLDER, 0.5, 2.0));
+        effects.apply(StatusEffect::slow(Entity::PLACEHOLDER, 0.1, 2.0));
+        assert!((effects.speed_mult() - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn strongest_slow_wins() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::slow(Entity::PLACEHOLDER, 0.5, 2.0));
+        effects.apply(StatusEffect::infection(Entity::PLACEHOLDER));
+        assert!((effects.speed_mult() - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn tick_damage_scales_with_stacks() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::infection(Entity::PLACEHOLDER));
+        effects.apply(StatusEffect::infection(Entity::PLACEHOLDER));
+        let disease = effects.iter().next().expect("infection applied");
+        assert_eq!(disease.tick_damage(), Health(2));
+    }
+
+    #[test]
+    fn heal_over_time_refresh_keeps_tick_progress() {
+        let mut effects = StatusEffects::default();
+        let mut spring = StatusEffect::heal_over_time(Entity::PLACEHOLDER, Health(1), 1.0, 2.0);
+        spring.tick.tick(std::time::Duration::from_secs_f32(0.5));
+        effects.apply(spring);
+        effects.apply(StatusEffect::heal_over_time(
+            Entity::PLACEHOLDER,
+            Health(1),
+            1.0,
+            2.0,
+        ));
+        let regen = effects.iter().next().expect("regeneration applied");
+        assert!((regen.tick.elapsed_secs() - 0.5).abs() < 1e-4);
+        assert_eq!(regen.tick_heal(), Health(1));
+        assert_eq!(regen.tick_damage(), Health(0));
+    }
+}
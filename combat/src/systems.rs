@@ -1,30 +1,78 @@
 use bevy::prelude::*;
-use models::health::Health;
+use models::health::{Health, MaxHealth};
+use models::player::Player;
+use std::collections::HashSet;
 
+use crate::events::context::DamageContext;
 use crate::events::damage::{DamageEvent, DeathEvent};
 use crate::events::death::DeathOccurred;
-use crate::modifier::{DamageModifier, ModifyDamage};
+use crate::events::heal::HealEvent;
+use crate::modifier::DamageModifiers;
+use crate::reaction::{react_to_hit, HitResponse, Invulnerable};
 
-/// Reads incoming `DamageEvent`s, applies any `DamageModifier` on the
-/// target, mutates `Health`, and emits `DeathEvent` (legacy `Message`
-/// channel) plus `DeathOccurred` (observer-targeted `EntityEvent`)
-/// when the target's health hits zero.
+/// Reads incoming `DamageEvent`s, runs them through the target's
+/// `DamageModifiers` stack (if any), mutates `Health`, and emits
+/// `DeathEvent` (legacy `Message` channel) plus `DeathOccurred`
+/// (observer-targeted `EntityEvent`) when the target's health hits zero.
+///
+/// Targets that are `Invulnerable` ignore everything but status ticks.
+/// Surviving targets with a `HitResponse` get their i-frames, knockback
+/// (away from the source's position), hit-stun and flash here.
+#[allow(clippy::type_complexity)]
 pub fn apply_damage(
     mut commands: Commands,
     mut damage_events: MessageReader<DamageEvent>,
     mut death_events: MessageWriter<DeathEvent>,
-    mut targets: Query<(&mut Health, Option<&DamageModifier>)>,
+    mut targets: Query<(
+        &mut Health,
+        Option<&DamageModifiers>,
+        Option<&HitResponse>,
+        Has<Invulnerable>,
+    )>,
+    positions: Query<&Transform>,
 ) {
+    // `Invulnerable` is inserted through `Commands`, so hits later in the
+    // same frame wouldn't see it yet; track this frame's grants here.
+    let mut granted_iframes: HashSet<Entity> = HashSet::new();
+
     damage_events.read().for_each(|event| {
-        let Ok((mut health, modifier)) = targets.get_mut(event.target) else {
+        let Ok((mut health, modifiers, response, invulnerable)) = targets.get_mut(event.target)
+        else {
             return;
         };
 
+        // Already dead -- the death was reported when it hit zero.
+        if health.0 == 0 {
+            return;
+        }
+
+        let is_status = matches!(event.context, DamageContext::Status { .. });
+        if !is_status && (invulnerable || granted_iframes.contains(&event.target)) {
+            return;
+        }
+
         let base = event.attack.damage;
-        let final_damage = modifier.map_or(base, |m| m.modify(base, &event.context));
+        let final_damage = modifiers.map_or(base, |m| {
+            m.apply(base, event.attack.damage_type, &event.context)
+        });
 
         if *health > final_damage {
             *health -= final_damage;
+            if let (Some(response), false, true) = (response, is_status, final_damage.0 > 0) {
+                let away = positions
+                    .get(event.source)
+                    .ok()
+                    .zip(positions.get(event.target).ok())
+                    .map_or(Vec2::ZERO, |(src, dst)| {
+                        (dst.translation - src.translation)
+                            .truncate()
+                            .normalize_or_zero()
+                    });
+                react_to_hit(&mut commands, event.target, response, away);
+                if response.invulnerable_secs > 0.0 {
+                    granted_iframes.insert(event.target);
+                }
+            }
             return;
         }
 
@@ -49,9 +97,40 @@ pub fn apply_damage(
     });
 }
 
+/// Reads incoming `HealEvent`s and restores `Health`, clamped to the
+/// target's `MaxHealth`. Targets without a `MaxHealth` have no ceiling to
+/// clamp to and can't be healed; dead targets stay dead.
+pub fn apply_heal(
+    mut heal_events: MessageReader<HealEvent>,
+    mut targets: Query<(&mut Health, &MaxHealth)>,
+) {
+    for event in heal_events.read() {
+        let Ok((mut health, max)) = targets.get_mut(event.target) else {
+            continue;
+        };
+        if health.0 == 0 {
+            continue;
+        }
+        let healed = health.healed(event.amount, *max);
+        if healed != *health {
+            *health = healed;
+        }
+    }
+}
+
 /// Default observer for `DeathOccurred`: despawn the dying entity.
 /// Other crates can register additional observers (e.g. to trigger
 /// game-over UI, drop loot, play SFX) without removing this one.
-pub fn default_death_observer(on: On<DeathOccurred>, mut commands: Commands) {
+///
+/// The player is left alone -- their death ends the run through
+/// `GameState::GameOver`, and the world teardown despawns them.
+pub fn default_death_observer(
+    on: On<DeathOccurred>,
+    mut commands: Commands,
+    players: Query<(), With<Player>>,
+) {
+    if players.contains(on.entity) {
+        return;
+    }
     commands.entity(on.entity).despawn();
 }
@@ -0,0 +1,153 @@
+//! Dialogue side effects dispatched to handlers registered by other crates.
+//!
+//! Content names an action by string id -- as a `DialogueLine::Action` or in
+//! a `ChoiceOption::actions` list -- and the runner hands its `args` to
+//! whichever one-shot system registered that id. The dialog crate never
+//! learns what the action does, so it doesn't depend on gameplay crates:
+//!
+//! ```rust,ignore
+//! app.register_dialogue_action("set_weather", |In(args): In<ActionArgs>| {
+//!     info!("weather -> {args:?}");
+//! });
+//! ```
+//!
+//! Ids with no handler are logged and skipped. The ids content can use:
+//!
+//! | id | args | registered by |
+//! |----|------|---------------|
+//! | `start_quest` | quest id | `dialog` ([`crate::quest`]) |
+//! | `give_item` | item id, optional count | `level::dialogue_actions` |
+//! | `set_weather` | `Clear` / `Breezy` / `Windy` / `Rain` / `Storm` | `level::dialogue_actions` |
+//!
+//! Flags and alignment are changed through `ChoiceOption` fields rather
+//! than actions. Add a row here when registering a new id.
+
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Arguments an action carries, verbatim from the script.
+pub type ActionArgs = Vec<String>;
+
+/// One side effect named in a script.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DialogueAction {
+    pub id: String,
+    #[serde(default)]
+    pub args: ActionArgs,
+}
+
+/// Registered handlers keyed by action id.
+#[derive(Resource, Default)]
+pub struct DialogueActions(HashMap<String, SystemId<In<ActionArgs>>>);
+
+impl DialogueActions {
+    pub fn is_registered(&self, id: &str) -> bool {
+        self.0.contains_key(id)
+    }
+
+    /// Queue the handler for `id` with `args`. Runs when `commands` apply.
+    pub fn dispatch(&self, commands: &mut Commands, id: &str, args: &[String]) {
+        match self.0.get(id) {
+            Some(system) => commands.run_system_with(*system, args.to_vec()),
+            None => warn!("No handler registered for dialogue action '{id}'"),
+        }
+    }
+}
+
+/// `App` extension for plugging gameplay into dialogue actions.
+pub trait DialogueActionAppExt {
+    /// Register `handler` as the system run for action `id`. A later
+    /// registration for the same id replaces the earlier one.
+    fn register_dialogue_action<M>(
+        &mut self,
+        id: impl Into<String>,
+        handler: impl IntoSystem<In<ActionArgs>, (), M> + 'static,
+    ) -> &mut Self;
+}
+
+impl DialogueActionAppExt for App {
+    fn register_dialogue_action<M>(
+        &mut self,
+        id: impl Into<String>,
+        handler: impl IntoSystem<In<ActionArgs>, (), M> + 'static,
+    ) -> &mut Self {
+        let system = self.register_system(handler);
+        self.world_mut()
+            .get_resource_or_init::<DialogueActions>()
+            .0
+            .insert(id.into(), system);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every `(handler, args)` pair run so far.
+    #[derive(Resource, Default)]
+    struct Ran(Vec<(&'static str, ActionArgs)>);
+
+    fn first(In(args): In<ActionArgs>, mut ran: ResMut<Ran>) {
+        ran.0.push(("first", args));
+    }
+
+    fn second(In(args): In<ActionArgs>, mut ran: ResMut<Ran>) {
+        ran.0.push(("second", args));
+    }
+
+    fn dispatch(app: &mut App, id: &str, args: &[&str]) {
+        let args: ActionArgs = args.iter().map(|arg| (*arg).to_owned()).collect();
+        let world = app.world_mut();
+        world.resource_scope(|world, actions: Mut<DialogueActions>| {
+            let mut commands = world.commands();
+            actions.dispatch(&mut commands, id, &args);
+        });
+        world.flush();
+    }
+
+    fn ran(app: &App) -> &[(&'static str, ActionArgs)] {
+        &app.world().resource::<Ran>().0
+    }
+
+    #[test]
+    fn dispatch_runs_the_handler_with_its_args() {
+        let mut app = App::new();
+        app.init_resource::<Ran>()
+            .register_dialogue_action("give_item", first);
+
+        dispatch(&mut app, "give_item", &["antler", "2"]);
+        assert_eq!(
+            ran(&app),
+            [("first", vec!["antler".to_owned(), "2".to_owned()])]
+        );
+    }
+
+    #[test]
+    fn unknown_ids_are_skipped() {
+        let mut app = App::new();
+        app.init_resource::<Ran>()
+            .register_dialogue_action("give_item", first);
+
+        assert!(!app
+            .world()
+            .resource::<DialogueActions>()
+            .is_registered("set_weather"));
+        dispatch(&mut app, "set_weather", &["Rain"]);
+        assert!(ran(&app).is_empty());
+    }
+
+    #[test]
+    fn registering_an_id_again_replaces_its_handler() {
+        let mut app = App::new();
+        app.init_resource::<Ran>()
+            .register_dialogue_action("give_item", first)
+            .register_dialogue_action("give_item", second);
+
+        dispatch(&mut app, "give_item", &[]);
+        assert_eq!(ran(&app), [("second", Vec::new())]);
+    }
+}
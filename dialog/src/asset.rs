@@ -1,8 +1,11 @@
 use bevy::asset::{io::Reader, Asset, AssetLoader, LoadContext};
 use bevy::reflect::TypePath;
-use models::alignment::AlignmentFaction;
-use serde::Deserialize;
+use models::alignment::{AlignmentFaction, AlignmentGrant};
+use models::decoration::Biome;
+use models::weather::WeatherKind;
+use serde::{Deserialize, Serialize};
 
+use crate::actions::DialogueAction;
 use crate::flags::Condition;
 
 /// Broad category for lore entries displayed in the lore page sidebar.
@@ -60,21 +63,106 @@ pub struct DialogueScript {
     /// Scripts without this field are not recorded.
     #[serde(default)]
     pub lore: Option<LoreMeta>,
+    /// When this script is used as a bark: its weight and when it may fire.
+    /// Barks without it always qualify with weight 1.
+    #[serde(default)]
+    pub bark: Option<BarkMeta>,
     /// Ordered sequence of lines/choices.
     pub lines: Vec<DialogueLine>,
 }
 
+/// Selection rules for a script used as a bark (see [`crate::barks`]).
+/// Every requirement given must hold; omitted ones don't constrain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BarkMeta {
+    /// Relative chance among the barks that qualify. Defaults to 1.
+    #[serde(default = "RandomBranch::default_weight")]
+    pub weight: u32,
+    /// Flag and alignment predicate, e.g.
+    /// `AlignmentAtLeast(Darkwoods, 3)`. Defaults to `Always`.
+    #[serde(default = "Condition::always")]
+    pub condition: Condition,
+    /// Weather the bark fits, e.g. `[Rain, Storm]`. Empty means any.
+    #[serde(default)]
+    pub weather: Vec<WeatherKind>,
+    /// Game hours `(from, to)` the bark fits. Wraps past midnight when
+    /// `from > to`, so `(20.0, 5.0)` means night.
+    #[serde(default)]
+    pub hours: Option<(f32, f32)>,
+    /// Biomes the NPC must be standing in. Empty means any.
+    #[serde(default)]
+    pub biome: Vec<Biome>,
+}
+
 /// A single step in a dialogue script.
-#[derive(Debug, Deserialize, Clone)]
+///
+/// `Speech` and `PlayerChoice` are presented to the player; the rest are
+/// control flow the runner resolves on the spot (see `runner::flow`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DialogueLine {
     /// The speaker says something. `text_key` is a locale key.
     Speech { text_key: String },
     /// The player must choose from a set of options.
     PlayerChoice { options: Vec<ChoiceOption> },
+    /// Jump target. A no-op when reached in sequence.
+    Label(String),
+    /// Continue from the line after the matching [`DialogueLine::Label`].
+    /// Labels are found anywhere in the script, nested branches included;
+    /// whatever was queued after the jump is dropped.
+    Jump(String),
+    /// Run `then` when `condition` holds, `else` otherwise.
+    If {
+        condition: Condition,
+        #[serde(default)]
+        then: Vec<DialogueLine>,
+        #[serde(default, rename = "else")]
+        otherwise: Vec<DialogueLine>,
+    },
+    /// Run one of `branches`, picked at random by weight.
+    Random { branches: Vec<RandomBranch> },
+    /// Fire the side effect registered as `id` (see [`crate::actions`]).
+    Action {
+        id: String,
+        #[serde(default)]
+        args: Vec<String>,
+    },
+}
+
+impl DialogueLine {
+    /// Nested line lists this line can run: choice `next`s, `If` arms and
+    /// `Random` branches.
+    pub fn branches(&self) -> Vec<&[DialogueLine]> {
+        match self {
+            Self::PlayerChoice { options } => options.iter().map(|o| o.next.as_slice()).collect(),
+            Self::If {
+                then, otherwise, ..
+            } => vec![then.as_slice(), otherwise.as_slice()],
+            Self::Random { branches } => branches.iter().map(|b| b.lines.as_slice()).collect(),
+            Self::Speech { .. } | Self::Label(_) | Self::Jump(_) | Self::Action { .. } => {
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// One weighted arm of a [`DialogueLine::Random`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RandomBranch {
+    /// Relative chance of this arm. Defaults to 1.
+    #[serde(default = "RandomBranch::default_weight")]
+    pub weight: u32,
+    pub lines: Vec<DialogueLine>,
+}
+
+impl RandomBranch {
+    /// `serde(default)` helper -- every arm equally likely.
+    pub fn default_weight() -> u32 {
+        1
+    }
 }
 
 /// One branch inside a [`DialogueLine::PlayerChoice`].
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChoiceOption {
     /// Locale key for the option label shown to the player.
     pub text_key: String,
@@ -84,14 +172,25 @@ pub struct ChoiceOption {
     #[serde(default)]
     pub flags_required: Vec<String>,
     /// Composable predicate (`AllSet`/`AnySet`/`NoneSet`/`All`/`Any`/`Not`/`Always`)
-    /// over [`crate::flags::DialogueFlags`]. Defaults to `Always`.
+    /// over [`crate::flags::DialogueFlags`], plus `AlignmentAtLeast`/
+    /// `AlignmentBelow`/`Dominant` over the player's alignment. Defaults to
+    /// `Always`.
     #[serde(default = "Condition::always")]
     pub condition: Condition,
     /// Flags to set to `true` when this option is selected.
     pub flags_set: Vec<String>,
-    /// If set, grant +1 to this alignment faction when the choice is made.
+    /// Legacy shorthand: grant +1 to this alignment faction when the choice
+    /// is made. Equivalent to a one-point entry in `alignment_grants` with
+    /// no decay.
     #[serde(default)]
     pub alignment_grant: Option<AlignmentFaction>,
+    /// Signed alignment changes applied when the choice is made, e.g.
+    /// `(faction: Darkwoods, amount: 2, decay: 1)`.
+    #[serde(default)]
+    pub alignment_grants: Vec<AlignmentGrant>,
+    /// Side effects fired when the choice is made, before `next` runs.
+    #[serde(default)]
+    pub actions: Vec<DialogueAction>,
     /// Sub-lines to run after this choice is made.
     pub next: Vec<DialogueLine>,
 }
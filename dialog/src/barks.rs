@@ -1,43 +1,123 @@
 use bevy::prelude::*;
-use rand::seq::IndexedRandom;
+use models::alignment::PlayerAlignment;
+use models::decoration::Biome;
+use models::time::GameClock;
+use models::weather::{WeatherKind, WeatherState};
+use rand::RngExt;
+use std::collections::VecDeque;
 
-use crate::asset::DialogueLine;
-use crate::asset::DialogueScript;
+use crate::asset::{BarkMeta, DialogueLine, DialogueScript};
 use crate::components::BarkPool;
 use crate::events::BarkFired;
+use crate::flags::DialogueFlags;
+use crate::runner::flow::resolve_flow;
 
-/// Strategy for picking which bark to fire from a pool. Default is uniform
-/// random; replace via `app.insert_resource(BarkSelector::Custom(...))` for
-/// weighted, mood-based, or scripted selection.
-#[derive(Resource, Clone, Default)]
-pub enum BarkSelector {
-    #[default]
-    Uniform,
-    Custom(fn(&[Handle<DialogueScript>]) -> Option<Handle<DialogueScript>>),
+/// What the world looks like at the moment a bark is picked. `None` fields
+/// are unknown (e.g. no weather simulation running); a bark that requires
+/// them doesn't qualify.
+pub struct BarkContext<'a> {
+    pub flags: &'a DialogueFlags,
+    pub alignment: &'a PlayerAlignment,
+    pub weather: Option<WeatherKind>,
+    pub hour: Option<f32>,
+    pub biome: Option<Biome>,
+}
+
+impl BarkMeta {
+    /// Whether every requirement of this bark holds in `ctx`.
+    pub fn allows(&self, ctx: &BarkContext) -> bool {
+        let weather_ok =
+            self.weather.is_empty() || ctx.weather.is_some_and(|w| self.weather.contains(&w));
+        let hours_ok = self
+            .hours
+            .is_none_or(|(from, to)| ctx.hour.is_some_and(|h| in_hours(h, from, to)));
+        let biome_ok = self.biome.is_empty() || ctx.biome.is_some_and(|b| self.biome.contains(&b));
+        weather_ok && hours_ok && biome_ok && self.condition.is_satisfied(ctx.flags, ctx.alignment)
+    }
+}
+
+/// `hour` lies in `[from, to)`, wrapping past midnight when `from > to`.
+fn in_hours(hour: f32, from: f32, to: f32) -> bool {
+    if from <= to {
+        (from..to).contains(&hour)
+    } else {
+        hour >= from || hour < to
+    }
+}
+
+/// How barks are picked from a [`BarkPool`]: scripts whose `bark` rules
+/// don't hold right now are skipped, as are the NPC's last
+/// `recent_window` barks while anything else qualifies; the rest are rolled
+/// by weight. Tune via `app.insert_resource(BarkSelector { .. })`.
+#[derive(Resource, Clone, Debug)]
+pub struct BarkSelector {
+    /// How many of an NPC's latest barks to hold back.
+    pub recent_window: usize,
+}
+
+impl Default for BarkSelector {
+    fn default() -> Self {
+        Self { recent_window: 2 }
+    }
 }
 
 impl BarkSelector {
-    pub fn select(
+    /// Index into `candidates` -- `(id, bark rules)` -- of the bark to fire.
+    /// `roll(total)` must return a value in `0..total`.
+    pub fn select<K: PartialEq>(
         &self,
-        barks: &[Handle<DialogueScript>],
-    ) -> Option<Handle<DialogueScript>> {
-        match self {
-            Self::Uniform => {
-                let mut rng = rand::rng();
-                barks.choose(&mut rng).cloned()
-            }
-            Self::Custom(f) => f(barks),
+        candidates: &[(K, Option<&BarkMeta>)],
+        recent: &VecDeque<K>,
+        ctx: &BarkContext,
+        roll: &mut impl FnMut(u32) -> u32,
+    ) -> Option<usize> {
+        let eligible: Vec<(usize, u32)> = candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, meta))| meta.is_none_or(|m| m.allows(ctx)))
+            .map(|(i, (_, meta))| (i, meta.map_or(1, |m| m.weight)))
+            .filter(|(_, weight)| *weight > 0)
+            .collect();
+        let held_back = recent.iter().rev().take(self.recent_window);
+        let fresh: Vec<(usize, u32)> = eligible
+            .iter()
+            .copied()
+            .filter(|(i, _)| {
+                let id = &candidates[*i].0;
+                !held_back.clone().any(|r| r == id)
+            })
+            .collect();
+        let pool = if fresh.is_empty() { eligible } else { fresh };
+
+        let total: u32 = pool.iter().map(|(_, weight)| weight).sum();
+        if total == 0 {
+            return None;
         }
+        let mut pick = roll(total);
+        pool.into_iter().find_map(|(i, weight)| {
+            if pick < weight {
+                return Some(i);
+            }
+            pick -= weight;
+            None
+        })
     }
 }
 
 /// System: fires a bark from nearby NPCs with a [`BarkPool`].
 ///
-/// Only runs in `GameState::Playing`. The bark is emitted as a [`BarkFired`]
-/// message; the UI crate decides how to display it (floating text above the NPC).
+/// Only runs in `GameState::Playing`. The bark's script is resolved like a
+/// conversation (so `If`/`Random` lines work) and its first speech line is
+/// emitted as a [`BarkFired`] message; the UI crate decides how to display
+/// it (floating text above the NPC). Actions in bark scripts are ignored.
+#[allow(clippy::too_many_arguments)]
 pub fn tick_barks(
     time: Res<Time>,
     selector: Res<BarkSelector>,
+    flags: Res<DialogueFlags>,
+    alignment: Res<PlayerAlignment>,
+    weather: Option<Res<WeatherState>>,
+    clock: Option<Res<GameClock>>,
     player_q: Query<&GlobalTransform, Without<BarkPool>>,
     mut bark_q: Query<(Entity, &mut BarkPool, &GlobalTransform)>,
     scripts: Res<Assets<DialogueScript>>,
@@ -47,6 +127,7 @@ pub fn tick_barks(
         return;
     };
     let player_pos = player_tf.translation().truncate();
+    let mut rng = rand::rng();
 
     for (entity, mut pool, tf) in &mut bark_q {
         pool.cooldown.tick(time.delta());
@@ -59,26 +140,130 @@ pub fn tick_barks(
             continue;
         }
 
-        let Some(handle) = selector.select(&pool.barks) else {
+        let ctx = BarkContext {
+            flags: &flags,
+            alignment: &alignment,
+            weather: weather.as_ref().map(|w| w.current),
+            hour: clock.as_ref().map(|c| c.hour),
+            biome: pool.biome,
+        };
+        let candidates: Vec<(AssetId<DialogueScript>, Option<&BarkMeta>)> = pool
+            .barks
+            .iter()
+            .filter_map(|handle| {
+                let script = scripts.get(handle.id())?;
+                Some((handle.id(), script.bark.as_ref()))
+            })
+            .collect();
+        let Some(index) = selector.select(&candidates, &pool.recent, &ctx, &mut |total| {
+            rng.random_range(0..total)
+        }) else {
             continue;
         };
-        let Some(script) = scripts.get(handle.id()) else {
+        let id = candidates[index].0;
+        let Some(script) = scripts.get(id) else {
             continue;
         };
 
-        let text_key = script.lines.iter().find_map(|line| {
-            if let DialogueLine::Speech { text_key } = line {
-                Some(text_key.clone())
-            } else {
-                None
-            }
+        let mut lines = script.lines.clone();
+        let resolved = resolve_flow(script, &mut lines, &flags, &alignment, &mut |total| {
+            rng.random_range(0..total)
         });
+        let Some(DialogueLine::Speech { text_key }) = lines.into_iter().next() else {
+            if let Err(e) = resolved {
+                warn!("Bark script '{}' stopped: {e:?}", script.id);
+            }
+            continue;
+        };
 
-        let Some(text_key) = text_key else { continue };
-
+        pool.recent.push_back(id);
+        while pool.recent.len() > selector.recent_window {
+            pool.recent.pop_front();
+        }
         writer.write(BarkFired {
             npc: entity,
             text_key,
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(src: &str) -> BarkMeta {
+        ron::from_str(src).expect("test bark meta parses")
+    }
+
+    fn ctx<'a>(
+        flags: &'a DialogueFlags,
+        alignment: &'a PlayerAlignment,
+        weather: WeatherKind,
+        hour: f32,
+    ) -> BarkContext<'a> {
+        BarkContext {
+            flags,
+            alignment,
+            weather: Some(weather),
+            hour: Some(hour),
+            biome: Some(Biome::Greenwood),
+        }
+    }
+
+    #[test]
+    fn requirements_follow_the_world() {
+        let flags = DialogueFlags::default();
+        let mut alignment = PlayerAlignment::default();
+        let rain = meta("(weather: [Rain, Storm])");
+        let night = meta("(hours: Some((20.0, 5.0)))");
+        let dark = meta("(condition: AlignmentAtLeast(Darkwoods, 2), biome: [Greenwood])");
+
+        let noon = ctx(&flags, &alignment, WeatherKind::Rain, 12.0);
+        assert!(rain.allows(&noon));
+        assert!(!night.allows(&noon));
+        assert!(!dark.allows(&noon));
+
+        let late = ctx(&flags, &alignment, WeatherKind::Clear, 23.5);
+        assert!(!rain.allows(&late));
+        assert!(night.allows(&late));
+        assert!(night.allows(&ctx(&flags, &alignment, WeatherKind::Clear, 4.0)));
+
+        alignment.grant(models::alignment::AlignmentFaction::Darkwoods);
+        alignment.grant(models::alignment::AlignmentFaction::Darkwoods);
+        assert!(dark.allows(&ctx(&flags, &alignment, WeatherKind::Clear, 9.0)));
+    }
+
+    #[test]
+    fn weights_and_recent_barks_shape_the_pick() {
+        let flags = DialogueFlags::default();
+        let alignment = PlayerAlignment::default();
+        let now = ctx(&flags, &alignment, WeatherKind::Rain, 12.0);
+        let heavy = meta("(weight: 3)");
+        let never = meta("(weather: [Storm])");
+        let candidates = [(0, None), (1, Some(&heavy)), (2, Some(&never))];
+        let selector = BarkSelector::default();
+
+        let mut totals = Vec::new();
+        let mut pick = |roll: u32| {
+            selector.select(&candidates, &VecDeque::new(), &now, &mut |total| {
+                totals.push(total);
+                roll
+            })
+        };
+        assert_eq!(pick(0), Some(0));
+        assert_eq!(pick(1), Some(1));
+        assert_eq!(pick(3), Some(1));
+        assert_eq!(totals, [4, 4, 4]);
+
+        // The last bark is held back while another qualifies...
+        let recent = VecDeque::from([1]);
+        assert_eq!(
+            selector.select(&candidates, &recent, &now, &mut |_| 0),
+            Some(0)
+        );
+        // ...but not when it's the only one left.
+        let recent = VecDeque::from([0, 1]);
+        let only = [(0, None), (1, Some(&heavy))];
+        assert!(selector.select(&only, &recent, &now, &mut |_| 0).is_some());
+    }
+}
@@ -0,0 +1,201 @@
+//! Offline dialogue/locale linter.
+//!
+//! ```text
+//! cargo run -p dialog --bin dialog_lint -- [ROOT] [--strict]
+//! ```
+//!
+//! Parses every `ROOT/assets/dialogue/**/*.dialog.ron`,
+//! `ROOT/assets/quests/*.quest.ron` and `ROOT/assets/locale/*.locale.ron`,
+//! scans `ROOT/*/src/**/*.rs` for locale keys quoted in code, then prints
+//! [`dialog::lint`] findings.
+//! A locale with a `fallback` is checked together with the strings it
+//! inherits, so `es-MX` only needs the keys it overrides.
+//! Exits non-zero on any error, or on any warning with `--strict`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use dialog::asset::DialogueScript;
+use dialog::lint::{keys_quoted_in, lint, Finding, LintInput, Severity};
+use dialog::locale::{parse_locale, DEFAULT_LOCALE_CODE};
+use dialog::quest::QuestDefinition;
+
+const SCRIPT_EXT: &str = ".dialog.ron";
+const QUEST_EXT: &str = ".quest.ron";
+const LOCALE_EXT: &str = ".locale.ron";
+
+fn main() -> ExitCode {
+    let mut root = PathBuf::from(".");
+    let mut strict = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--strict" => strict = true,
+            _ => root = PathBuf::from(arg),
+        }
+    }
+
+    let mut parse_errors = Vec::new();
+    let mut input = LintInput::default();
+
+    for path in files_with_suffix(&root.join("assets/dialogue"), SCRIPT_EXT) {
+        let display = display_path(&root, &path);
+        match read_ron::<DialogueScript>(&path) {
+            Ok(script) => input.scripts.push((display, script)),
+            Err(message) => parse_errors.push(Finding::Parse {
+                path: display,
+                message,
+            }),
+        }
+    }
+
+    for path in files_with_suffix(&root.join("assets/quests"), QUEST_EXT) {
+        let display = display_path(&root, &path);
+        match read_ron::<QuestDefinition>(&path) {
+            Ok(quest) => input.quests.push((display, quest)),
+            Err(message) => parse_errors.push(Finding::Parse {
+                path: display,
+                message,
+            }),
+        }
+    }
+
+    let mut fallbacks = HashMap::new();
+    for path in files_with_suffix(&root.join("assets/locale"), LOCALE_EXT) {
+        let display = display_path(&root, &path);
+        let Some(code) = display
+            .rsplit('/')
+            .next()
+            .and_then(|name| name.strip_suffix(LOCALE_EXT))
+            .map(str::to_owned)
+        else {
+            continue;
+        };
+        let parsed = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| parse_locale(&text).map_err(|e| e.to_string()));
+        match parsed {
+            Ok((meta, table)) => {
+                if let Some(fallback) = meta.fallback {
+                    fallbacks.insert(code.clone(), fallback);
+                }
+                input.locales.push((code, table));
+            }
+            Err(message) => parse_errors.push(Finding::Parse {
+                path: display,
+                message,
+            }),
+        }
+    }
+    inherit_fallbacks(&mut input.locales, &fallbacks);
+
+    let sources: Vec<String> = crate_dirs(&root)
+        .iter()
+        .flat_map(|dir| files_with_suffix(&dir.join("src"), ".rs"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .collect();
+    input.code_keys = keys_quoted_in(input.locales.iter().flat_map(|(_, t)| t.keys()), &sources);
+
+    let findings: Vec<Finding> = parse_errors.into_iter().chain(lint(&input)).collect();
+    let mut errors = 0_usize;
+    let mut warnings = 0_usize;
+    for finding in &findings {
+        match finding.severity() {
+            Severity::Error => {
+                errors += 1;
+                eprintln!("error: {finding}");
+            }
+            Severity::Warning => {
+                warnings += 1;
+                eprintln!("warning: {finding}");
+            }
+        }
+    }
+    eprintln!(
+        "{} scripts, {} quests, {} locales: {errors} error(s), {warnings} warning(s)",
+        input.scripts.len(),
+        input.quests.len(),
+        input.locales.len()
+    );
+
+    if errors > 0 || (strict && warnings > 0) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Fill each locale's table with the keys it inherits through its
+/// `fallback` chain, stopping short of the default locale (which every
+/// chain ends in, and which is what "missing" is measured against).
+fn inherit_fallbacks(
+    locales: &mut [(String, HashMap<String, String>)],
+    fallbacks: &HashMap<String, String>,
+) {
+    let originals: HashMap<String, HashMap<String, String>> = locales.iter().cloned().collect();
+    for (code, table) in locales.iter_mut() {
+        let mut seen = vec![code.clone()];
+        let mut next = fallbacks.get(code.as_str());
+        while let Some(parent) = next {
+            if parent == DEFAULT_LOCALE_CODE || seen.contains(parent) {
+                break;
+            }
+            for (key, value) in originals.get(parent).into_iter().flatten() {
+                table.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+            seen.push(parent.clone());
+            next = fallbacks.get(parent.as_str());
+        }
+    }
+}
+
+fn read_ron<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    ron::from_str(&text).map_err(|e| e.to_string())
+}
+
+/// Every file under `dir` (recursively) whose name ends in `suffix`,
+/// sorted so output is stable between runs.
+fn files_with_suffix(dir: &Path, suffix: &str) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.ends_with(suffix))
+            {
+                out.push(path);
+            }
+        }
+    }
+    out.sort();
+    out
+}
+
+/// Workspace member directories: direct children of `root` with a `src/`.
+fn crate_dirs(root: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.join("src").is_dir())
+        .collect()
+}
+
+fn display_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
@@ -1,10 +1,18 @@
+use std::collections::{HashSet, VecDeque};
+
 use bevy::prelude::*;
+use models::decoration::Biome;
+use serde::{Deserialize, Serialize};
 
 use crate::asset::DialogueScript;
 
 /// Place on an NPC entity to give it scripted dialogue.
 #[derive(Component, Debug)]
 pub struct Talker {
+    /// Stable identity for greeting state, e.g.
+    /// `"<map seed>/<area x>,<area y>/Mordred"`. Keys [`GreetedNpcs`] so a
+    /// reloaded game remembers who already spoke.
+    pub id: String,
     /// The scripted greeting to run when the player interacts.
     pub greeting: Handle<DialogueScript>,
     /// Whether the greeting fires every interaction or only once.
@@ -14,16 +22,18 @@ pub struct Talker {
 }
 
 impl Talker {
-    pub fn new(greeting: Handle<DialogueScript>) -> Self {
+    pub fn new(id: impl Into<String>, greeting: Handle<DialogueScript>) -> Self {
         Self {
+            id: id.into(),
             greeting,
             repeat_greeting: false,
             has_greeted: false,
         }
     }
 
-    pub fn repeating(greeting: Handle<DialogueScript>) -> Self {
+    pub fn repeating(id: impl Into<String>, greeting: Handle<DialogueScript>) -> Self {
         Self {
+            id: id.into(),
             greeting,
             repeat_greeting: true,
             has_greeted: false,
@@ -31,15 +41,58 @@ impl Talker {
     }
 }
 
-/// Place on an NPC entity to give it random ambient bark lines.
+/// [`Talker::id`]s of every NPC on the current map whose one-shot greeting
+/// has already run. Persisted by `save::SavePlugin`; `Talker::has_greeted`
+/// is only the per-entity mirror and is lost when the NPC despawns. The
+/// level crate drops other maps' entries when a map is entered.
+#[derive(Resource, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GreetedNpcs(pub HashSet<String>);
+
+impl GreetedNpcs {
+    pub fn contains(&self, id: &str) -> bool {
+        self.0.contains(id)
+    }
+
+    pub fn insert(&mut self, id: impl Into<String>) {
+        self.0.insert(id.into());
+    }
+}
+
+/// Place on an NPC entity to give it ambient bark lines.
 #[derive(Component, Debug)]
 pub struct BarkPool {
-    /// Set of possible bark scripts. One is chosen at random on trigger.
+    /// Set of possible bark scripts. [`crate::barks::BarkSelector`] picks
+    /// one that fits the moment on trigger.
     pub barks: Vec<Handle<DialogueScript>>,
     /// Player must be within this distance (pixels) to trigger a bark.
     pub trigger_radius_px: f32,
     /// Minimum time between consecutive barks from this NPC.
     pub cooldown: Timer,
+    /// Biome the NPC stands in, for barks that require one.
+    pub biome: Option<Biome>,
+    /// This NPC's latest barks, newest last, kept out of the next picks.
+    pub(crate) recent: VecDeque<AssetId<DialogueScript>>,
+}
+
+impl BarkPool {
+    pub fn new(
+        barks: Vec<Handle<DialogueScript>>,
+        trigger_radius_px: f32,
+        cooldown: Timer,
+    ) -> Self {
+        Self {
+            barks,
+            trigger_radius_px,
+            cooldown,
+            biome: None,
+            recent: VecDeque::new(),
+        }
+    }
+
+    pub fn in_biome(mut self, biome: Biome) -> Self {
+        self.biome = Some(biome);
+        self
+    }
 }
 
 /// Inserted on the player entity when they are in interact range of a Talker.
@@ -1,4 +1,8 @@
 use bevy::prelude::{Entity, Message};
+use models::enemy::EnemyKind;
+use models::npc::NpcKind;
+
+use crate::quest::AreaVisit;
 
 /// Send this to begin a scripted dialogue with an NPC entity.
 #[derive(Message, Debug, Clone)]
@@ -6,6 +10,13 @@ pub struct StartDialogue {
     pub npc: Entity,
 }
 
+/// Emitted when a [`StartDialogue`] actually opens a conversation, i.e.
+/// the NPC had a greeting to run.
+#[derive(Message, Debug, Clone)]
+pub struct DialogueStarted {
+    pub npc: Entity,
+}
+
 /// Emitted by the runner when a speech line is ready to display.
 #[derive(Message, Debug, Clone)]
 pub struct DialogueLineReady {
@@ -39,3 +50,42 @@ pub struct BarkFired {
     /// Locale key for the bark text.
     pub text_key: String,
 }
+
+/// Something happened that quest objectives may count. Sent by gameplay
+/// crates.
+#[derive(Message, Debug, Clone)]
+pub enum QuestProgress {
+    EnteredArea(AreaVisit),
+    /// A conversation started with an NPC of this kind.
+    TalkedTo(NpcKind),
+    /// An enemy of this kind was killed outright.
+    Defeated(EnemyKind),
+    /// An enemy of this kind was purified.
+    Purified(EnemyKind),
+}
+
+/// Send this to start a quest by id. Ignored once the quest has started.
+#[derive(Message, Debug, Clone)]
+pub struct StartQuest {
+    pub id: String,
+}
+
+/// Emitted when a quest is added to the [`crate::quest::QuestLog`].
+#[derive(Message, Debug, Clone)]
+pub struct QuestStarted {
+    pub id: String,
+}
+
+/// Emitted when a quest moves on to a later stage.
+#[derive(Message, Debug, Clone)]
+pub struct QuestAdvanced {
+    pub id: String,
+    /// Index of the new current stage.
+    pub stage: usize,
+}
+
+/// Emitted when a quest's last stage is finished.
+#[derive(Message, Debug, Clone)]
+pub struct QuestCompleted {
+    pub id: String,
+}
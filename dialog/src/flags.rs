@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use serde::Deserialize;
+use models::alignment::{AlignmentFaction, PlayerAlignment};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Persistent key/value flag store used by dialogue choices.
@@ -7,7 +8,8 @@ use std::collections::HashMap;
 /// Flags drive conditional branches: a choice option is only shown when its
 /// [`Condition`] (or legacy `flags_required` AND-list) is satisfied.
 /// Selecting a choice sets all entries in its `flags_set` list to `true`.
-#[derive(Resource, Debug, Default, Clone)]
+/// Persisted by `save::SavePlugin`.
+#[derive(Resource, Debug, Default, Clone, Serialize, Deserialize)]
 pub struct DialogueFlags(pub HashMap<String, bool>);
 
 impl DialogueFlags {
@@ -24,11 +26,12 @@ impl DialogueFlags {
     }
 }
 
-/// Composable predicate over [`DialogueFlags`]. Lets choice options express
-/// AND/OR/NOT logic in RON instead of just an AND-list of required flags.
+/// Composable predicate over [`DialogueFlags`] and [`PlayerAlignment`]. Lets
+/// choice options express AND/OR/NOT logic in RON instead of just an
+/// AND-list of required flags, and react to which side the player has taken.
 ///
 /// Equivalent to `Condition::AllSet(flags_required)` for legacy entries.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Condition {
     /// All flags must be set.
     AllSet(Vec<String>),
@@ -36,6 +39,14 @@ pub enum Condition {
     AnySet(Vec<String>),
     /// None of the flags may be set.
     NoneSet(Vec<String>),
+    /// Standing with the faction is at least this (0-10), e.g.
+    /// `AlignmentAtLeast(Darkwoods, 6)`.
+    AlignmentAtLeast(AlignmentFaction, u8),
+    /// Standing with the faction is below this.
+    AlignmentBelow(AlignmentFaction, u8),
+    /// The faction is the player's dominant one
+    /// ([`PlayerAlignment::dominant`]).
+    Dominant(AlignmentFaction),
     /// Logical AND of nested conditions.
     All(Vec<Condition>),
     /// Logical OR of nested conditions.
@@ -52,14 +63,17 @@ impl Condition {
         Self::Always
     }
 
-    pub fn is_satisfied(&self, flags: &DialogueFlags) -> bool {
+    pub fn is_satisfied(&self, flags: &DialogueFlags, alignment: &PlayerAlignment) -> bool {
         match self {
             Self::AllSet(keys) => flags.all_set(keys),
             Self::AnySet(keys) => keys.iter().any(|k| flags.is_set(k)),
             Self::NoneSet(keys) => keys.iter().all(|k| !flags.is_set(k)),
-            Self::All(conds) => conds.iter().all(|c| c.is_satisfied(flags)),
-            Self::Any(conds) => conds.iter().any(|c| c.is_satisfied(flags)),
-            Self::Not(cond) => !cond.is_satisfied(flags),
+            Self::AlignmentAtLeast(faction, min) => alignment.get(*faction) >= *min,
+            Self::AlignmentBelow(faction, max) => alignment.get(*faction) < *max,
+            Self::Dominant(faction) => alignment.dominant() == *faction,
+            Self::All(conds) => conds.iter().all(|c| c.is_satisfied(flags, alignment)),
+            Self::Any(conds) => conds.iter().any(|c| c.is_satisfied(flags, alignment)),
+            Self::Not(cond) => !cond.is_satisfied(flags, alignment),
             Self::Always => true,
         }
     }
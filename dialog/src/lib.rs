@@ -1,11 +1,15 @@
+pub mod actions;
 pub mod asset;
 pub mod barks;
 pub mod components;
 pub mod events;
 pub mod flags;
 pub mod history;
+pub mod lint;
 pub mod locale;
+pub mod message;
 pub mod plugin;
+pub mod quest;
 pub mod runner;
 
 pub use plugin::DialogPlugin;
@@ -0,0 +1,585 @@
+//! Offline content checks for dialogue scripts, quests and locale tables.
+//!
+//! Pure analysis over already-parsed data; the `dialog_lint` binary does
+//! the file walking and exit code. [`lint`] reports:
+//!
+//! - locale keys referenced by a script or quest but missing from a locale,
+//! - locale keys nothing references (scripts, quests or quoted in Rust
+//!   source),
+//! - duplicate [`DialogueScript::id`]s,
+//! - flags read by a `Condition` / `flags_required` but never set by a
+//!   choice, a quest stage or a completed quest,
+//! - `PlayerChoice` options whose gate can never pass,
+//! - `Jump`s to labels the script doesn't define.
+//!
+//! Missing keys in the default locale are errors; in other locales they
+//! are warnings, since [`crate::locale::LocaleMap`] falls back to the
+//! default at runtime.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+use crate::asset::{ChoiceOption, DialogueLine, DialogueScript};
+use crate::flags::Condition;
+use crate::locale::DEFAULT_LOCALE_CODE;
+use crate::quest::{ObjectiveGoal, QuestDefinition};
+
+/// How seriously a [`Finding`] should be taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// One problem found in the content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    /// A file failed to parse.
+    Parse { path: String, message: String },
+    /// `key` is used by `script` (a script or quest path) but absent from
+    /// `locale`.
+    MissingKey {
+        locale: String,
+        key: String,
+        script: String,
+    },
+    /// `key` is defined in `locale` but nothing uses it.
+    UnusedKey { locale: String, key: String },
+    /// Two or more files declare the same script `id`.
+    DuplicateId { id: String, paths: Vec<String> },
+    /// `flag` gates something in `script` (a script or quest path) but
+    /// nothing ever sets it.
+    UnsetFlag { flag: String, script: String },
+    /// The option labelled `text_key` in `script` can never be shown.
+    UnreachableChoice { script: String, text_key: String },
+    /// `script` jumps to `label`, which it never defines.
+    UnknownLabel { script: String, label: String },
+}
+
+impl Finding {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::MissingKey { locale, .. } if locale != DEFAULT_LOCALE_CODE => Severity::Warning,
+            Self::UnusedKey { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { path, message } => write!(f, "{path}: parse error: {message}"),
+            Self::MissingKey {
+                locale,
+                key,
+                script,
+            } => write!(f, "{script}: key '{key}' missing from {locale}"),
+            Self::UnusedKey { locale, key } => write!(f, "{locale}: key '{key}' is never used"),
+            Self::DuplicateId { id, paths } => {
+                write!(f, "duplicate script id '{id}' in {}", paths.join(", "))
+            }
+            Self::UnsetFlag { flag, script } => {
+                write!(f, "{script}: flag '{flag}' is read but never set")
+            }
+            Self::UnreachableChoice { script, text_key } => {
+                write!(f, "{script}: choice '{text_key}' can never be shown")
+            }
+            Self::UnknownLabel { script, label } => {
+                write!(f, "{script}: jump to undefined label '{label}'")
+            }
+        }
+    }
+}
+
+/// Everything the linter looks at. `path`s are only used in messages.
+#[derive(Debug, Default)]
+pub struct LintInput {
+    /// `(path, script)` for every parsed `.dialog.ron`.
+    pub scripts: Vec<(String, DialogueScript)>,
+    /// `(path, quest)` for every parsed `.quest.ron`.
+    pub quests: Vec<(String, QuestDefinition)>,
+    /// `(locale code, table)` for every parsed `.locale.ron`.
+    pub locales: Vec<(String, HashMap<String, String>)>,
+    /// Locale keys known to be referenced from code (see [`keys_quoted_in`]).
+    pub code_keys: HashSet<String>,
+}
+
+/// Every key of `keys` that appears as a quoted string literal in any of
+/// `sources`. Catches `locale.get("ui.pause.title")` and friends.
+pub fn keys_quoted_in<'a>(
+    keys: impl IntoIterator<Item = &'a String>,
+    sources: &[String],
+) -> HashSet<String> {
+    keys.into_iter()
+        .filter(|key| {
+            let quoted = format!("\"{key}\"");
+            sources.iter().any(|src| src.contains(&quoted))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Run every check. Findings come back sorted, errors first.
+pub fn lint(input: &LintInput) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    // Script id uniqueness.
+    let mut ids: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (path, script) in &input.scripts {
+        ids.entry(script.id.as_str())
+            .or_default()
+            .push(path.clone());
+    }
+    for (id, paths) in ids {
+        if paths.len() > 1 {
+            findings.push(Finding::DuplicateId {
+                id: id.to_owned(),
+                paths,
+            });
+        }
+    }
+
+    // Locale coverage.
+    let mut used: HashSet<&str> = HashSet::new();
+    let script_uses = input.scripts.iter().map(|(path, script)| {
+        let mut keys = Vec::new();
+        script_keys(script, &mut keys);
+        (path, keys)
+    });
+    let quest_uses = input.quests.iter().map(|(path, quest)| {
+        let mut keys = Vec::new();
+        quest_keys(quest, &mut keys);
+        (path, keys)
+    });
+    for (path, keys) in script_uses.chain(quest_uses) {
+        let keys: BTreeSet<&str> = keys.into_iter().collect();
+        for (locale, table) in &input.locales {
+            for key in &keys {
+                if !table.contains_key(*key) {
+                    findings.push(Finding::MissingKey {
+                        locale: locale.clone(),
+                        key: (*key).to_owned(),
+                        script: path.clone(),
+                    });
+                }
+            }
+        }
+        used.extend(keys);
+    }
+    for (locale, table) in &input.locales {
+        let unused: BTreeSet<&String> = table
+            .keys()
+            .filter(|k| !used.contains(k.as_str()) && !input.code_keys.contains(*k))
+            .collect();
+        for key in unused {
+            findings.push(Finding::UnusedKey {
+                locale: locale.clone(),
+                key: key.clone(),
+            });
+        }
+    }
+
+    // Flags.
+    let mut set: HashSet<&str> = HashSet::new();
+    for (_, script) in &input.scripts {
+        for_each_option(&script.lines, &mut |opt| {
+            set.extend(opt.flags_set.iter().map(String::as_str));
+        });
+    }
+    for (_, quest) in &input.quests {
+        set.extend(quest.flags_on_complete.iter().map(String::as_str));
+        for stage in &quest.stages {
+            set.extend(stage.flags_set.iter().map(String::as_str));
+        }
+    }
+    for (path, quest) in &input.quests {
+        let mut read: BTreeSet<&str> = BTreeSet::new();
+        if let Some(start) = &quest.start {
+            condition_flags(start, &mut read);
+        }
+        for objective in quest.stages.iter().flat_map(|s| &s.objectives) {
+            if let ObjectiveGoal::Flags(condition) = &objective.goal {
+                condition_flags(condition, &mut read);
+            }
+        }
+        for flag in read.into_iter().filter(|f| !set.contains(f)) {
+            findings.push(Finding::UnsetFlag {
+                flag: flag.to_owned(),
+                script: path.clone(),
+            });
+        }
+    }
+    for (path, script) in &input.scripts {
+        let mut read: BTreeSet<&str> = BTreeSet::new();
+        walk(&script.lines, &mut |line| match line {
+            DialogueLine::PlayerChoice { options } => {
+                for opt in options {
+                    read.extend(opt.flags_required.iter().map(String::as_str));
+                    condition_flags(&opt.condition, &mut read);
+                }
+            }
+            DialogueLine::If { condition, .. } => condition_flags(condition, &mut read),
+            _ => {}
+        });
+        for flag in read.into_iter().filter(|f| !set.contains(f)) {
+            findings.push(Finding::UnsetFlag {
+                flag: flag.to_owned(),
+                script: path.clone(),
+            });
+        }
+        for_each_option(&script.lines, &mut |opt| {
+            if !option_reachable(opt, &set) {
+                findings.push(Finding::UnreachableChoice {
+                    script: path.clone(),
+                    text_key: opt.text_key.clone(),
+                });
+            }
+        });
+    }
+
+    // Labels.
+    for (path, script) in &input.scripts {
+        let mut labels: HashSet<&str> = HashSet::new();
+        let mut jumps: BTreeSet<&str> = BTreeSet::new();
+        walk(&script.lines, &mut |line| match line {
+            DialogueLine::Label(l) => {
+                labels.insert(l);
+            }
+            DialogueLine::Jump(l) => {
+                jumps.insert(l);
+            }
+            _ => {}
+        });
+        for label in jumps.into_iter().filter(|l| !labels.contains(l)) {
+            findings.push(Finding::UnknownLabel {
+                script: path.clone(),
+                label: label.to_owned(),
+            });
+        }
+    }
+
+    findings.sort_by_key(|f| std::cmp::Reverse(f.severity()));
+    findings
+}
+
+/// Every locale key a script references.
+fn script_keys<'a>(script: &'a DialogueScript, out: &mut Vec<&'a str>) {
+    out.push(&script.speaker_key);
+    if let Some(lore) = &script.lore {
+        out.push(&lore.topic);
+    }
+    line_keys(&script.lines, out);
+}
+
+/// Every locale key a quest references.
+fn quest_keys<'a>(quest: &'a QuestDefinition, out: &mut Vec<&'a str>) {
+    out.push(&quest.title_key);
+    out.push(&quest.summary_key);
+    for stage in &quest.stages {
+        out.push(&stage.text_key);
+        out.extend(stage.objectives.iter().map(|o| o.text_key.as_str()));
+    }
+}
+
+fn line_keys<'a>(lines: &'a [DialogueLine], out: &mut Vec<&'a str>) {
+    walk(lines, &mut |line| match line {
+        DialogueLine::Speech { text_key } => out.push(text_key),
+        DialogueLine::PlayerChoice { options } => {
+            out.extend(options.iter().map(|o| o.text_key.as_str()));
+        }
+        _ => {}
+    });
+}
+
+/// Visit every line, including ones nested in branches.
+fn walk<'a>(lines: &'a [DialogueLine], f: &mut impl FnMut(&'a DialogueLine)) {
+    for line in lines {
+        f(line);
+        for branch in line.branches() {
+            walk(branch, f);
+        }
+    }
+}
+
+/// Visit every choice option, including ones nested in other branches.
+fn for_each_option<'a>(lines: &'a [DialogueLine], f: &mut impl FnMut(&'a ChoiceOption)) {
+    walk(lines, &mut |line| {
+        if let DialogueLine::PlayerChoice { options } = line {
+            options.iter().for_each(&mut *f);
+        }
+    });
+}
+
+fn condition_flags<'a>(condition: &'a Condition, out: &mut BTreeSet<&'a str>) {
+    match condition {
+        Condition::AllSet(keys) | Condition::AnySet(keys) | Condition::NoneSet(keys) => {
+            out.extend(keys.iter().map(String::as_str));
+        }
+        Condition::All(conds) | Condition::Any(conds) => {
+            for c in conds {
+                condition_flags(c, out);
+            }
+        }
+        Condition::Not(c) => condition_flags(c, out),
+        Condition::AlignmentAtLeast(..)
+        | Condition::AlignmentBelow(..)
+        | Condition::Dominant(_)
+        | Condition::Always => {}
+    }
+}
+
+/// Flags never set anywhere are always false; settable flags could be
+/// either. An option is unreachable when its gate is false either way.
+fn option_reachable(opt: &ChoiceOption, settable: &HashSet<&str>) -> bool {
+    let required = opt
+        .flags_required
+        .iter()
+        .all(|f| settable.contains(f.as_str()));
+    required && can_hold(&opt.condition, settable, true)
+}
+
+/// Whether `condition` can evaluate to `want` for some assignment of the
+/// settable flags. Alignment is only known at runtime, so alignment
+/// conditions can go either way.
+fn can_hold(condition: &Condition, settable: &HashSet<&str>, want: bool) -> bool {
+    let can_be_true = |f: &String| settable.contains(f.as_str());
+    match (condition, want) {
+        (Condition::Always, want) => want,
+        (Condition::AllSet(keys), true) => keys.iter().all(can_be_true),
+        (Condition::AllSet(keys), false) => !keys.is_empty(),
+        (Condition::AnySet(keys), true) => keys.iter().any(can_be_true),
+        (Condition::AnySet(_), false) => true,
+        (Condition::NoneSet(_), true) => true,
+        (Condition::NoneSet(keys), false) => keys.iter().any(can_be_true),
+        (
+            Condition::AlignmentAtLeast(..)
+            | Condition::AlignmentBelow(..)
+            | Condition::Dominant(_),
+            _,
+        ) => true,
+        (Condition::All(conds), true) | (Condition::Any(conds), false) => {
+            conds.iter().all(|c| can_hold(c, settable, want))
+        }
+        (Condition::All(conds), false) | (Condition::Any(conds), true) => {
+            conds.iter().any(|c| can_hold(c, settable, want))
+        }
+        (Condition::Not(c), want) => can_hold(c, settable, !want),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use models::alignment::AlignmentFaction;
+
+    use super::*;
+
+    fn script(src: &str) -> DialogueScript {
+        ron::from_str(src).expect("test script parses")
+    }
+
+    fn locale(keys: &[&str]) -> HashMap<String, String> {
+        keys.iter()
+            .map(|k| ((*k).to_owned(), String::new()))
+            .collect()
+    }
+
+    const GATED: &str = r#"DialogueScript(
+        id: "gated",
+        speaker_key: "npc.name",
+        keyword_tags: [],
+        lines: [
+            PlayerChoice(options: [
+                ChoiceOption(
+                    text_key: "opt.open",
+                    flags_required: [],
+                    flags_set: ["met"],
+                    next: [],
+                ),
+                ChoiceOption(
+                    text_key: "opt.revisit",
+                    condition: AllSet(["met"]),
+                    flags_set: [],
+                    next: [],
+                ),
+                ChoiceOption(
+                    text_key: "opt.never",
+                    condition: Not(Always),
+                    flags_set: [],
+                    next: [],
+                ),
+                ChoiceOption(
+                    text_key: "opt.loyal",
+                    condition: All([Dominant(Cities), Not(AlignmentBelow(Cities, 5))]),
+                    flags_set: [],
+                    next: [],
+                ),
+                ChoiceOption(
+                    text_key: "opt.ghost",
+                    flags_required: ["ghost"],
+                    flags_set: [],
+                    next: [],
+                ),
+            ]),
+        ],
+    )"#;
+
+    fn gated_input() -> LintInput {
+        LintInput {
+            scripts: vec![("gated.dialog.ron".to_owned(), script(GATED))],
+            locales: vec![(
+                DEFAULT_LOCALE_CODE.to_owned(),
+                locale(&[
+                    "npc.name",
+                    "opt.open",
+                    "opt.revisit",
+                    "opt.never",
+                    "opt.loyal",
+                    "opt.ghost",
+                    "ui.title",
+                ]),
+            )],
+            ..LintInput::default()
+        }
+    }
+
+    const QUEST: &str = r#"QuestDefinition(
+        id: "errand",
+        title_key: "quest.title",
+        summary_key: "quest.summary",
+        start: Some(AllSet(["met"])),
+        stages: [
+            (
+                text_key: "quest.stage.0",
+                objectives: [
+                    (text_key: "quest.talk", goal: TalkTo(Drizella)),
+                ],
+                flags_set: ["errand_begun"],
+            ),
+            (
+                text_key: "quest.stage.1",
+                objectives: [
+                    (text_key: "quest.wait", goal: Flags(AllSet(["errand_begun", "owl"]))),
+                ],
+            ),
+        ],
+        flags_on_complete: ["ghost"],
+    )"#;
+
+    #[test]
+    fn quests_use_keys_and_set_and_read_flags() {
+        let mut input = gated_input();
+        input.quests.push((
+            "errand.quest.ron".to_owned(),
+            ron::from_str(QUEST).expect("test quest parses"),
+        ));
+        let quest_keys = [
+            "quest.title",
+            "quest.summary",
+            "quest.stage.0",
+            "quest.talk",
+            "quest.stage.1",
+        ];
+        input.locales[0]
+            .1
+            .extend(quest_keys.map(|k| (k.to_owned(), String::new())));
+
+        let findings = lint(&input);
+        // Used by the quest, and one objective key is missing.
+        assert!(!findings.iter().any(|f| matches!(
+            f,
+            Finding::UnusedKey { key, .. } if key.starts_with("quest.")
+        )));
+        assert!(findings.contains(&Finding::MissingKey {
+            locale: DEFAULT_LOCALE_CODE.to_owned(),
+            key: "quest.wait".to_owned(),
+            script: "errand.quest.ron".to_owned(),
+        }));
+        // `ghost` is set on completion; `owl` is set nowhere.
+        let unset: Vec<(&str, &str)> = findings
+            .iter()
+            .filter_map(|f| match f {
+                Finding::UnsetFlag { flag, script } => Some((flag.as_str(), script.as_str())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(unset, vec![("owl", "errand.quest.ron")]);
+    }
+
+    #[test]
+    fn flags_and_reachability() {
+        let findings = lint(&gated_input());
+        assert!(findings.contains(&Finding::UnsetFlag {
+            flag: "ghost".to_owned(),
+            script: "gated.dialog.ron".to_owned(),
+        }));
+        let unreachable: Vec<&str> = findings
+            .iter()
+            .filter_map(|f| match f {
+                Finding::UnreachableChoice { text_key, .. } => Some(text_key.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(unreachable, vec!["opt.never", "opt.ghost"]);
+    }
+
+    #[test]
+    fn alignment_gates_read_no_flags_and_stay_reachable() {
+        let findings = lint(&gated_input());
+        assert!(!findings.iter().any(|f| matches!(
+            f,
+            Finding::UnreachableChoice { text_key, .. } if text_key == "opt.loyal"
+        )));
+
+        let gate = Condition::Any(vec![
+            Condition::AlignmentAtLeast(AlignmentFaction::Darkwoods, 6),
+            Condition::AllSet(vec!["met".to_owned()]),
+        ]);
+        let mut read = BTreeSet::new();
+        condition_flags(&gate, &mut read);
+        assert_eq!(read.into_iter().collect::<Vec<_>>(), vec!["met"]);
+    }
+
+    #[test]
+    fn locale_coverage() {
+        let mut input = gated_input();
+        input.locales.push((
+            "es-ES".to_owned(),
+            locale(&["npc.name", "opt.open", "opt.loyal"]),
+        ));
+        let findings = lint(&input);
+        assert!(findings.contains(&Finding::UnusedKey {
+            locale: DEFAULT_LOCALE_CODE.to_owned(),
+            key: "ui.title".to_owned(),
+        }));
+        let missing = findings
+            .iter()
+            .filter(|f| matches!(f, Finding::MissingKey { .. }))
+            .collect::<Vec<_>>();
+        assert_eq!(missing.len(), 3);
+        assert!(missing.iter().all(|f| f.severity() == Severity::Warning));
+
+        input.code_keys = keys_quoted_in(
+            &input.locales[0].1.keys().cloned().collect::<Vec<_>>(),
+            &["locale.get(\"ui.title\")".to_owned()],
+        );
+        assert!(!lint(&input)
+            .iter()
+            .any(|f| matches!(f, Finding::UnusedKey { .. })));
+    }
+
+    #[test]
+    fn duplicate_ids() {
+        let input = LintInput {
+            scripts: vec![
+                ("a.dialog.ron".to_owned(), script(GATED)),
+                ("b.dialog.ron".to_owned(), script(GATED)),
+            ],
+            ..LintInput::default()
+        };
+        assert!(lint(&input).contains(&Finding::DuplicateId {
+            id: "gated".to_owned(),
+            paths: vec!["a.dialog.ron".to_owned(), "b.dialog.ron".to_owned()],
+        }));
+    }
+}
@@ -1,52 +1,193 @@
-use bevy::asset::{io::Reader, Asset, AssetId, AssetLoader, LoadContext};
+use bevy::asset::{io::Reader, Asset, AssetEvent, AssetLoader, LoadContext};
 use bevy::prelude::*;
 use bevy::reflect::TypePath;
 use models::settings::GameSettings;
-use std::collections::HashMap;
+use ron::error::SpannedError;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Mutex;
 
-/// All supported locales as `(code, display_name)` pairs.
-/// Add entries here when adding new locale files under `assets/locale/`.
-pub const AVAILABLE_LOCALES: &[(&str, &str)] = &[("en-US", "English"), ("es-ES", "Espanol")];
+use crate::message::{format_message, LocaleArgs};
 
-/// A flat key→string locale map loaded from a `.locale.ron` file.
+/// Default fallback locale for missing keys (English). Every chain ends here.
+pub const DEFAULT_LOCALE_CODE: &str = "en-US";
+
+/// Folder holding the `*.locale.ron` files.
+const LOCALE_DIR: &str = "locale";
+const LOCALE_EXT: &str = ".locale.ron";
+
+/// Manifest listing the locale codes shipped in [`LOCALE_DIR`]. The web
+/// asset reader can't list folders, so this is how every platform
+/// discovers locales; adding a language means adding a file and a line.
+const LOCALE_INDEX_PATH: &str = "locale/index.locales.ron";
+
+/// Reading direction declared by a locale.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum TextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+/// Header of a `.locale.ron` file.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LocaleMeta {
+    /// Name shown in the language picker, in the language itself.
+    pub name: String,
+    #[serde(default)]
+    pub direction: TextDirection,
+    /// Asset path of a font the locale needs, if the default lacks glyphs.
+    #[serde(default)]
+    pub font: Option<String>,
+    /// Next locale to try for missing keys (`es-MX` -> `es-ES`). The chain
+    /// always ends at [`DEFAULT_LOCALE_CODE`].
+    #[serde(default)]
+    pub fallback: Option<String>,
+}
+
+/// On-disk shape of a `.locale.ron`: `LocaleFile(meta: (...), strings: {...})`.
+/// A bare `{ "key": "value" }` map is still accepted, with default metadata.
+#[derive(Debug, Deserialize)]
+struct LocaleFile {
+    meta: LocaleMeta,
+    strings: HashMap<String, String>,
+}
+
+/// Parse the text of a `.locale.ron` file in either supported shape.
+/// Errors are reported against the header form.
+pub fn parse_locale(text: &str) -> Result<(LocaleMeta, HashMap<String, String>), SpannedError> {
+    match ron::from_str::<LocaleFile>(text) {
+        Ok(file) => Ok((file.meta, file.strings)),
+        Err(file_err) => ron::from_str::<HashMap<String, String>>(text)
+            .map(|strings| (LocaleMeta::default(), strings))
+            .map_err(|_| file_err),
+    }
+}
+
+/// One loaded `.locale.ron` file. `code` comes from the file name.
 #[derive(Asset, TypePath, Debug, Default, Clone)]
-pub struct LocaleAsset(pub HashMap<String, String>);
+pub struct LocaleAsset {
+    pub code: String,
+    pub meta: LocaleMeta,
+    pub strings: HashMap<String, String>,
+}
 
-/// Default fallback locale for missing keys (English).
-pub const DEFAULT_LOCALE_CODE: &str = "en-US";
+/// `locale/index.locales.ron`: the code of every shipped locale, e.g.
+/// `["en-US", "es-ES", "es-MX"]`.
+#[derive(Asset, TypePath, Debug, Default, Clone, Deserialize)]
+pub struct LocaleIndex(pub Vec<String>);
+
+/// Every locale known so far: metadata from loaded files plus the handles
+/// that keep them (and the index) loaded and hot-reloadable.
+#[derive(Resource, Debug, Default)]
+pub struct LocaleCatalog {
+    meta: BTreeMap<String, LocaleMeta>,
+    handles: HashMap<String, Handle<LocaleAsset>>,
+    index: Handle<LocaleIndex>,
+}
+
+impl LocaleCatalog {
+    /// `(code, display name)` for every loaded locale, sorted by code.
+    pub fn available(&self) -> Vec<(&str, &str)> {
+        self.meta
+            .iter()
+            .map(|(code, meta)| (code.as_str(), meta.name.as_str()))
+            .collect()
+    }
+
+    /// Header of `code`'s file, once it has loaded.
+    pub fn meta(&self, code: &str) -> Option<&LocaleMeta> {
+        self.meta.get(code)
+    }
+
+    /// Display name for `code`, or the code itself until its file loads.
+    pub fn display_name<'a>(&'a self, code: &'a str) -> &'a str {
+        self.meta
+            .get(code)
+            .map(|m| m.name.as_str())
+            .filter(|name| !name.is_empty())
+            .unwrap_or(code)
+    }
 
-/// Runtime resource holding the currently active locale data + a fallback
-/// chain. Lookup order:
-///   1. exact-key in active locale
-///   2. exact-key in default locale (e.g. en-US) -- if different
-///   3. the key itself (debug fallthrough)
+    /// Lookup order for `code`: itself, then each declared `fallback`,
+    /// then [`DEFAULT_LOCALE_CODE`]. Cycles are cut at the first repeat.
+    pub fn fallback_chain(&self, code: &str) -> Vec<String> {
+        let mut chain: Vec<String> = Vec::new();
+        let mut next = Some(code.to_owned());
+        while let Some(code) = next.take() {
+            if chain.contains(&code) {
+                break;
+            }
+            next = self.meta.get(&code).and_then(|m| m.fallback.clone());
+            chain.push(code);
+        }
+        if !chain.iter().any(|c| c == DEFAULT_LOCALE_CODE) {
+            chain.push(DEFAULT_LOCALE_CODE.to_owned());
+        }
+        chain
+    }
+}
+
+/// Runtime resource holding the active locale and its fallback chain.
+/// Lookup order: each locale in [`LocaleCatalog::fallback_chain`], then
+/// the key itself (debug fallthrough).
 #[derive(Resource, Debug, Default)]
 pub struct LocaleMap {
-    data: HashMap<String, String>,
-    fallback: HashMap<String, String>,
+    /// `(code, strings)`, active locale first.
+    chain: Vec<(String, HashMap<String, String>)>,
     active_code: String,
+    /// Dev mode: warn once per key the active locale doesn't have.
+    warn_missing: bool,
+    reported: Mutex<HashSet<String>>,
 }
 
 impl LocaleMap {
-    /// Look up a locale key. Falls through active → default → key.
+    /// Look up a locale key. Falls through the chain, then to the key.
     pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
-        if let Some(value) = self.data.get(key) {
-            return value.as_str();
+        self.lookup(key).map_or(key, |(value, _)| value)
+    }
+
+    /// Look up `key` like [`LocaleMap::get`] and fill its placeholders from
+    /// `args` (see [`crate::message`]). Plural rules follow whichever locale
+    /// the string actually came from.
+    pub fn format(&self, key: &str, args: &LocaleArgs) -> String {
+        let (pattern, code) = self.lookup(key).unwrap_or((key, DEFAULT_LOCALE_CODE));
+        if args.is_empty() {
+            return pattern.to_owned();
         }
-        if let Some(value) = self.fallback.get(key) {
-            return value.as_str();
+        format_message(pattern, args, code)
+    }
+
+    /// `(value, code of the locale it came from)`.
+    fn lookup<'a>(&'a self, key: &str) -> Option<(&'a str, &'a str)> {
+        let found = self
+            .chain
+            .iter()
+            .find_map(|(code, strings)| strings.get(key).map(|v| (v.as_str(), code.as_str())));
+        if self.warn_missing && found.is_none_or(|(_, code)| code != self.active_code) {
+            self.report_missing(key, found.map(|(_, code)| code));
         }
-        key
+        found
     }
 
-    /// Replace the active locale data.
-    pub fn load_from(&mut self, asset: &LocaleAsset) {
-        self.data = asset.0.clone();
+    fn report_missing(&self, key: &str, used: Option<&str>) {
+        let Ok(mut reported) = self.reported.lock() else {
+            return;
+        };
+        if reported.insert(key.to_owned()) {
+            match used {
+                Some(code) => warn!("Locale {}: '{key}' missing, using {code}", self.active_code),
+                None => warn!("Locale {}: '{key}' missing everywhere", self.active_code),
+            }
+        }
     }
 
-    /// Replace the default-locale fallback table.
-    pub fn load_fallback(&mut self, asset: &LocaleAsset) {
-        self.fallback = asset.0.clone();
+    /// Replace the lookup chain (active locale first).
+    pub fn set_chain(&mut self, chain: Vec<(String, HashMap<String, String>)>) {
+        self.chain = chain;
+        if let Ok(mut reported) = self.reported.lock() {
+            reported.clear();
+        }
     }
 
     /// Track the currently active locale code.
@@ -58,6 +199,11 @@ impl LocaleMap {
     pub fn active_code(&self) -> &str {
         &self.active_code
     }
+
+    /// Turn dev-mode missing-key warnings on or off.
+    pub fn set_warn_missing(&mut self, on: bool) {
+        self.warn_missing = on;
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -77,13 +223,25 @@ impl AssetLoader for LocaleAssetLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &(),
-        _ctx: &mut LoadContext<'_>,
+        ctx: &mut LoadContext<'_>,
     ) -> Result<LocaleAsset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
         let text = std::str::from_utf8(&bytes)?;
-        let map: HashMap<String, String> = ron::from_str(text)?;
-        Ok(LocaleAsset(map))
+        let (meta, strings) = parse_locale(text)?;
+        let code = ctx
+            .path()
+            .path()
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(LOCALE_EXT))
+            .unwrap_or_default()
+            .to_owned();
+        Ok(LocaleAsset {
+            code,
+            meta,
+            strings,
+        })
     }
 
     fn extensions(&self) -> &[&str] {
@@ -91,56 +249,125 @@ impl AssetLoader for LocaleAssetLoader {
     }
 }
 
+/// Loads `index.locales.ron` into [`LocaleIndex`].
+#[derive(Default, TypePath)]
+pub struct LocaleIndexLoader;
+
+impl AssetLoader for LocaleIndexLoader {
+    type Asset = LocaleIndex;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _ctx: &mut LoadContext<'_>,
+    ) -> Result<LocaleIndex, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let text = std::str::from_utf8(&bytes)?;
+        Ok(ron::from_str(text)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["locales.ron"]
+    }
+}
+
 // ---------------------------------------------------------------------------
-// Handle resource
+// Discovery + chain sync
 // ---------------------------------------------------------------------------
 
-/// Holds the active locale asset handle so it isn't dropped.
-#[derive(Resource)]
-pub struct ActiveLocale(pub Handle<LocaleAsset>);
+fn locale_path(code: &str) -> String {
+    format!("{LOCALE_DIR}/{code}{LOCALE_EXT}")
+}
 
-/// Holds the default-locale asset handle (e.g. `en-US`) used as fallback
-/// when the active locale is missing keys.
-#[derive(Resource)]
-pub struct FallbackLocale(pub Handle<LocaleAsset>);
+/// Startup: load the default locale right away and the index that names
+/// the rest.
+pub fn load_locales(mut catalog: ResMut<LocaleCatalog>, asset_server: Res<AssetServer>) {
+    let handle = asset_server.load(locale_path(DEFAULT_LOCALE_CODE));
+    catalog
+        .handles
+        .insert(DEFAULT_LOCALE_CODE.to_owned(), handle);
+    catalog.index = asset_server.load(LOCALE_INDEX_PATH);
+}
 
-/// System: copies the active locale asset into [`LocaleMap`] once loaded,
-/// and re-copies whenever the active locale changes (language switching).
-///
-/// Tracks the last-loaded [`AssetId`] so it only re-runs after a language
-/// change, not every frame.
-pub fn sync_locale(
-    active: Res<ActiveLocale>,
-    assets: Res<Assets<LocaleAsset>>,
-    mut locale_map: ResMut<LocaleMap>,
-    mut loaded_id: Local<Option<AssetId<LocaleAsset>>>,
+/// System: once the locale index loads (or changes on disk), keep a handle
+/// to every locale it lists so they load and show up in the catalog.
+pub fn discover_locales(
+    mut events: MessageReader<AssetEvent<LocaleIndex>>,
+    indexes: Res<Assets<LocaleIndex>>,
+    asset_server: Res<AssetServer>,
+    mut catalog: ResMut<LocaleCatalog>,
 ) {
-    let current_id = active.0.id();
-    if *loaded_id == Some(current_id) {
+    let index_id = catalog.index.id();
+    let loaded = events.read().any(|event| {
+        matches!(event, AssetEvent::Added { id } | AssetEvent::Modified { id } if *id == index_id)
+    });
+    if !loaded {
         return;
     }
-    if let Some(asset) = assets.get(current_id) {
-        locale_map.load_from(asset);
-        *loaded_id = Some(current_id);
+    let Some(index) = indexes.get(index_id) else {
+        return;
+    };
+    for code in &index.0 {
+        if !catalog.handles.contains_key(code) {
+            let handle = asset_server.load(locale_path(code));
+            catalog.handles.insert(code.clone(), handle);
+        }
     }
 }
 
-/// System: copies the fallback locale asset into [`LocaleMap::fallback`].
-/// Runs once per fallback-handle change.
-pub fn sync_fallback_locale(
-    fallback: Res<FallbackLocale>,
+/// System: rebuild the catalog metadata and [`LocaleMap`]'s chain when a
+/// locale file loads or changes on disk (hot reload), or the language
+/// setting changes. Locales in the chain that aren't loaded yet are
+/// requested; the chain is rebuilt again once they arrive.
+pub fn sync_locale_chain(
+    settings: Res<GameSettings>,
+    mut events: MessageReader<AssetEvent<LocaleAsset>>,
     assets: Res<Assets<LocaleAsset>>,
+    asset_server: Res<AssetServer>,
+    mut catalog: ResMut<LocaleCatalog>,
     mut locale_map: ResMut<LocaleMap>,
-    mut loaded_id: Local<Option<AssetId<LocaleAsset>>>,
+    mut language: Local<String>,
 ) {
-    let current_id = fallback.0.id();
-    if *loaded_id == Some(current_id) {
+    let mut dirty = *language != settings.language;
+    for event in events.read() {
+        if let AssetEvent::Added { id } | AssetEvent::Modified { id } = event {
+            if let Some(asset) = assets.get(*id) {
+                catalog.meta.insert(asset.code.clone(), asset.meta.clone());
+                dirty = true;
+            }
+        }
+    }
+    if !dirty {
         return;
     }
-    if let Some(asset) = assets.get(current_id) {
-        locale_map.load_fallback(asset);
-        *loaded_id = Some(current_id);
+
+    language.clone_from(&settings.language);
+    let codes = catalog.fallback_chain(&language);
+    for code in &codes {
+        if !catalog.handles.contains_key(code) {
+            let handle = asset_server.load(locale_path(code));
+            catalog.handles.insert(code.clone(), handle);
+        }
     }
+
+    let chain = codes
+        .into_iter()
+        .filter_map(|code| {
+            let strings = catalog
+                .handles
+                .get(&code)
+                .and_then(|h| assets.get(h.id()))?
+                .strings
+                .clone();
+            Some((code, strings))
+        })
+        .collect();
+    locale_map.set_chain(chain);
+    locale_map.set_active_code(language.as_str());
 }
 
 // ---------------------------------------------------------------------------
@@ -152,52 +379,134 @@ pub fn sync_fallback_locale(
 /// locale asset finishes loading and the text will fill in once available.
 ///
 /// Pair with `Text::new("")` (or a placeholder); [`apply_locale_keys`] rewrites
-/// the `Text` whenever [`LocaleMap`] changes (asset load, language switch).
+/// the `Text` whenever [`LocaleMap`] changes (asset load, language switch)
+/// or the component itself does -- so updating `args` re-renders the text.
 #[derive(Component, Debug, Clone)]
-pub struct LocaleKey(pub String);
+pub struct LocaleKey {
+    pub key: String,
+    /// Placeholder values for the string (see [`crate::message`]).
+    pub args: LocaleArgs,
+}
 
 impl LocaleKey {
     pub fn new(key: impl Into<String>) -> Self {
-        Self(key.into())
+        Self {
+            key: key.into(),
+            args: LocaleArgs::default(),
+        }
+    }
+
+    pub fn with_args(key: impl Into<String>, args: LocaleArgs) -> Self {
+        Self {
+            key: key.into(),
+            args,
+        }
     }
 }
 
 /// System: refreshes every `Text` tagged with [`LocaleKey`] whenever the
 /// active locale changes (asset arrives, language switch). Also runs when a
-/// [`LocaleKey`] is freshly added so newly-spawned entities get text on the
-/// next frame even if `LocaleMap` itself didn't change.
+/// [`LocaleKey`] is freshly added or its args change, so newly-spawned
+/// entities get text on the next frame even if `LocaleMap` itself didn't
+/// change.
 ///
-/// Both filtered (`Added`) and unfiltered queries access `&mut Text`, so they
-/// must live behind a [`ParamSet`] to avoid B0001.
+/// Both filtered (`Changed`) and unfiltered queries access `&mut Text`, so
+/// they must live behind a [`ParamSet`] to avoid B0001.
+#[allow(clippy::type_complexity)]
 pub fn apply_locale_keys(
     locale_map: Res<LocaleMap>,
     mut queries: ParamSet<(
         Query<(&LocaleKey, &mut Text)>,
-        Query<(&LocaleKey, &mut Text), Added<LocaleKey>>,
+        Query<(&LocaleKey, &mut Text), Changed<LocaleKey>>,
     )>,
 ) {
     if locale_map.is_changed() {
         for (key, mut text) in &mut queries.p0() {
-            **text = locale_map.get(&key.0).to_owned();
+            **text = locale_map.format(&key.key, &key.args);
         }
         return;
     }
     for (key, mut text) in &mut queries.p1() {
-        **text = locale_map.get(&key.0).to_owned();
+        **text = locale_map.format(&key.key, &key.args);
     }
 }
 
-/// System: when [`GameSettings::language`] changes, reload the locale asset.
-pub fn sync_language(
-    settings: Res<GameSettings>,
-    mut active: ResMut<ActiveLocale>,
-    mut locale_map: ResMut<LocaleMap>,
-    asset_server: Res<AssetServer>,
-) {
-    if !settings.is_changed() {
-        return;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(fallback: Option<&str>) -> LocaleMeta {
+        LocaleMeta {
+            fallback: fallback.map(str::to_owned),
+            ..LocaleMeta::default()
+        }
+    }
+
+    #[test]
+    fn chains_follow_declared_fallbacks_and_end_at_default() {
+        let mut catalog = LocaleCatalog::default();
+        catalog.meta.insert("es-MX".into(), meta(Some("es-ES")));
+        catalog.meta.insert("es-ES".into(), meta(None));
+        catalog.meta.insert("xx-A".into(), meta(Some("xx-B")));
+        catalog.meta.insert("xx-B".into(), meta(Some("xx-A")));
+
+        assert_eq!(catalog.fallback_chain("es-MX"), ["es-MX", "es-ES", "en-US"]);
+        assert_eq!(catalog.fallback_chain("en-US"), ["en-US"]);
+        assert_eq!(catalog.fallback_chain("xx-A"), ["xx-A", "xx-B", "en-US"]);
+        assert_eq!(catalog.fallback_chain("fr-FR"), ["fr-FR", "en-US"]);
+    }
+
+    #[test]
+    fn lookup_walks_the_chain() {
+        let table = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+                .collect::<HashMap<_, _>>()
+        };
+        let mut map = LocaleMap::default();
+        map.set_chain(vec![
+            ("es-MX".into(), table(&[("a", "ándale")])),
+            ("es-ES".into(), table(&[("a", "vale"), ("b", "hola")])),
+            ("en-US".into(), table(&[("c", "hi")])),
+        ]);
+        assert_eq!(map.get("a"), "ándale");
+        assert_eq!(map.get("b"), "hola");
+        assert_eq!(map.get("c"), "hi");
+        assert_eq!(map.get("d"), "d");
+    }
+
+    #[test]
+    fn both_file_shapes_parse() {
+        let (meta, strings) = parse_locale(
+            r#"LocaleFile(meta: (name: "Test", fallback: Some("es-ES")), strings: {"k": "v"})"#,
+        )
+        .expect("header form parses");
+        assert_eq!(meta.name, "Test");
+        assert_eq!(meta.fallback.as_deref(), Some("es-ES"));
+        assert_eq!(strings.get("k").map(String::as_str), Some("v"));
+
+        let (meta, strings) = parse_locale(r#"{"k": "v"}"#).expect("flat form parses");
+        assert!(meta.name.is_empty());
+        assert_eq!(strings.len(), 1);
+    }
+
+    #[test]
+    fn headers_carry_direction_and_font() {
+        let (meta, _) = parse_locale(
+            r#"LocaleFile(
+                meta: (name: "العربية", direction: Rtl, font: Some("fonts/NotoNaskhArabic.ttf")),
+                strings: {},
+            )"#,
+        )
+        .expect("header parses");
+        assert_eq!(meta.direction, TextDirection::Rtl);
+        assert_eq!(meta.font.as_deref(), Some("fonts/NotoNaskhArabic.ttf"));
+
+        // Omitted fields fall back to the defaults.
+        let (meta, _) = parse_locale(r#"LocaleFile(meta: (name: "Español"), strings: {})"#)
+            .expect("header parses");
+        assert_eq!(meta.direction, TextDirection::Ltr);
+        assert_eq!(meta.font, None);
     }
-    let path = format!("locale/{}.locale.ron", settings.language);
-    *active = ActiveLocale(asset_server.load(path));
-    locale_map.set_active_code(&settings.language);
 }
@@ -0,0 +1,248 @@
+//! Locale message formatting: placeholders, plurals and selects.
+//!
+//! Locale strings may embed a small ICU-style syntax, filled from
+//! [`LocaleArgs`] supplied by the caller:
+//!
+//! - `{player}` -- replaced by the `player` argument.
+//! - `{count, plural, =0 {none} one {# petal} other {# petals}}` -- picks a
+//!   case by exact value (`=N`) or by the locale's [`PluralCategory`], then
+//!   replaces `#` with the number.
+//! - `{gender, select, female {her} male {his} other {their}}` -- picks the
+//!   case named by the argument, else `other`.
+//!
+//! Cases nest. A placeholder whose argument is missing is left as written,
+//! the same way a missing key falls through to the key itself.
+
+/// Named arguments for one formatted string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocaleArgs(Vec<(String, String)>);
+
+impl LocaleArgs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder form of [`LocaleArgs::set`].
+    pub fn with(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    /// Set `name`, replacing any earlier value.
+    pub fn set(&mut self, name: impl Into<String>, value: impl ToString) {
+        let name = name.into();
+        let value = value.to_string();
+        match self.0.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v = value,
+            None => self.0.push((name, value)),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// CLDR plural category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Selector used for this category inside a `plural` placeholder.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Plural category of the integer `n` in `locale` (e.g. `"es-ES"`).
+/// Languages without rules here use the English ones.
+pub fn plural_category(locale: &str, n: u64) -> PluralCategory {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    match language {
+        // CLDR es: 1 is "one"; non-zero multiples of a million are "many"
+        // ("un millón de hojas").
+        "es" if n == 1 => PluralCategory::One,
+        "es" if n != 0 && n.is_multiple_of(1_000_000) => PluralCategory::Many,
+        _ if n == 1 => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Fill `pattern` from `args` using `locale`'s plural rules.
+pub fn format_message(pattern: &str, args: &LocaleArgs, locale: &str) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    render(pattern, args, locale, None, &mut out);
+    out
+}
+
+/// `hash` is the number `#` stands for inside a plural case.
+fn render(pattern: &str, args: &LocaleArgs, locale: &str, hash: Option<&str>, out: &mut String) {
+    let mut rest = pattern;
+    while let Some(i) = rest.find(|c: char| c == '{' || (c == '#' && hash.is_some())) {
+        out.push_str(&rest[..i]);
+        let tail = &rest[i..];
+        if let Some(after) = tail.strip_prefix('#') {
+            out.push_str(hash.unwrap_or("#"));
+            rest = after;
+            continue;
+        }
+        let Some(end) = closing_brace(tail) else {
+            out.push_str(tail);
+            return;
+        };
+        render_placeholder(&tail[1..end], args, locale, out);
+        rest = &tail[end + 1..];
+    }
+    out.push_str(rest);
+}
+
+/// Byte index of the `}` matching the `{` that `s` starts with.
+fn closing_brace(s: &str) -> Option<usize> {
+    let mut depth = 0_usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn render_placeholder(inner: &str, args: &LocaleArgs, locale: &str, out: &mut String) {
+    let mut parts = inner.splitn(3, ',');
+    let name = parts.next().unwrap_or_default().trim();
+    let kind = parts.next().map(str::trim);
+    let cases = parts.next().unwrap_or_default();
+    let Some(value) = args.get(name) else {
+        out.push('{');
+        out.push_str(inner);
+        out.push('}');
+        return;
+    };
+    match kind {
+        None => out.push_str(value),
+        Some("plural") => {
+            let cases = parse_cases(cases);
+            let exact = format!("={value}");
+            let category = value
+                .parse::<u64>()
+                .map_or(PluralCategory::Other, |n| plural_category(locale, n));
+            let body = find_case(&cases, &exact)
+                .or_else(|| find_case(&cases, category.as_str()))
+                .or_else(|| find_case(&cases, "other"));
+            if let Some(body) = body {
+                render(body, args, locale, Some(value), out);
+            }
+        }
+        Some("select") => {
+            let cases = parse_cases(cases);
+            if let Some(body) = find_case(&cases, value).or_else(|| find_case(&cases, "other")) {
+                render(body, args, locale, None, out);
+            }
+        }
+        Some(_) => out.push_str(value),
+    }
+}
+
+/// `sel {body} sel {body} ...` into `(sel, body)` pairs.
+fn parse_cases(mut s: &str) -> Vec<(&str, &str)> {
+    let mut cases = Vec::new();
+    loop {
+        s = s.trim_start();
+        let Some(open) = s.find('{') else {
+            return cases;
+        };
+        let selector = s[..open].trim();
+        let Some(end) = closing_brace(&s[open..]) else {
+            return cases;
+        };
+        cases.push((selector, &s[open + 1..open + end]));
+        s = &s[open + end + 1..];
+    }
+}
+
+fn find_case<'a>(cases: &[(&str, &'a str)], selector: &str) -> Option<&'a str> {
+    cases
+        .iter()
+        .find(|(sel, _)| *sel == selector)
+        .map(|(_, body)| *body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PETALS: &str = "{count, plural, =0 {no petals} one {# petal} other {# petals}}";
+
+    #[test]
+    fn substitutes_and_keeps_unknown_placeholders() {
+        let args = LocaleArgs::new().with("player", "Rowan");
+        assert_eq!(
+            format_message("Welcome, {player}. {missing}", &args, "en-US"),
+            "Welcome, Rowan. {missing}"
+        );
+    }
+
+    #[test]
+    fn plurals_follow_the_locale() {
+        let fmt =
+            |n: u64, locale| format_message(PETALS, &LocaleArgs::new().with("count", n), locale);
+        assert_eq!(fmt(0, "en-US"), "no petals");
+        assert_eq!(fmt(1, "en-US"), "1 petal");
+        assert_eq!(fmt(3, "en-US"), "3 petals");
+
+        assert_eq!(plural_category("en-US", 1_000_000), PluralCategory::Other);
+        assert_eq!(plural_category("es-ES", 1_000_000), PluralCategory::Many);
+        assert_eq!(plural_category("es-ES", 1), PluralCategory::One);
+        let hojas = "{n, plural, one {# hoja} many {un millón de hojas} other {# hojas}}";
+        let args = LocaleArgs::new().with("n", 1_000_000);
+        assert_eq!(format_message(hojas, &args, "es-ES"), "un millón de hojas");
+    }
+
+    #[test]
+    fn select_nests_and_falls_back_to_other() {
+        let pattern =
+            "{gender, select, female {{name} lost her staff} other {{name} lost their staff}}";
+        let args = LocaleArgs::new()
+            .with("name", "Mab")
+            .with("gender", "female");
+        assert_eq!(
+            format_message(pattern, &args, "en-US"),
+            "Mab lost her staff"
+        );
+        let args = LocaleArgs::new()
+            .with("name", "Kit")
+            .with("gender", "unknown");
+        assert_eq!(
+            format_message(pattern, &args, "en-US"),
+            "Kit lost their staff"
+        );
+    }
+}
@@ -1,22 +1,29 @@
 use bevy::prelude::*;
 use models::alignment::PlayerAlignment;
 use models::game_states::GameState;
-use models::settings::GameSettings;
 
+use crate::actions::{DialogueActionAppExt, DialogueActions};
 use crate::asset::{DialogueScript, DialogueScriptLoader};
 use crate::barks::{tick_barks, BarkSelector};
+use crate::components::GreetedNpcs;
 use crate::events::{
-    BarkFired, ChoiceMade, ChoicesReady, DialogueEnded, DialogueLineReady, StartDialogue,
+    BarkFired, ChoiceMade, ChoicesReady, DialogueEnded, DialogueLineReady, DialogueStarted,
+    QuestAdvanced, QuestCompleted, QuestProgress, QuestStarted, StartDialogue, StartQuest,
 };
 use crate::flags::DialogueFlags;
 use crate::history::LoreBook;
 use crate::locale::{
-    apply_locale_keys, sync_fallback_locale, sync_language, sync_locale, ActiveLocale,
-    FallbackLocale, LocaleAsset, LocaleAssetLoader, LocaleMap, DEFAULT_LOCALE_CODE,
+    apply_locale_keys, discover_locales, load_locales, sync_locale_chain, LocaleAsset,
+    LocaleAssetLoader, LocaleCatalog, LocaleIndex, LocaleIndexLoader, LocaleMap,
+};
+use crate::quest::{
+    load_quests, start_quest_action, update_quests, QuestDefinition, QuestDefinitionLoader,
+    QuestLibrary, QuestLog, START_QUEST,
 };
 use crate::runner::{
-    advance_runner, detect_interact_input, detect_interact_range, handle_choice, on_dialogue_ended,
-    start_dialogue, DialogueRunner, DialogueTarget,
+    advance_runner, detect_interact_input, detect_interact_range, handle_choice,
+    has_saved_dialogue, on_dialogue_ended, resume_dialogue, snapshot_runner, start_dialogue,
+    DialogueRunner, DialogueSnapshot, DialogueTarget,
 };
 
 pub struct DialogPlugin;
@@ -26,43 +33,64 @@ impl Plugin for DialogPlugin {
         // Assets & loaders
         app.init_asset::<DialogueScript>()
             .init_asset::<LocaleAsset>()
+            .init_asset::<LocaleIndex>()
+            .init_asset::<QuestDefinition>()
             .init_asset_loader::<DialogueScriptLoader>()
-            .init_asset_loader::<LocaleAssetLoader>();
+            .init_asset_loader::<LocaleAssetLoader>()
+            .init_asset_loader::<LocaleIndexLoader>()
+            .init_asset_loader::<QuestDefinitionLoader>();
 
         // Resources
         app.init_resource::<DialogueFlags>()
+            .init_resource::<GreetedNpcs>()
+            .init_resource::<DialogueActions>()
             .init_resource::<LoreBook>()
-            .init_resource::<LocaleMap>()
+            .init_resource::<QuestLog>()
+            .init_resource::<QuestLibrary>()
+            .init_resource::<LocaleCatalog>()
             .init_resource::<DialogueRunner>()
             .init_resource::<DialogueTarget>()
+            .init_resource::<DialogueSnapshot>()
             .init_resource::<PlayerAlignment>()
             .init_resource::<BarkSelector>();
 
+        // Dev builds report every key the active locale is missing.
+        let mut locale_map = LocaleMap::default();
+        locale_map.set_warn_missing(cfg!(feature = "warn_missing_keys"));
+        app.insert_resource(locale_map);
+
         // Messages
         app.add_message::<StartDialogue>()
+            .add_message::<DialogueStarted>()
             .add_message::<DialogueLineReady>()
             .add_message::<ChoicesReady>()
             .add_message::<ChoiceMade>()
             .add_message::<DialogueEnded>()
-            .add_message::<BarkFired>();
+            .add_message::<BarkFired>()
+            .add_message::<QuestProgress>()
+            .add_message::<StartQuest>()
+            .add_message::<QuestStarted>()
+            .add_message::<QuestAdvanced>()
+            .add_message::<QuestCompleted>();
+
+        app.register_dialogue_action(START_QUEST, start_quest_action);
 
-        // Startup: load the locale specified in GameSettings (set by SavePlugin).
-        app.add_systems(Startup, load_initial_locale);
+        // Startup: load the default locale and the locale index, and load
+        // the quest definitions.
+        app.add_systems(Startup, (load_locales, load_quests));
 
-        // Locale sync and language switching (runs always). `apply_locale_keys`
-        // must run after `sync_locale`/`sync_fallback_locale` so freshly-loaded
-        // strings reach `LocaleKey`-tagged Text nodes the same frame.
+        // Locale discovery, reloads and language switching (runs always).
+        // `apply_locale_keys` must run after `sync_locale_chain` so
+        // freshly-loaded strings reach `LocaleKey`-tagged Text nodes the same
+        // frame.
         app.add_systems(
             Update,
-            (
-                sync_locale,
-                sync_fallback_locale,
-                sync_language,
-                apply_locale_keys,
-            )
-                .chain(),
+            (discover_locales, sync_locale_chain, apply_locale_keys).chain(),
         );
 
+        // Quest progress (runs always: flags also change mid-dialogue).
+        app.add_systems(Update, update_quests);
+
         // Playing: range detection, interact input, barks, start_dialogue
         app.add_systems(
             Update,
@@ -71,6 +99,7 @@ impl Plugin for DialogPlugin {
                 detect_interact_input,
                 tick_barks,
                 start_dialogue,
+                resume_dialogue.run_if(has_saved_dialogue),
             )
                 .run_if(in_state(GameState::Playing)),
         );
@@ -78,24 +107,14 @@ impl Plugin for DialogPlugin {
         // Dialogue state: runner systems
         app.add_systems(
             Update,
-            (advance_runner, handle_choice, on_dialogue_ended)
+            (
+                advance_runner,
+                handle_choice,
+                on_dialogue_ended,
+                snapshot_runner,
+            )
                 .chain()
                 .run_if(in_state(GameState::Dialogue)),
         );
     }
 }
-
-fn load_initial_locale(
-    mut commands: Commands,
-    mut locale_map: ResMut<LocaleMap>,
-    asset_server: Res<AssetServer>,
-    settings: Res<GameSettings>,
-) {
-    let active_path = format!("locale/{}.locale.ron", settings.language);
-    commands.insert_resource(ActiveLocale(asset_server.load(active_path)));
-
-    let fallback_path = format!("locale/{DEFAULT_LOCALE_CODE}.locale.ron");
-    commands.insert_resource(FallbackLocale(asset_server.load(fallback_path)));
-
-    locale_map.set_active_code(&settings.language);
-}
@@ -0,0 +1,147 @@
+use bevy::asset::{io::Reader, Asset, AssetLoader, LoadContext};
+use bevy::reflect::TypePath;
+use models::decoration::Biome;
+use models::enemy::EnemyKind;
+use models::npc::NpcKind;
+use serde::Deserialize;
+
+use crate::flags::Condition;
+
+/// A quest loaded from a `.quest.ron` file: an ordered list of stages, each
+/// finished once all of its objectives are.
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct QuestDefinition {
+    /// Stable identifier, used by [`super::QuestLog`] and the `start_quest`
+    /// dialogue action.
+    pub id: String,
+    pub title_key: String,
+    /// Locale key for the journal's one-paragraph description.
+    pub summary_key: String,
+    /// Starts the quest on its own once it holds. `None` means only the
+    /// `start_quest` action (or a [`crate::events::StartQuest`]) does.
+    #[serde(default)]
+    pub start: Option<Condition>,
+    pub stages: Vec<QuestStage>,
+    /// Flags set when the last stage is finished.
+    #[serde(default)]
+    pub flags_on_complete: Vec<String>,
+}
+
+/// One step of a quest.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuestStage {
+    /// Locale key for the journal text while this stage is current.
+    pub text_key: String,
+    pub objectives: Vec<Objective>,
+    /// Flags set when this stage is finished.
+    #[serde(default)]
+    pub flags_set: Vec<String>,
+}
+
+/// A single thing to do within a stage.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Objective {
+    pub text_key: String,
+    pub goal: ObjectiveGoal,
+}
+
+/// What finishes an [`Objective`]. NPCs and enemies are named by their
+/// [`NpcKind`] / [`EnemyKind`] variant (`TalkTo(Drizella)`).
+#[derive(Debug, Clone, Deserialize)]
+pub enum ObjectiveGoal {
+    /// The condition holds over [`crate::flags::DialogueFlags`]. Stays done
+    /// once met.
+    Flags(Condition),
+    /// The player walks into a matching area.
+    EnterArea(AreaMatch),
+    /// The player starts a conversation with this NPC.
+    TalkTo(NpcKind),
+    /// Enemies of this kind killed outright.
+    Defeat {
+        enemy: EnemyKind,
+        #[serde(default = "one")]
+        count: u32,
+    },
+    /// Enemies of this kind purified back into animals.
+    Purify {
+        enemy: EnemyKind,
+        #[serde(default = "one")]
+        count: u32,
+    },
+}
+
+fn one() -> u32 {
+    1
+}
+
+impl ObjectiveGoal {
+    /// How many matching events finish the objective.
+    pub fn target(&self) -> u32 {
+        match self {
+            Self::Defeat { count, .. } | Self::Purify { count, .. } => *count,
+            Self::Flags(_) | Self::EnterArea(_) | Self::TalkTo(_) => 1,
+        }
+    }
+}
+
+/// Which areas an [`ObjectiveGoal::EnterArea`] accepts.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum AreaMatch {
+    Any,
+    Biome(Biome),
+    /// The area holding this NPC's encounter.
+    Npc(NpcKind),
+    /// The area holding the map's exit.
+    Exit,
+}
+
+impl AreaMatch {
+    pub fn matches(&self, visit: &AreaVisit) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Biome(biome) => visit.biome == *biome,
+            Self::Npc(kind) => visit.npc == Some(*kind),
+            Self::Exit => visit.exit,
+        }
+    }
+}
+
+/// The area the player just entered, as reported by the level crate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AreaVisit {
+    pub biome: Biome,
+    /// NPC met in this area, if any.
+    pub npc: Option<NpcKind>,
+    /// Whether the map's exit is here.
+    pub exit: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Asset loader
+// ---------------------------------------------------------------------------
+
+/// Loads `.quest.ron` files into [`QuestDefinition`] assets.
+#[derive(Default, TypePath)]
+pub struct QuestDefinitionLoader;
+
+impl AssetLoader for QuestDefinitionLoader {
+    type Asset = QuestDefinition;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _ctx: &mut LoadContext<'_>,
+    ) -> Result<QuestDefinition, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let text = std::str::from_utf8(&bytes)?;
+        Ok(ron::from_str(text)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["quest.ron"]
+    }
+}
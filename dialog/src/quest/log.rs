@@ -0,0 +1,238 @@
+use bevy::prelude::*;
+use models::alignment::PlayerAlignment;
+use serde::{Deserialize, Serialize};
+
+use crate::events::QuestProgress;
+use crate::flags::DialogueFlags;
+
+use super::asset::{ObjectiveGoal, QuestDefinition, QuestStage};
+
+/// Persistent record of every quest the player has started.
+/// Persisted by `save::SavePlugin`.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestLog {
+    pub quests: Vec<QuestEntry>,
+}
+
+impl QuestLog {
+    pub fn get(&self, id: &str) -> Option<&QuestEntry> {
+        self.quests.iter().find(|q| q.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut QuestEntry> {
+        self.quests.iter_mut().find(|q| q.id == id)
+    }
+
+    /// Quests in progress, in the order they were started.
+    pub fn active(&self) -> impl Iterator<Item = &QuestEntry> {
+        self.quests.iter().filter(|q| !q.completed)
+    }
+
+    /// Finished quests, in the order they were started.
+    pub fn completed(&self) -> impl Iterator<Item = &QuestEntry> {
+        self.quests.iter().filter(|q| q.completed)
+    }
+
+    /// Starts `quest` at its first stage. Returns `false` if it was already
+    /// started (or finished).
+    pub fn start(&mut self, quest: &QuestDefinition) -> bool {
+        if self.get(&quest.id).is_some() {
+            return false;
+        }
+        let mut entry = QuestEntry {
+            id: quest.id.clone(),
+            stage: 0,
+            progress: Vec::new(),
+            completed: quest.stages.is_empty(),
+        };
+        entry.reset_progress(quest);
+        self.quests.push(entry);
+        true
+    }
+}
+
+/// Where one started quest stands.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestEntry {
+    /// [`QuestDefinition::id`].
+    pub id: String,
+    /// Index of the current stage.
+    pub stage: usize,
+    /// Count per objective of the current stage, in definition order.
+    pub progress: Vec<u32>,
+    pub completed: bool,
+}
+
+/// A stage change reported by [`QuestEntry::advance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuestStep {
+    /// Moved on to this stage index.
+    Advanced(usize),
+    Completed,
+}
+
+impl QuestEntry {
+    pub fn current_stage<'a>(&self, quest: &'a QuestDefinition) -> Option<&'a QuestStage> {
+        if self.completed {
+            return None;
+        }
+        quest.stages.get(self.stage)
+    }
+
+    /// Counts `event` towards the current stage's objectives.
+    pub fn record(&mut self, quest: &QuestDefinition, event: &QuestProgress) {
+        let Some(stage) = self.current_stage(quest) else {
+            return;
+        };
+        for (objective, count) in stage.objectives.iter().zip(&mut self.progress) {
+            if *count < objective.goal.target() && counts_towards(&objective.goal, event) {
+                *count += 1;
+            }
+        }
+    }
+
+    /// Marks flag objectives that now hold, then finishes every stage that
+    /// is done -- setting its flags, which may finish the next one too.
+    pub fn advance(
+        &mut self,
+        quest: &QuestDefinition,
+        flags: &mut DialogueFlags,
+        alignment: &PlayerAlignment,
+    ) -> Vec<QuestStep> {
+        let mut steps = Vec::new();
+        while let Some(stage) = self.current_stage(quest) {
+            for (objective, count) in stage.objectives.iter().zip(&mut self.progress) {
+                if let ObjectiveGoal::Flags(condition) = &objective.goal {
+                    if condition.is_satisfied(flags, alignment) {
+                        *count = 1;
+                    }
+                }
+            }
+            if !self.stage_done(quest) {
+                break;
+            }
+            for flag in &stage.flags_set {
+                flags.set(flag.as_str());
+            }
+            self.stage += 1;
+            if self.stage < quest.stages.len() {
+                self.reset_progress(quest);
+                steps.push(QuestStep::Advanced(self.stage));
+            } else {
+                self.completed = true;
+                self.progress.clear();
+                for flag in &quest.flags_on_complete {
+                    flags.set(flag.as_str());
+                }
+                steps.push(QuestStep::Completed);
+            }
+        }
+        steps
+    }
+
+    /// Whether every objective of the current stage is finished.
+    pub fn stage_done(&self, quest: &QuestDefinition) -> bool {
+        self.current_stage(quest).is_some_and(|stage| {
+            stage
+                .objectives
+                .iter()
+                .zip(&self.progress)
+                .all(|(objective, count)| *count >= objective.goal.target())
+        })
+    }
+
+    fn reset_progress(&mut self, quest: &QuestDefinition) {
+        self.progress = quest
+            .stages
+            .get(self.stage)
+            .map_or_else(Vec::new, |stage| vec![0; stage.objectives.len()]);
+    }
+}
+
+fn counts_towards(goal: &ObjectiveGoal, event: &QuestProgress) -> bool {
+    match (goal, event) {
+        (ObjectiveGoal::EnterArea(area), QuestProgress::EnteredArea(visit)) => area.matches(visit),
+        (ObjectiveGoal::TalkTo(npc), QuestProgress::TalkedTo(kind)) => npc == kind,
+        (ObjectiveGoal::Defeat { enemy, .. }, QuestProgress::Defeated(kind))
+        | (ObjectiveGoal::Purify { enemy, .. }, QuestProgress::Purified(kind)) => enemy == kind,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quest::asset::AreaVisit;
+    use models::decoration::Biome;
+    use models::enemy::EnemyKind;
+    use models::npc::NpcKind;
+
+    fn quest() -> QuestDefinition {
+        ron::from_str(
+            r#"QuestDefinition(
+                id: "heart",
+                title_key: "t",
+                summary_key: "s",
+                stages: [
+                    (text_key: "a", objectives: [
+                        (text_key: "a.0", goal: Purify(enemy: DiseasedFox, count: 2)),
+                        (text_key: "a.1", goal: EnterArea(Biome(Darkwood))),
+                    ], flags_set: ["purified"]),
+                    (text_key: "b", objectives: [
+                        (text_key: "b.0", goal: Flags(AllSet(["purified"]))),
+                    ]),
+                    (text_key: "c", objectives: [
+                        (text_key: "c.0", goal: TalkTo(Drizella)),
+                    ]),
+                ],
+                flags_on_complete: ["heart_done"],
+            )"#,
+        )
+        .expect("test quest parses")
+    }
+
+    #[test]
+    fn stages_advance_as_objectives_finish() {
+        let quest = quest();
+        let mut flags = DialogueFlags::default();
+        let alignment = PlayerAlignment::default();
+        let mut log = QuestLog::default();
+        assert!(log.start(&quest));
+        assert!(!log.start(&quest));
+        let entry = log.get_mut("heart").expect("quest started");
+
+        entry.record(&quest, &QuestProgress::Purified(EnemyKind::DiseasedFox));
+        entry.record(&quest, &QuestProgress::Defeated(EnemyKind::DiseasedFox));
+        entry.record(&quest, &QuestProgress::Purified(EnemyKind::DiseasedFox));
+        entry.record(&quest, &QuestProgress::Purified(EnemyKind::DiseasedFox));
+        assert_eq!(entry.progress, [2, 0]);
+        assert!(entry.advance(&quest, &mut flags, &alignment).is_empty());
+
+        entry.record(
+            &quest,
+            &QuestProgress::EnteredArea(AreaVisit {
+                biome: Biome::Darkwood,
+                npc: None,
+                exit: false,
+            }),
+        );
+        // Stage one sets the flag stage two waits on, so both finish.
+        assert_eq!(
+            entry.advance(&quest, &mut flags, &alignment),
+            [QuestStep::Advanced(1), QuestStep::Advanced(2)]
+        );
+        assert!(flags.is_set("purified"));
+
+        entry.record(&quest, &QuestProgress::TalkedTo(NpcKind::Gothel));
+        assert!(entry.advance(&quest, &mut flags, &alignment).is_empty());
+        entry.record(&quest, &QuestProgress::TalkedTo(NpcKind::Drizella));
+        assert_eq!(
+            entry.advance(&quest, &mut flags, &alignment),
+            [QuestStep::Completed]
+        );
+        assert!(entry.completed);
+        assert!(flags.is_set("heart_done"));
+        assert_eq!(log.completed().count(), 1);
+        assert_eq!(log.active().count(), 0);
+    }
+}
@@ -0,0 +1,20 @@
+//! Quests: staged objectives layered over dialogue flags and world events.
+//!
+//! A [`QuestDefinition`] (`assets/quests/*.quest.ron`) is a list of stages;
+//! a stage is finished once all of its objectives are, which may set flags
+//! that later stages or dialogue react to. Gameplay crates report what the
+//! player does through [`crate::events::QuestProgress`]; progress lives in
+//! the persisted [`QuestLog`].
+
+pub(crate) mod asset;
+pub(crate) mod log;
+pub(crate) mod systems;
+
+pub use asset::{
+    AreaMatch, AreaVisit, Objective, ObjectiveGoal, QuestDefinition, QuestDefinitionLoader,
+    QuestStage,
+};
+pub use log::{QuestEntry, QuestLog, QuestStep};
+pub use systems::{
+    load_quests, start_quest_action, update_quests, QuestLibrary, QUEST_PATHS, START_QUEST,
+};
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use models::alignment::PlayerAlignment;
+
+use crate::actions::ActionArgs;
+use crate::events::{QuestAdvanced, QuestCompleted, QuestProgress, QuestStarted, StartQuest};
+use crate::flags::DialogueFlags;
+
+use super::asset::QuestDefinition;
+use super::log::{QuestLog, QuestStep};
+
+/// Dialogue action id: `start_quest <id>`.
+pub const START_QUEST: &str = "start_quest";
+
+/// Quest files loaded at startup. Listed rather than read from
+/// `assets/quests/`, which web builds can't list.
+pub const QUEST_PATHS: [&str; 2] = [
+    "quests/drizellas_heart.quest.ron",
+    "quests/glass_slippers.quest.ron",
+];
+
+/// Keeps every [`QuestDefinition`] loaded.
+#[derive(Resource, Default)]
+pub struct QuestLibrary(pub Vec<Handle<QuestDefinition>>);
+
+/// Startup system: loads [`QUEST_PATHS`].
+pub fn load_quests(asset_server: Res<AssetServer>, mut library: ResMut<QuestLibrary>) {
+    library.0 = QUEST_PATHS
+        .iter()
+        .map(|path| asset_server.load(*path))
+        .collect();
+}
+
+/// Handler for the `start_quest` dialogue action.
+pub fn start_quest_action(In(args): In<ActionArgs>, mut writer: MessageWriter<StartQuest>) {
+    let Some(id) = args.first() else {
+        warn!("{START_QUEST}: missing quest id");
+        return;
+    };
+    writer.write(StartQuest { id: id.clone() });
+}
+
+/// System: starts quests (on request or once their `start` condition
+/// holds), counts [`QuestProgress`] towards the current stages and moves
+/// finished stages on.
+///
+/// A [`StartQuest`] can arrive before its definition has loaded; it waits in
+/// `pending` until then, and is only dropped as unknown once every file in
+/// the [`QuestLibrary`] has finished loading.
+///
+/// The log and flags are only marked changed when something actually
+/// moved, so the save isn't rewritten every frame.
+#[allow(clippy::too_many_arguments)]
+pub fn update_quests(
+    mut progress: MessageReader<QuestProgress>,
+    mut requests: MessageReader<StartQuest>,
+    mut pending: Local<Vec<String>>,
+    library: Res<QuestLibrary>,
+    asset_server: Res<AssetServer>,
+    definitions: Res<Assets<QuestDefinition>>,
+    mut log: ResMut<QuestLog>,
+    mut flags: ResMut<DialogueFlags>,
+    alignment: Res<PlayerAlignment>,
+    mut started: MessageWriter<QuestStarted>,
+    mut advanced: MessageWriter<QuestAdvanced>,
+    mut completed: MessageWriter<QuestCompleted>,
+) {
+    let events: Vec<QuestProgress> = progress.read().cloned().collect();
+    pending.extend(requests.read().map(|r| r.id.clone()));
+    let (requested, waiting): (Vec<String>, Vec<String>) = pending
+        .drain(..)
+        .partition(|id| definitions.iter().any(|(_, quest)| quest.id == *id));
+    let library_loaded = library.0.iter().all(|handle| {
+        let state = asset_server.load_state(handle);
+        state.is_loaded() || state.is_failed()
+    });
+    if library_loaded {
+        for id in &waiting {
+            warn!("Unknown quest '{id}'");
+        }
+    } else {
+        *pending = waiting;
+    }
+
+    let mut log_changed = false;
+    let mut flags_changed = false;
+    let quests = log.bypass_change_detection();
+    for (_, quest) in definitions.iter() {
+        if quests.get(&quest.id).is_none() {
+            let wanted = requested.contains(&quest.id)
+                || quest
+                    .start
+                    .as_ref()
+                    .is_some_and(|c| c.is_satisfied(&flags, &alignment));
+            if !wanted || !quests.start(quest) {
+                continue;
+            }
+            started.write(QuestStarted {
+                id: quest.id.clone(),
+            });
+            log_changed = true;
+        }
+        let Some(entry) = quests.get_mut(&quest.id) else {
+            continue;
+        };
+        if entry.completed {
+            continue;
+        }
+
+        let before = entry.clone();
+        for event in &events {
+            entry.record(quest, event);
+        }
+        let steps = entry.advance(quest, flags.bypass_change_detection(), &alignment);
+        flags_changed |= !steps.is_empty();
+        for step in steps {
+            match step {
+                QuestStep::Advanced(stage) => {
+                    advanced.write(QuestAdvanced {
+                        id: quest.id.clone(),
+                        stage,
+                    });
+                }
+                QuestStep::Completed => {
+                    completed.write(QuestCompleted {
+                        id: quest.id.clone(),
+                    });
+                }
+            }
+        }
+        log_changed |= *entry != before;
+    }
+
+    if log_changed {
+        log.set_changed();
+    }
+    if flags_changed {
+        flags.set_changed();
+    }
+}
@@ -1,19 +1,29 @@
 use bevy::prelude::*;
 use keybinds::action::Action;
 use keybinds::bindings::Keybinds;
+use models::alignment::PlayerAlignment;
+use rand::RngExt;
 
+use crate::actions::DialogueActions;
 use crate::asset::DialogueLine;
 use crate::events::{ChoicesReady, DialogueEnded, DialogueLineReady};
 use crate::flags::DialogueFlags;
 
-use super::state::{DialogueRunner, RunnerState};
+use super::flow::resolve_flow;
+use super::state::{BacklogEntry, DialogueRunner, RunnerState};
 
 /// Advances the runner by one step, emitting presentation events for the UI.
+/// Actions passed on the way are dispatched to their registered handlers,
+/// unless the script fails to resolve.
+#[allow(clippy::too_many_arguments)]
 pub fn advance_runner(
+    mut commands: Commands,
+    actions: Res<DialogueActions>,
     mut runner: ResMut<DialogueRunner>,
     keyboard: Res<ButtonInput<KeyCode>>,
     bindings: Res<Keybinds>,
     flags: Res<DialogueFlags>,
+    alignment: Res<PlayerAlignment>,
     mut line_writer: MessageWriter<DialogueLineReady>,
     mut choice_writer: MessageWriter<ChoicesReady>,
     mut end_writer: MessageWriter<DialogueEnded>,
@@ -30,9 +40,10 @@ pub fn advance_runner(
     let RunnerState::Running {
         ref script,
         ref mut remaining,
-        ref mut seen,
+        ref mut backlog,
         ref mut awaiting_advance,
         ref mut awaiting_choice,
+        ..
     } = runner.state
     else {
         return;
@@ -51,13 +62,33 @@ pub fn advance_runner(
         *awaiting_advance = false;
     }
 
+    let mut rng = rand::rng();
+    let resolved = resolve_flow(script, remaining, &flags, &alignment, &mut |total| {
+        rng.random_range(0..total)
+    });
+    match resolved {
+        Ok(fired) => {
+            for action in &fired {
+                actions.dispatch(&mut commands, &action.id, &action.args);
+            }
+        }
+        Err(e) => {
+            warn!("Dialogue script '{}' stopped: {e:?}", script.id);
+            remaining.clear();
+            return;
+        }
+    }
+
     let Some(line) = remaining.first().cloned() else {
         return;
     };
 
     match line {
         DialogueLine::Speech { ref text_key } => {
-            seen.push(text_key.clone());
+            backlog.push(BacklogEntry::Line {
+                speaker_key: Some(script.speaker_key.clone()),
+                text_key: text_key.clone(),
+            });
             line_writer.write(DialogueLineReady {
                 speaker_key: Some(script.speaker_key.clone()),
                 text_key: text_key.clone(),
@@ -70,7 +101,8 @@ pub fn advance_runner(
                 .iter()
                 .enumerate()
                 .filter(|(_, opt)| {
-                    flags.all_set(&opt.flags_required) && opt.condition.is_satisfied(&flags)
+                    flags.all_set(&opt.flags_required)
+                        && opt.condition.is_satisfied(&flags, &alignment)
                 })
                 .map(|(i, opt)| (i, opt.text_key.clone()))
                 .collect();
@@ -82,5 +114,17 @@ pub fn advance_runner(
                 *awaiting_choice = true;
             }
         }
+        // `resolve_flow` only returns with a presentable line at the front.
+        DialogueLine::Label(_)
+        | DialogueLine::Jump(_)
+        | DialogueLine::If { .. }
+        | DialogueLine::Random { .. }
+        | DialogueLine::Action { .. } => {
+            warn!(
+                "Dialogue script '{}' left a control line unresolved: {line:?}",
+                script.id
+            );
+            remaining.clear();
+        }
     }
 }
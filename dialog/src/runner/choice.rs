@@ -1,14 +1,18 @@
 use bevy::prelude::*;
 use models::alignment::PlayerAlignment;
 
+use crate::actions::DialogueActions;
 use crate::asset::DialogueLine;
 use crate::events::ChoiceMade;
 use crate::flags::DialogueFlags;
 
-use super::state::{DialogueRunner, RunnerState};
+use super::state::{BacklogEntry, DialogueRunner, RunnerState};
 
-/// Handles a [`ChoiceMade`] event: applies flags and inlines the chosen branch.
+/// Handles a [`ChoiceMade`] event: applies flags, fires the choice's
+/// actions and inlines the chosen branch.
 pub fn handle_choice(
+    mut commands: Commands,
+    actions: Res<DialogueActions>,
     mut events: MessageReader<ChoiceMade>,
     mut runner: ResMut<DialogueRunner>,
     mut flags: ResMut<DialogueFlags>,
@@ -19,7 +23,7 @@ pub fn handle_choice(
     };
     let RunnerState::Running {
         ref mut remaining,
-        ref mut seen,
+        ref mut backlog,
         ref mut awaiting_choice,
         ..
     } = runner.state
@@ -42,8 +46,17 @@ pub fn handle_choice(
     if let Some(faction) = chosen.alignment_grant {
         alignment.grant(faction);
     }
+    for grant in &chosen.alignment_grants {
+        alignment.apply(grant);
+    }
+
+    for action in &chosen.actions {
+        actions.dispatch(&mut commands, &action.id, &action.args);
+    }
 
-    seen.push(chosen.text_key.clone());
+    backlog.push(BacklogEntry::Choice {
+        text_key: chosen.text_key.clone(),
+    });
 
     let mut new_remaining = chosen.next.clone();
     new_remaining.extend(remaining.iter().skip(1).cloned());
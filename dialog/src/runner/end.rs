@@ -4,12 +4,15 @@ use models::game_states::GameState;
 use crate::events::DialogueEnded;
 use crate::history::LoreBook;
 
+use super::resume::DialogueSnapshot;
 use super::state::{DialogueRunner, DialogueTarget, RunnerState};
 
-/// Records lore and transitions back to Playing when dialogue ends.
+/// Records lore, forgets the saved conversation and transitions back to
+/// Playing when dialogue ends.
 pub fn on_dialogue_ended(
     mut events: MessageReader<DialogueEnded>,
     mut runner: ResMut<DialogueRunner>,
+    mut snapshot: ResMut<DialogueSnapshot>,
     mut target: ResMut<DialogueTarget>,
     mut lore_book: ResMut<LoreBook>,
     mut next_state: ResMut<NextState<GameState>>,
@@ -21,7 +24,7 @@ pub fn on_dialogue_ended(
 
     if let RunnerState::Running {
         ref script,
-        ref seen,
+        ref backlog,
         ..
     } = runner.state
     {
@@ -33,13 +36,17 @@ pub fn on_dialogue_ended(
                 category: lore.category,
                 topic: lore.topic.clone(),
                 image: lore.image.clone(),
-                lines_seen: seen.clone(),
+                lines_seen: backlog
+                    .iter()
+                    .map(|entry| entry.text_key().to_owned())
+                    .collect(),
                 game_time: time.elapsed_secs(),
             });
         }
     }
 
     runner.state = RunnerState::Idle;
+    snapshot.0 = None;
     target.0 = None;
     next_state.set(GameState::Playing);
 }
@@ -0,0 +1,293 @@
+//! Control-flow resolution: `Label`, `Jump`, `If`, `Random` and `Action`
+//! lines.
+//!
+//! None of these are shown to the player. Before presenting the next line,
+//! [`resolve_flow`] rewrites the front of the runner's `remaining` queue
+//! until a `Speech` / `PlayerChoice` (or nothing) is at the front. Actions
+//! passed on the way are returned for the caller to dispatch -- only when
+//! resolution succeeds, so a broken script has no side effects.
+//!
+//! A script that loops without ever presenting anything -- a `Jump` back to
+//! its own label, say -- would hang the frame, so resolution gives up after
+//! [`MAX_FLOW_STEPS`] consecutive control lines.
+
+use models::alignment::PlayerAlignment;
+
+use crate::actions::DialogueAction;
+use crate::asset::{DialogueLine, DialogueScript, RandomBranch};
+use crate::flags::DialogueFlags;
+
+/// Control lines resolved in a row before the script is declared stuck.
+pub(crate) const MAX_FLOW_STEPS: usize = 256;
+
+/// Why a script couldn't be resolved to a presentable line.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum FlowError {
+    /// A `Jump` names a label the script doesn't contain.
+    UnknownLabel(String),
+    /// [`MAX_FLOW_STEPS`] control lines without presenting anything.
+    StepLimit,
+}
+
+/// Resolve control lines at the front of `remaining`, returning the actions
+/// reached in order. `roll(total)` must return a value in `0..total`; it
+/// picks `Random` branches.
+pub(crate) fn resolve_flow(
+    script: &DialogueScript,
+    remaining: &mut Vec<DialogueLine>,
+    flags: &DialogueFlags,
+    alignment: &PlayerAlignment,
+    roll: &mut impl FnMut(u32) -> u32,
+) -> Result<Vec<DialogueAction>, FlowError> {
+    let mut fired = Vec::new();
+    for _ in 0..MAX_FLOW_STEPS {
+        let Some(line) = remaining.first() else {
+            return Ok(fired);
+        };
+        let body = match line {
+            DialogueLine::Speech { .. } | DialogueLine::PlayerChoice { .. } => return Ok(fired),
+            DialogueLine::Label(_) => Vec::new(),
+            DialogueLine::Jump(label) => {
+                let Some(tail) = find_label(&script.lines, label) else {
+                    return Err(FlowError::UnknownLabel(label.clone()));
+                };
+                *remaining = tail;
+                continue;
+            }
+            DialogueLine::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                if condition.is_satisfied(flags, alignment) {
+                    then.clone()
+                } else {
+                    otherwise.clone()
+                }
+            }
+            DialogueLine::Random { branches } => pick_branch(branches, roll)
+                .map(|b| b.lines.clone())
+                .unwrap_or_default(),
+            DialogueLine::Action { id, args } => {
+                fired.push(DialogueAction {
+                    id: id.clone(),
+                    args: args.clone(),
+                });
+                Vec::new()
+            }
+        };
+        let mut next = body;
+        next.extend(remaining.drain(1..));
+        *remaining = next;
+    }
+    Err(FlowError::StepLimit)
+}
+
+/// The lines that follow `Label(label)` -- including everything after the
+/// branch it sits in -- or `None` when no such label exists.
+fn find_label(lines: &[DialogueLine], label: &str) -> Option<Vec<DialogueLine>> {
+    for (i, line) in lines.iter().enumerate() {
+        let rest = lines.get(i + 1..).unwrap_or_default();
+        if matches!(line, DialogueLine::Label(l) if l == label) {
+            return Some(rest.to_vec());
+        }
+        for branch in line.branches() {
+            if let Some(mut tail) = find_label(branch, label) {
+                tail.extend_from_slice(rest);
+                return Some(tail);
+            }
+        }
+    }
+    None
+}
+
+fn pick_branch<'a>(
+    branches: &'a [RandomBranch],
+    roll: &mut impl FnMut(u32) -> u32,
+) -> Option<&'a RandomBranch> {
+    let total: u32 = branches.iter().map(|b| b.weight).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut pick = roll(total);
+    branches.iter().find(|b| {
+        if pick < b.weight {
+            return true;
+        }
+        pick -= b.weight;
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(lines: &str) -> DialogueScript {
+        ron::from_str(&format!(
+            "DialogueScript(id: \"t\", speaker_key: \"s\", keyword_tags: [], lines: {lines})"
+        ))
+        .expect("test script parses")
+    }
+
+    fn front_key(remaining: &[DialogueLine]) -> Option<&str> {
+        match remaining.first() {
+            Some(DialogueLine::Speech { text_key }) => Some(text_key),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn jump_into_nested_label_keeps_continuation() {
+        let s = script(
+            r#"[
+                If(condition: Always, then: [Label("hub"), Speech(text_key: "a")]),
+                Speech(text_key: "b"),
+                Jump("hub"),
+            ]"#,
+        );
+        let mut remaining = vec![s.lines[2].clone()];
+        let ok = resolve_flow(
+            &s,
+            &mut remaining,
+            &DialogueFlags::default(),
+            &PlayerAlignment::default(),
+            &mut |_| 0,
+        );
+        assert_eq!(ok, Ok(Vec::new()));
+        assert_eq!(front_key(&remaining), Some("a"));
+        assert_eq!(remaining.len(), 3);
+    }
+
+    #[test]
+    fn if_follows_flags() {
+        let s = script(
+            r#"[If(condition: AllSet(["met"]),
+                  then: [Speech(text_key: "again")],
+                  else: [Speech(text_key: "hello")])]"#,
+        );
+        let mut flags = DialogueFlags::default();
+        let alignment = PlayerAlignment::default();
+        let mut remaining = s.lines.clone();
+        assert_eq!(
+            resolve_flow(&s, &mut remaining, &flags, &alignment, &mut |_| 0),
+            Ok(Vec::new())
+        );
+        assert_eq!(front_key(&remaining), Some("hello"));
+
+        flags.set("met");
+        let mut remaining = s.lines.clone();
+        assert_eq!(
+            resolve_flow(&s, &mut remaining, &flags, &alignment, &mut |_| 0),
+            Ok(Vec::new())
+        );
+        assert_eq!(front_key(&remaining), Some("again"));
+    }
+
+    #[test]
+    fn if_follows_alignment() {
+        let s = script(
+            r#"[If(condition: Any([AlignmentAtLeast(Darkwoods, 6), Dominant(Cities)]),
+                  then: [Speech(text_key: "wary")],
+                  else: [Speech(text_key: "warm")])]"#,
+        );
+        let flags = DialogueFlags::default();
+        let mut alignment = PlayerAlignment::default();
+        let front = |alignment: &PlayerAlignment| {
+            let mut remaining = s.lines.clone();
+            let ok = resolve_flow(&s, &mut remaining, &flags, alignment, &mut |_| 0);
+            assert_eq!(ok, Ok(Vec::new()));
+            front_key(&remaining).map(str::to_owned)
+        };
+        assert_eq!(front(&alignment).as_deref(), Some("warm"));
+
+        alignment.darkwoods = 6;
+        assert_eq!(front(&alignment).as_deref(), Some("wary"));
+
+        alignment.darkwoods = 0;
+        alignment.cities = 7;
+        assert_eq!(front(&alignment).as_deref(), Some("wary"));
+    }
+
+    #[test]
+    fn random_respects_weights() {
+        let s = script(
+            r#"[Random(branches: [
+                (weight: 0, lines: [Speech(text_key: "never")]),
+                (weight: 3, lines: [Speech(text_key: "x")]),
+                (lines: [Speech(text_key: "y")]),
+            ])]"#,
+        );
+        let flags = DialogueFlags::default();
+        let alignment = PlayerAlignment::default();
+        for (roll, want) in [(0, "x"), (2, "x"), (3, "y")] {
+            let mut remaining = s.lines.clone();
+            let ok = resolve_flow(&s, &mut remaining, &flags, &alignment, &mut |total| {
+                assert_eq!(total, 4);
+                roll
+            });
+            assert_eq!(ok, Ok(Vec::new()));
+            assert_eq!(front_key(&remaining), Some(want));
+        }
+    }
+
+    #[test]
+    fn actions_are_collected_in_order() {
+        let s = script(
+            r#"[
+                Action(id: "give_item", args: ["antler", "2"]),
+                Action(id: "set_weather", args: ["Rain"]),
+                Speech(text_key: "done"),
+            ]"#,
+        );
+        let mut remaining = s.lines.clone();
+        let flags = DialogueFlags::default();
+        let alignment = PlayerAlignment::default();
+        let fired =
+            resolve_flow(&s, &mut remaining, &flags, &alignment, &mut |_| 0).unwrap_or_default();
+        let ids: Vec<&str> = fired.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["give_item", "set_weather"]);
+        assert_eq!(fired[0].args, vec!["antler", "2"]);
+        assert_eq!(front_key(&remaining), Some("done"));
+    }
+
+    #[test]
+    fn failed_resolution_fires_nothing() {
+        let s = script(
+            r#"[
+                Action(id: "give_item", args: ["antler"]),
+                Jump("nowhere"),
+            ]"#,
+        );
+        let mut remaining = s.lines.clone();
+        assert_eq!(
+            resolve_flow(
+                &s,
+                &mut remaining,
+                &DialogueFlags::default(),
+                &PlayerAlignment::default(),
+                &mut |_| 0,
+            ),
+            Err(FlowError::UnknownLabel("nowhere".to_owned()))
+        );
+    }
+
+    #[test]
+    fn silent_loops_and_bad_labels_fail() {
+        let s = script(r#"[Label("spin"), Jump("spin")]"#);
+        let mut remaining = s.lines.clone();
+        let flags = DialogueFlags::default();
+        let alignment = PlayerAlignment::default();
+        assert_eq!(
+            resolve_flow(&s, &mut remaining, &flags, &alignment, &mut |_| 0),
+            Err(FlowError::StepLimit)
+        );
+
+        let s = script(r#"[Jump("nowhere")]"#);
+        let mut remaining = s.lines.clone();
+        assert_eq!(
+            resolve_flow(&s, &mut remaining, &flags, &alignment, &mut |_| 0),
+            Err(FlowError::UnknownLabel("nowhere".to_owned()))
+        );
+    }
+}
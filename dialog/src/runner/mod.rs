@@ -4,6 +4,8 @@ pub(crate) mod advance;
 pub(crate) mod choice;
 pub(crate) mod detect;
 pub(crate) mod end;
+pub(crate) mod flow;
+pub(crate) mod resume;
 pub(crate) mod start;
 pub(crate) mod state;
 
@@ -11,5 +13,8 @@ pub use advance::advance_runner;
 pub use choice::handle_choice;
 pub use detect::{detect_interact_input, detect_interact_range};
 pub use end::on_dialogue_ended;
+pub use resume::{
+    has_saved_dialogue, resume_dialogue, snapshot_runner, DialogueSnapshot, SavedDialogue,
+};
 pub use start::start_dialogue;
-pub use state::{DialogueRunner, DialogueTarget};
+pub use state::{BacklogEntry, DialogueRunner, DialogueTarget};
@@ -0,0 +1,186 @@
+//! Resumable dialogue: the running conversation mirrored into a
+//! serialisable [`DialogueSnapshot`], persisted by `save::SavePlugin`.
+//!
+//! A snapshot always describes the conversation about to present its next
+//! step. A line waiting on "next" is put back at the front of the queue
+//! (and dropped from the backlog); a pending choice is already at the
+//! front. Restoring it re-shows exactly what was on screen, without firing
+//! the actions that led there a second time.
+
+use bevy::prelude::*;
+use models::game_states::GameState;
+use serde::{Deserialize, Serialize};
+
+use crate::asset::{DialogueLine, DialogueScript};
+use crate::components::Talker;
+
+use super::state::{BacklogEntry, DialogueRunner, DialogueTarget, RunnerState};
+
+/// How long to wait for the saved script's asset before dropping a resume.
+const RESUME_TIMEOUT_SECS: f32 = 5.0;
+
+/// Where a conversation stood when it was saved.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedDialogue {
+    /// [`DialogueScript::id`] of the script being run.
+    pub script_id: String,
+    /// [`Talker::id`] of the NPC being talked to.
+    pub talker_id: String,
+    /// Lines still to run, next one first.
+    pub remaining: Vec<DialogueLine>,
+    pub backlog: Vec<BacklogEntry>,
+}
+
+impl SavedDialogue {
+    /// Snapshot of `state`, or `None` when no dialogue is running.
+    pub(crate) fn capture(state: &RunnerState) -> Option<Self> {
+        let RunnerState::Running {
+            script,
+            talker_id,
+            remaining,
+            backlog,
+            awaiting_advance,
+            ..
+        } = state
+        else {
+            return None;
+        };
+        let mut remaining = remaining.clone();
+        let mut backlog = backlog.clone();
+        if *awaiting_advance && matches!(backlog.last(), Some(BacklogEntry::Line { .. })) {
+            if let Some(BacklogEntry::Line { text_key, .. }) = backlog.pop() {
+                remaining.insert(0, DialogueLine::Speech { text_key });
+            }
+        }
+        Some(Self {
+            script_id: script.id.clone(),
+            talker_id: talker_id.clone(),
+            remaining,
+            backlog,
+        })
+    }
+
+    /// Runner state that picks the conversation back up.
+    pub(crate) fn restore(self, script: DialogueScript) -> RunnerState {
+        RunnerState::Running {
+            script,
+            talker_id: self.talker_id,
+            remaining: self.remaining,
+            backlog: self.backlog,
+            awaiting_advance: false,
+            awaiting_choice: false,
+        }
+    }
+}
+
+/// The in-progress conversation, or `None` outside dialogue.
+/// Persisted by `save::SavePlugin`.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DialogueSnapshot(pub Option<SavedDialogue>);
+
+/// System: mirrors the running conversation into [`DialogueSnapshot`].
+/// Writes only on a real change, so the save isn't rewritten every frame.
+pub fn snapshot_runner(runner: Res<DialogueRunner>, mut snapshot: ResMut<DialogueSnapshot>) {
+    if !runner.is_changed() {
+        return;
+    }
+    if let Some(saved) = SavedDialogue::capture(&runner.state) {
+        snapshot.set_if_neq(DialogueSnapshot(Some(saved)));
+    }
+}
+
+/// Run condition: a saved conversation is waiting and none is running.
+pub fn has_saved_dialogue(snapshot: Res<DialogueSnapshot>, runner: Res<DialogueRunner>) -> bool {
+    snapshot.0.is_some() && matches!(runner.state, RunnerState::Idle)
+}
+
+/// System (Playing): restores a saved conversation once its script asset
+/// is loaded and returns to `GameState::Dialogue`. The NPC is targeted
+/// again if it has been spawned.
+#[allow(clippy::too_many_arguments)]
+pub fn resume_dialogue(
+    mut snapshot: ResMut<DialogueSnapshot>,
+    scripts: Res<Assets<DialogueScript>>,
+    talkers: Query<(Entity, &Talker)>,
+    mut runner: ResMut<DialogueRunner>,
+    mut target: ResMut<DialogueTarget>,
+    mut next_state: ResMut<NextState<GameState>>,
+    time: Res<Time>,
+    mut waited: Local<f32>,
+) {
+    let Some(saved) = snapshot.0.clone() else {
+        return;
+    };
+    let script = scripts
+        .iter()
+        .map(|(_, script)| script)
+        .find(|script| script.id == saved.script_id);
+    let Some(script) = script.cloned() else {
+        *waited += time.delta_secs();
+        if *waited > RESUME_TIMEOUT_SECS {
+            warn!(
+                "Saved dialogue '{}' never loaded; dropping it",
+                saved.script_id
+            );
+            snapshot.0 = None;
+            *waited = 0.0;
+        }
+        return;
+    };
+    *waited = 0.0;
+
+    target.0 = talkers
+        .iter()
+        .find(|(_, talker)| talker.id == saved.talker_id)
+        .map(|(entity, _)| entity);
+    runner.state = saved.restore(script);
+    next_state.set(GameState::Dialogue);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script() -> DialogueScript {
+        ron::from_str(
+            r#"DialogueScript(id: "elder", speaker_key: "npc.elder.name", keyword_tags: [],
+                lines: [Speech(text_key: "a"), Speech(text_key: "b")])"#,
+        )
+        .expect("test script parses")
+    }
+
+    #[test]
+    fn line_on_screen_is_requeued() {
+        let script = script();
+        let state = RunnerState::Running {
+            talker_id: "elder".to_owned(),
+            remaining: script.lines[1..].to_vec(),
+            backlog: vec![BacklogEntry::Line {
+                speaker_key: Some(script.speaker_key.clone()),
+                text_key: "a".to_owned(),
+            }],
+            awaiting_advance: true,
+            awaiting_choice: false,
+            script: script.clone(),
+        };
+        let saved = SavedDialogue::capture(&state).expect("running dialogue captures");
+        assert!(saved.backlog.is_empty());
+        assert_eq!(saved.remaining, script.lines);
+
+        let text = ron::to_string(&saved).expect("snapshot serialises");
+        let back: SavedDialogue = ron::from_str(&text).expect("snapshot deserialises");
+        assert_eq!(back, saved);
+
+        let RunnerState::Running {
+            remaining,
+            awaiting_advance,
+            ..
+        } = back.restore(script.clone())
+        else {
+            panic!("restore yields a running state");
+        };
+        assert_eq!(remaining, script.lines);
+        assert!(!awaiting_advance);
+        assert!(SavedDialogue::capture(&RunnerState::Idle).is_none());
+    }
+}
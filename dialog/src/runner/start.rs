@@ -2,19 +2,23 @@ use bevy::prelude::*;
 use models::game_states::GameState;
 
 use crate::asset::DialogueScript;
-use crate::components::Talker;
-use crate::events::StartDialogue;
+use crate::components::{GreetedNpcs, Talker};
+use crate::events::{DialogueStarted, StartDialogue};
 
 use super::state::{DialogueRunner, DialogueTarget, RunnerState};
 
 /// Handles a [`StartDialogue`] event: loads the script and transitions state.
+/// The conversation is announced as [`DialogueStarted`].
+#[allow(clippy::too_many_arguments)]
 pub fn start_dialogue(
     mut events: MessageReader<StartDialogue>,
     mut talker_q: Query<&mut Talker>,
+    mut greeted: ResMut<GreetedNpcs>,
     scripts: Res<Assets<DialogueScript>>,
     mut runner: ResMut<DialogueRunner>,
     mut target: ResMut<DialogueTarget>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut started: MessageWriter<DialogueStarted>,
 ) {
     let Some(event) = events.read().next() else {
         return;
@@ -22,7 +26,8 @@ pub fn start_dialogue(
     let Ok(mut talker) = talker_q.get_mut(event.npc) else {
         return;
     };
-    if talker.has_greeted && !talker.repeat_greeting {
+    let already_greeted = talker.has_greeted || greeted.contains(&talker.id);
+    if already_greeted && !talker.repeat_greeting {
         return;
     }
 
@@ -31,14 +36,19 @@ pub fn start_dialogue(
     };
 
     talker.has_greeted = true;
+    if !talker.repeat_greeting {
+        greeted.insert(talker.id.clone());
+    }
     target.0 = Some(event.npc);
     runner.state = RunnerState::Running {
         script: script.clone(),
+        talker_id: talker.id.clone(),
         remaining: script.lines.clone(),
-        seen: Vec::new(),
+        backlog: Vec::new(),
         awaiting_advance: false,
         awaiting_choice: false,
     };
 
+    started.write(DialogueStarted { npc: event.npc });
     next_state.set(GameState::Dialogue);
 }
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::asset::{DialogueLine, DialogueScript};
 
@@ -13,16 +14,52 @@ pub struct DialogueRunner {
     pub(crate) state: RunnerState,
 }
 
+impl DialogueRunner {
+    /// Everything presented so far in the current conversation, oldest
+    /// first. Empty when no dialogue is running.
+    pub fn backlog(&self) -> &[BacklogEntry] {
+        match &self.state {
+            RunnerState::Running { backlog, .. } => backlog,
+            RunnerState::Idle => &[],
+        }
+    }
+}
+
+/// One step of a conversation the player has already seen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BacklogEntry {
+    /// A speech line (locale keys).
+    Line {
+        speaker_key: Option<String>,
+        text_key: String,
+    },
+    /// The option the player picked (locale key of its label).
+    Choice { text_key: String },
+}
+
+impl BacklogEntry {
+    pub fn text_key(&self) -> &str {
+        match self {
+            Self::Line { text_key, .. } | Self::Choice { text_key } => text_key,
+        }
+    }
+}
+
+// A single instance lives in `DialogueRunner`; boxing would buy nothing.
+#[allow(clippy::large_enum_variant)]
 #[derive(Default)]
 pub(crate) enum RunnerState {
     #[default]
     Idle,
     Running {
         script: DialogueScript,
+        /// [`crate::components::Talker::id`] of the NPC being talked to.
+        talker_id: String,
         /// Remaining lines to process (front = next line).
         remaining: Vec<DialogueLine>,
-        /// Lines already presented (locale keys), for lore recording.
-        seen: Vec<String>,
+        /// Lines and choices already presented, for the backlog viewer
+        /// and lore recording.
+        backlog: Vec<BacklogEntry>,
         /// Whether the runner is waiting for the player to press "next".
         awaiting_advance: bool,
         /// Whether we've emitted choices and are waiting for a [`ChoiceMade`].
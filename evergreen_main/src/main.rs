@@ -23,6 +23,7 @@ fn main() {
                 .set(ImagePlugin::default_nearest())
                 .set(AssetPlugin {
                     meta_check: AssetMetaCheck::Never,
+                    watch_for_changes_override: cfg!(feature = "dev").then_some(true),
                     ..default()
                 }),
         )
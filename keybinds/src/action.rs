@@ -35,11 +35,18 @@ pub enum Action {
     // Interaction
     #[strum(to_string = "Interact")]
     Interact,
+    // Combat
+    #[strum(to_string = "Attack")]
+    Attack,
+    #[strum(to_string = "Purify")]
+    Purify,
     // UI
     #[strum(to_string = "Pause")]
     Pause,
     #[strum(to_string = "Advance Dialog")]
     DialogAdvance,
+    #[strum(to_string = "Dialog Backlog")]
+    DialogBacklog,
     // Diagnostics
     #[strum(to_string = "Toggle Diagnostics Overlay")]
     ToggleDiagnosticsOverlay,
@@ -15,8 +15,11 @@ const DEFAULT_BINDINGS: &[(Action, KeyCode)] = &[
     (Action::MoveRight, KeyCode::ArrowRight),
     (Action::Sprint, KeyCode::ShiftLeft),
     (Action::Interact, KeyCode::KeyE),
+    (Action::Attack, KeyCode::KeyF),
+    (Action::Purify, KeyCode::KeyG),
     (Action::Pause, KeyCode::Escape),
     (Action::DialogAdvance, KeyCode::Space),
+    (Action::DialogBacklog, KeyCode::Tab),
     (Action::ToggleDiagnosticsOverlay, KeyCode::F3),
     (Action::ToggleDebugPanel, KeyCode::F5),
 ];
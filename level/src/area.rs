@@ -1,61 +1,11 @@
 use std::collections::BTreeSet;
 
 use bevy::math::IVec2;
+pub use models::enemy::EnemyKind;
+pub use models::npc::{NpcKind, ALL_NPCS};
 
 use crate::terrain::Terrain;
 
-/// Identifies an NPC for area events.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum NpcKind {
-    Mordred,
-    Drizella,
-    Bigby,
-    Gothel,
-    Morgana,
-    Cadwallader,
-    BloodyMary,
-}
-
-impl NpcKind {
-    /// Alignment range this NPC will spawn in (min, max).
-    pub fn alignment_range(self) -> (u8, u8) {
-        match self {
-            // City-aligned
-            Self::Cadwallader => (1, 35),
-            Self::Bigby => (1, 35),
-            // Greenwood-aligned
-            Self::Drizella => (25, 75),
-            Self::Gothel => (25, 75),
-            // Darkwood-aligned
-            Self::Mordred => (60, 100),
-            Self::Morgana => (60, 100),
-            Self::BloodyMary => (60, 100),
-        }
-    }
-}
-
-/// All available NPC kinds, used for random selection.
-pub const ALL_NPCS: [NpcKind; 7] = [
-    NpcKind::Mordred,
-    NpcKind::Drizella,
-    NpcKind::Bigby,
-    NpcKind::Gothel,
-    NpcKind::Morgana,
-    NpcKind::Cadwallader,
-    NpcKind::BloodyMary,
-];
-
-/// Diseased creature / slime kinds that spawn in greenwood maps as the
-/// `AreaEvent::Enemy` content. Per-tile entity spawning lives in
-/// `crate::enemies`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum EnemyKind {
-    PurpleSlime,
-    DiseasedFox,
-    DiseasedDeer,
-    DiseasedBear,
-}
-
 /// What happens when the player enters an area.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AreaEvent {
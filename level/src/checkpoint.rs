@@ -0,0 +1,17 @@
+//! Respawn checkpoint. A map's start area is where the player first set
+//! foot in it -- for every map after the first, that's the portal they
+//! came through -- so it doubles as the place they get back up after
+//! dying.
+//!
+//! Dying moves to `GameState::GameOver`, whose `OnExit(Playing)` teardown
+//! clears the world as usual. The game-over screen sets
+//! [`RespawnRequested`] before heading back to `Playing`; `regenerate_world`
+//! sees it, keeps the current `WorldMap` and `MultiverseSave` as they are,
+//! and only moves the player's area back to `WorldMap::start_area`.
+
+use bevy::prelude::*;
+
+/// Set by the game-over screen's respawn button; consumed by
+/// `regenerate_world` on the next `OnEnter(Playing)`.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct RespawnRequested(pub bool);
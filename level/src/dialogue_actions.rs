@@ -0,0 +1,60 @@
+//! World-side handlers for dialogue actions (`dialog::actions`).
+//!
+//! - `give_item <item> [count]` -- hands the player items as if picked up,
+//!   e.g. `Action(id: "give_item", args: ["antler", "2"])`.
+//! - `set_weather <kind>` -- forces the weather now (`Clear`, `Breezy`,
+//!   `Windy`, `Rain`, `Storm`); the state machine takes over again at its
+//!   next check.
+
+use bevy::prelude::*;
+use dialog::actions::{ActionArgs, DialogueActionAppExt};
+use models::item::ItemKind;
+use models::player::Player;
+use models::weather::{WeatherKind, WeatherState};
+use models::wind::WindStrength;
+
+use crate::loot::ItemPickedUp;
+
+pub const GIVE_ITEM: &str = "give_item";
+pub const SET_WEATHER: &str = "set_weather";
+
+pub(crate) fn register(app: &mut App) {
+    app.register_dialogue_action(GIVE_ITEM, give_item)
+        .register_dialogue_action(SET_WEATHER, set_weather);
+}
+
+fn give_item(
+    In(args): In<ActionArgs>,
+    player: Query<Entity, With<Player>>,
+    mut picked: MessageWriter<ItemPickedUp>,
+) {
+    let Some(item) = args.first().and_then(|id| ItemKind::from_id(id)) else {
+        warn!("{GIVE_ITEM}: unknown item in {args:?}");
+        return;
+    };
+    let count = args.get(1).and_then(|n| n.parse().ok()).unwrap_or(1);
+    let Ok(collector) = player.single() else {
+        return;
+    };
+    picked.write(ItemPickedUp {
+        collector,
+        item,
+        count,
+    });
+}
+
+fn set_weather(
+    In(args): In<ActionArgs>,
+    mut weather: ResMut<WeatherState>,
+    wind: Res<WindStrength>,
+) {
+    let Some(kind) = args.first().and_then(|id| WeatherKind::from_id(id)) else {
+        warn!("{SET_WEATHER}: unknown weather in {args:?}");
+        return;
+    };
+    let (wind_min, wind_max) = kind.wind_range();
+    weather.current = kind;
+    weather.target_wind = (wind_min + wind_max) / 2.0;
+    weather.wind_lerp_start = wind.0;
+    weather.wind_lerp_remaining = WeatherState::WIND_LERP_DURATION_SECS;
+}
@@ -1,8 +1,9 @@
 //! Enemy entities placed by `AreaEvent::Enemy`. Animated via the existing
 //! NPC sprite-sheet pipeline (`models::npc_anim`): each enemy carries an
-//! `NpcSheet`, `NpcAnimKind::Walk`, `NpcAnimFrame`, `NpcAnimTimer`, and
+//! `NpcSheet`, `NpcAnimKind`, `NpcAnimFrame`, `NpcAnimTimer`, and
 //! `NpcFacing`, so the shared `npc_anim::advance_npc_frame` system animates
-//! them automatically.
+//! them automatically. Behaviour (patrol, chase, attack) is driven by
+//! `crate::enemy_ai`.
 //!
 //! Sheets are 4-row x 8-col (rows = facing south/east/north/west, cols 0-3
 //! and 4-7 = walk frames repeated, so idle and walk look identical for the
@@ -10,16 +11,24 @@
 //!
 //! Spawned per area when the area's event is `AreaEvent::Enemy { kind,
 //! count }`. Position is sampled deterministically from the area seed so
-//! re-entering produces the same layout.
+//! re-entering produces the same layout. Slots the player has purified
+//! (`WorldMap::is_purified`) get the healed animal instead -- see
+//! `crate::purify`.
 
 use bevy::math::IVec2;
 use bevy::prelude::*;
+use combat::{HitResponse, Hurtbox, Team};
+use models::health::{Health, MaxHealth};
 use models::layer::Layer;
 use models::npc_anim::{NpcAnimFrame, NpcAnimKind, NpcAnimTimer, NpcFacing, NpcSheet};
 
 use crate::area::{AreaEvent, EnemyKind, MAP_HEIGHT, MAP_WIDTH};
+use crate::enemy_ai::{EnemyAi, EnemyStats};
+use crate::purify::{healthy_speed, spawn_healed_creature};
+use crate::shadows::DropShadowAssets;
 use crate::spawning::{area_world_offset, TILE_SIZE_PX};
 use crate::terrain::tile_hash;
+use crate::world::WorldMap;
 
 #[allow(clippy::as_conversions)]
 const MAP_W_PX: f32 = MAP_WIDTH as f32 * TILE_SIZE_PX as f32;
@@ -32,6 +41,9 @@ const ENEMY_INSET_TILES: u32 = 4;
 
 /// Render size for enemy sprites (square, in pixels).
 const ENEMY_SPRITE_SIZE_PX: f32 = 28.0;
+/// Half-size of the projectile hurtbox -- the sprite minus its transparent
+/// margin.
+const ENEMY_HURTBOX_HALF_PX: f32 = 8.0;
 
 const ENEMY_SHEET_FRAME_PX: u32 = 32;
 const ENEMY_SHEET_COLS: u32 = 8;
@@ -43,6 +55,11 @@ const Y_SORT_SCALE: f32 = 0.001;
 #[derive(Component, Debug, Clone, Copy)]
 pub struct Enemy {
     pub kind: EnemyKind,
+    /// Area the enemy was spawned for.
+    pub area: IVec2,
+    /// Index among the area's `count` enemies. Stable across respawns, so
+    /// it identifies this enemy in `WorldMap`'s purified set.
+    pub slot: u8,
 }
 
 /// Spawn the area's enemies according to the area's event.
@@ -50,8 +67,10 @@ pub fn spawn_area_enemies(
     commands: &mut Commands,
     asset_server: &AssetServer,
     atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    shadow_assets: &DropShadowAssets,
     area: &crate::area::Area,
     area_pos: IVec2,
+    world: &WorldMap,
 ) {
     let AreaEvent::Enemy { kind, count } = area.event else {
         return;
@@ -92,22 +111,55 @@ pub fn spawn_area_enemies(
             + tile_px / 2.0;
         let world_y = base_offset_y + f32::from(u16::try_from(ty).unwrap_or(0)) * tile_px
             + tile_px / 2.0;
+        let sprite = Sprite {
+            image: asset_server.load(sprite_path(kind)),
+            texture_atlas: Some(TextureAtlas {
+                layout: layout.clone(),
+                index: 0,
+            }),
+            custom_size: Some(Vec2::splat(ENEMY_SPRITE_SIZE_PX)),
+            ..default()
+        };
+        let transform = Transform::from_xyz(
+            world_x,
+            world_y,
+            Layer::World.z_f32() - world_y * Y_SORT_SCALE,
+        );
+        if world.is_purified(area_pos, i) {
+            if let Some(speed) = healthy_speed(kind) {
+                spawn_healed_creature(
+                    commands,
+                    shadow_assets,
+                    sprite,
+                    speed,
+                    transform,
+                    u32::from(i),
+                    false,
+                );
+            }
+            continue;
+        }
         commands.spawn((
-            Enemy { kind },
-            Sprite {
-                image: asset_server.load(sprite_path(kind)),
-                texture_atlas: Some(TextureAtlas {
-                    layout: layout.clone(),
-                    index: 0,
-                }),
-                custom_size: Some(Vec2::splat(ENEMY_SPRITE_SIZE_PX)),
-                ..default()
+            Enemy {
+                kind,
+                area: area_pos,
+                slot: i,
             },
-            Transform::from_xyz(world_x, world_y, Layer::World.z_f32() - world_y * Y_SORT_SCALE),
+            enemy_health(kind),
+            MaxHealth(enemy_health(kind).0),
+            Hurtbox {
+                half_extents: Vec2::splat(ENEMY_HURTBOX_HALF_PX),
+                team: Team::Enemy,
+            },
+            enemy_hit_response(kind),
+            EnemyAi::new(Vec2::new(world_x, world_y)),
+            EnemyStats::for_kind(kind).attack,
+            sprite,
+            transform,
             NpcFacing::default(),
-            NpcAnimKind::Walk,
+            NpcAnimKind::Idle,
             NpcAnimFrame::default(),
-            NpcAnimTimer(Timer::from_seconds(1.0 / NpcAnimKind::Walk.fps(), TimerMode::Repeating)),
+            NpcAnimTimer::default(),
             NpcSheet {
                 idle_frames: ENEMY_IDLE_FRAMES,
                 walk_frames: ENEMY_WALK_FRAMES,
@@ -126,6 +178,33 @@ fn sprite_path(kind: EnemyKind) -> &'static str {
     }
 }
 
+/// Starting health per kind. Slimes pop in a hit or two; bears soak a
+/// full flurry.
+fn enemy_health(kind: EnemyKind) -> Health {
+    match kind {
+        EnemyKind::PurpleSlime => Health(2),
+        EnemyKind::DiseasedFox => Health(3),
+        EnemyKind::DiseasedDeer => Health(4),
+        EnemyKind::DiseasedBear => Health(8),
+    }
+}
+
+/// How hard each kind reels from a hit. Short i-frames stop one swing from
+/// registering twice; the bear barely budges.
+fn enemy_hit_response(kind: EnemyKind) -> HitResponse {
+    let knockback_px = match kind {
+        EnemyKind::PurpleSlime => 20.0,
+        EnemyKind::DiseasedFox => 16.0,
+        EnemyKind::DiseasedDeer => 12.0,
+        EnemyKind::DiseasedBear => 4.0,
+    };
+    HitResponse {
+        invulnerable_secs: 0.2,
+        knockback_px,
+        stun_secs: 0.3,
+    }
+}
+
 /// Despawn every enemy on world teardown.
 pub fn despawn_enemies(mut commands: Commands, q: Query<Entity, With<Enemy>>) {
     for entity in &q {
@@ -0,0 +1,634 @@
+//! Enemy behaviour for diseased creatures and slimes.
+//!
+//! Each `Enemy` carries an [`EnemyAi`] state machine:
+//!
+//! ```text
+//! Idle <-> Patrol --(player in aggro radius)--> Chase
+//! Chase --(in attack range, attack ready)--> Telegraph --> Recover --> Chase / Idle
+//! Chase --(dragged past leash radius)--> Return --> Idle
+//! ```
+//!
+//! The telegraph roots the enemy and tints its sprite so the player can
+//! step out of range; the strike at the end of it goes through
+//! `combat::DamageEvent` against the player's `Health`, or -- for kinds
+//! with a ranged attack, like the spitting slime -- launches a
+//! `combat::Projectile` at the player. Per-kind tuning lives in
+//! [`EnemyStats`]. While a hit has the enemy in `combat::HitStun` the
+//! machine is paused and any telegraph is cancelled. Enemies move one axis
+//! at a time against `SceneryCollider`s, so they slide along trees and
+//! rocks instead of walking through them.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use combat::reaction::blocked;
+use combat::{
+    ApplyStatus, DamageContext, DamageEvent, HitStun, Hurtbox, Projectile, StatusEffect, Team,
+};
+use models::attack::Attack;
+use models::distance::Distance;
+use models::health::Health;
+use models::layer::Layer;
+use models::npc_anim::{NpcAnimKind, NpcFacing};
+use models::palette;
+use models::player::Player;
+use models::scenery::SceneryCollider;
+use models::seconds::Seconds;
+use rand::RngExt;
+
+use crate::area::EnemyKind;
+use crate::enemies::Enemy;
+use crate::spawning::TILE_SIZE_PX;
+
+/// Y-sort scale -- must match the value used in level scenery/decorations.
+const Y_SORT_SCALE: f32 = 0.001;
+
+/// Minimum idle duration between patrol legs (seconds).
+const IDLE_MIN_SECS: f32 = 1.5;
+/// Maximum idle duration between patrol legs (seconds).
+const IDLE_MAX_SECS: f32 = 4.0;
+/// Minimum patrol leg duration (seconds).
+const PATROL_MIN_SECS: f32 = 1.0;
+/// Maximum patrol leg duration (seconds).
+const PATROL_MAX_SECS: f32 = 2.5;
+/// Patrol legs never stray further than this from the spawn point (pixels).
+const PATROL_RADIUS_PX: f32 = 40.0;
+/// Chasing further than this from the spawn point gives up the chase
+/// (pixels). Keeps enemies inside their own area.
+const LEASH_RADIUS_PX: f32 = 160.0;
+/// The player must leave `aggro_radius * DISENGAGE_MULT` to shake a chase.
+const DISENGAGE_MULT: f32 = 1.5;
+/// A `Return` walk ends once the enemy is this close to home (pixels).
+const HOME_EPSILON_PX: f32 = 4.0;
+/// Rendered size of a spit glob (pixels).
+const SPIT_SIZE_PX: f32 = 4.0;
+
+/// Per-`EnemyKind` tuning for the state machine.
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyStats {
+    /// Player distance (pixels) that starts a chase.
+    pub aggro_radius_px: f32,
+    /// Walking speed while idling around home (pixels / second).
+    pub patrol_speed_px: f32,
+    /// Running speed while chasing (pixels / second).
+    pub chase_speed_px: f32,
+    /// Wind-up before the strike lands (seconds).
+    pub telegraph_secs: f32,
+    /// The strike itself. `range` gates when the telegraph starts and
+    /// `cooldown` is the recover window afterwards.
+    pub attack: Attack,
+    /// `Some(speed)` fires the attack as a projectile (pixels / second)
+    /// instead of landing it on the spot.
+    pub projectile_speed_px: Option<f32>,
+    /// Landed strikes also pass on the purple blight
+    /// (`StatusEffect::infection`).
+    pub infects: bool,
+}
+
+impl EnemyStats {
+    pub fn for_kind(kind: EnemyKind) -> Self {
+        match kind {
+            EnemyKind::PurpleSlime => Self {
+                aggro_radius_px: 112.0,
+                patrol_speed_px: 8.0,
+                chase_speed_px: 20.0,
+                telegraph_secs: 0.7,
+                attack: Attack::ranged(Health(1), Distance(500)),
+                projectile_speed_px: Some(72.0),
+                infects: false,
+            },
+            EnemyKind::DiseasedFox => Self {
+                aggro_radius_px: 96.0,
+                patrol_speed_px: 20.0,
+                chase_speed_px: 56.0,
+                telegraph_secs: 0.35,
+                attack: Attack::melee(Health(1)),
+                projectile_speed_px: None,
+                infects: true,
+            },
+            EnemyKind::DiseasedDeer => Self {
+                aggro_radius_px: 72.0,
+                patrol_speed_px: 16.0,
+                chase_speed_px: 48.0,
+                telegraph_secs: 0.5,
+                attack: Attack::melee(Health(1)),
+                projectile_speed_px: None,
+                infects: true,
+            },
+            EnemyKind::DiseasedBear => Self {
+                aggro_radius_px: 80.0,
+                patrol_speed_px: 12.0,
+                chase_speed_px: 36.0,
+                telegraph_secs: 0.9,
+                attack: Attack {
+                    cooldown: Seconds(3),
+                    ..Attack::melee(Health(2))
+                },
+                projectile_speed_px: None,
+                infects: true,
+            },
+        }
+    }
+
+    fn attack_range_px(&self) -> f32 {
+        self.attack.range.tiles() * f32::from(TILE_SIZE_PX)
+    }
+}
+
+/// Current behaviour of an enemy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EnemyState {
+    /// Standing still near home.
+    Idle,
+    /// Walking a short leg in the given (normalised) direction.
+    Patrol(Vec2),
+    /// Running at the player.
+    Chase,
+    /// Rooted wind-up; the strike lands when the timer finishes.
+    Telegraph,
+    /// Post-strike cooldown; stands still.
+    Recover,
+    /// Walking back to the spawn point after losing the player.
+    Return,
+}
+
+/// Drives an enemy's state machine. Attached at spawn by
+/// `enemies::spawn_area_enemies`.
+#[derive(Component, Debug)]
+pub struct EnemyAi {
+    pub state: EnemyState,
+    pub timer: Timer,
+    /// World-space spawn point -- patrols stay near it and chases leash to it.
+    pub origin: Vec2,
+}
+
+impl EnemyAi {
+    pub fn new(origin: Vec2) -> Self {
+        Self {
+            state: EnemyState::Idle,
+            timer: Timer::from_seconds(IDLE_MIN_SECS, TimerMode::Once),
+            origin,
+        }
+    }
+
+    fn enter(&mut self, state: EnemyState, secs: f32) {
+        self.state = state;
+        self.timer = Timer::from_seconds(secs, TimerMode::Once);
+    }
+
+    fn enter_idle(&mut self) {
+        let secs = rand::rng().random_range(IDLE_MIN_SECS..IDLE_MAX_SECS);
+        self.enter(EnemyState::Idle, secs);
+    }
+
+    fn enter_patrol(&mut self, pos: Vec2) {
+        let mut rng = rand::rng();
+        // Bias patrol legs back toward home once we've drifted out.
+        let dir = if pos.distance(self.origin) > PATROL_RADIUS_PX {
+            (self.origin - pos).normalize_or_zero()
+        } else {
+            let angle = rng.random_range(0.0..std::f32::consts::TAU);
+            Vec2::new(angle.cos(), angle.sin())
+        };
+        let secs = rng.random_range(PATROL_MIN_SECS..PATROL_MAX_SECS);
+        self.enter(EnemyState::Patrol(dir), secs);
+    }
+
+    /// Advance the machine by `delta` for an enemy at `pos`, with the
+    /// player at `to_player` relative to it (`None` when there is no
+    /// player). `now` is the attack clock. Ends a telegraph by stamping
+    /// `attack` as used; carrying out the strike is left to the caller.
+    fn step(
+        &mut self,
+        stats: &EnemyStats,
+        attack: &mut Attack,
+        now: f32,
+        delta: Duration,
+        pos: Vec2,
+        to_player: Option<Vec2>,
+    ) -> AiStep {
+        let player_dist = to_player.map_or(f32::INFINITY, Vec2::length);
+        self.timer.tick(delta);
+
+        match self.state {
+            EnemyState::Idle | EnemyState::Patrol(_) if player_dist < stats.aggro_radius_px => {
+                self.enter(EnemyState::Chase, 0.0);
+                AiStep::STILL
+            }
+            EnemyState::Idle => {
+                if self.timer.is_finished() {
+                    self.enter_patrol(pos);
+                }
+                AiStep::STILL
+            }
+            EnemyState::Patrol(dir) => {
+                if self.timer.is_finished() {
+                    self.enter_idle();
+                    AiStep::STILL
+                } else {
+                    AiStep::moving(dir * stats.patrol_speed_px)
+                }
+            }
+            EnemyState::Chase => {
+                if pos.distance(self.origin) > LEASH_RADIUS_PX
+                    || player_dist > stats.aggro_radius_px * DISENGAGE_MULT
+                {
+                    self.enter(EnemyState::Return, 0.0);
+                    AiStep::STILL
+                } else if player_dist <= stats.attack_range_px() && attack.is_ready(now) {
+                    self.enter(EnemyState::Telegraph, stats.telegraph_secs);
+                    AiStep::cue(AiCue::WindUp)
+                } else if player_dist <= stats.attack_range_px() {
+                    AiStep::STILL
+                } else {
+                    AiStep::moving(
+                        to_player
+                            .map_or(Vec2::ZERO, |d| d.normalize_or_zero() * stats.chase_speed_px),
+                    )
+                }
+            }
+            EnemyState::Telegraph => {
+                if self.timer.is_finished() {
+                    attack.mark_used(now);
+                    self.enter(EnemyState::Recover, f32::from(attack.cooldown.0));
+                    AiStep::cue(AiCue::Strike)
+                } else {
+                    AiStep::STILL
+                }
+            }
+            EnemyState::Recover => {
+                if self.timer.is_finished() {
+                    if player_dist < stats.aggro_radius_px {
+                        self.enter(EnemyState::Chase, 0.0);
+                    } else {
+                        self.enter_idle();
+                    }
+                }
+                AiStep::STILL
+            }
+            EnemyState::Return => {
+                let home = self.origin - pos;
+                if home.length() <= HOME_EPSILON_PX {
+                    self.enter_idle();
+                    AiStep::STILL
+                } else {
+                    AiStep::moving(home.normalize() * stats.patrol_speed_px)
+                }
+            }
+        }
+    }
+}
+
+/// Side effect of a step that the system carries out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AiCue {
+    /// A telegraph started: tint the sprite.
+    WindUp,
+    /// The telegraph ended: land or launch the attack, clear the tint.
+    Strike,
+}
+
+/// Result of one [`EnemyAi::step`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AiStep {
+    velocity: Vec2,
+    cue: Option<AiCue>,
+}
+
+impl AiStep {
+    const STILL: Self = Self {
+        velocity: Vec2::ZERO,
+        cue: None,
+    };
+
+    fn moving(velocity: Vec2) -> Self {
+        Self {
+            velocity,
+            cue: None,
+        }
+    }
+
+    fn cue(cue: AiCue) -> Self {
+        Self {
+            velocity: Vec2::ZERO,
+            cue: Some(cue),
+        }
+    }
+}
+
+/// Advance every enemy's state machine, move it, and land strikes on the
+/// player at the end of a telegraph.
+#[allow(clippy::type_complexity)]
+pub fn run_enemy_ai(
+    time: Res<Time>,
+    mut commands: Commands,
+    player_q: Query<(Entity, &Transform), (With<Player>, With<Health>)>,
+    mut enemy_q: Query<
+        (
+            Entity,
+            &Enemy,
+            &mut EnemyAi,
+            &mut Attack,
+            &mut Transform,
+            &mut NpcFacing,
+            &mut NpcAnimKind,
+            &mut Sprite,
+            &Hurtbox,
+            Has<HitStun>,
+        ),
+        Without<Player>,
+    >,
+    colliders: Query<(&Transform, &SceneryCollider), (Without<Enemy>, Without<Player>)>,
+    mut damage: MessageWriter<DamageEvent>,
+    mut status: MessageWriter<ApplyStatus>,
+) {
+    let player = player_q
+        .single()
+        .ok()
+        .map(|(e, tf)| (e, tf.translation.truncate()));
+    let now = time.elapsed_secs();
+    let dt = time.delta_secs();
+
+    for (
+        entity,
+        enemy,
+        mut ai,
+        mut attack,
+        mut tf,
+        mut facing,
+        mut anim,
+        mut sprite,
+        hurtbox,
+        stunned,
+    ) in &mut enemy_q
+    {
+        // Reeling from a hit: knockback owns the transform and a wind-up in
+        // progress is lost. The hit flash paints over its telegraph tint and
+        // clears the sprite back to white when it ends.
+        if stunned {
+            if ai.state == EnemyState::Telegraph {
+                ai.enter(EnemyState::Chase, 0.0);
+            }
+            if *anim != NpcAnimKind::Idle {
+                *anim = NpcAnimKind::Idle;
+            }
+            tf.translation.z = Layer::World.z_f32() - tf.translation.y * Y_SORT_SCALE;
+            continue;
+        }
+
+        let stats = EnemyStats::for_kind(enemy.kind);
+        let pos = tf.translation.truncate();
+        let to_player = player.map(|(_, pp)| pp - pos);
+        let player_dist = to_player.map_or(f32::INFINITY, Vec2::length);
+
+        let step = ai.step(&stats, &mut attack, now, time.delta(), pos, to_player);
+        match step.cue {
+            Some(AiCue::WindUp) => sprite.color = palette::ENEMY_TELEGRAPH,
+            Some(AiCue::Strike) => {
+                match (player, stats.projectile_speed_px) {
+                    (Some(_), Some(speed)) => {
+                        let dir = to_player.unwrap_or(Vec2::ZERO).normalize_or_zero();
+                        commands.spawn((
+                            Projectile::new(
+                                entity,
+                                Team::Enemy,
+                                *attack,
+                                dir * speed,
+                                stats.attack_range_px(),
+                            ),
+                            Sprite::from_color(palette::SLIME_SPIT, Vec2::splat(SPIT_SIZE_PX)),
+                            Transform::from_translation(pos.extend(tf.translation.z)),
+                        ));
+                    }
+                    (Some((target, _)), None) if player_dist <= stats.attack_range_px() => {
+                        damage.write(DamageEvent::with_context(
+                            target,
+                            *attack,
+                            entity,
+                            DamageContext::DEFAULT_PHYSICAL,
+                        ));
+                        if stats.infects {
+                            status.write(ApplyStatus {
+                                target,
+                                effect: StatusEffect::infection(entity),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+                sprite.color = palette::OPAQUE_WHITE;
+            }
+            None => {}
+        }
+        let velocity = step.velocity;
+
+        // Face the player while squaring up to strike; otherwise face travel.
+        let look = match ai.state {
+            EnemyState::Chase | EnemyState::Telegraph => to_player.unwrap_or(velocity),
+            _ => velocity,
+        };
+        if look.length_squared() > 1.0 {
+            *facing = NpcFacing::from_vec2(look);
+        }
+
+        let want_anim = if velocity == Vec2::ZERO {
+            NpcAnimKind::Idle
+        } else {
+            NpcAnimKind::Walk
+        };
+        if *anim != want_anim {
+            *anim = want_anim;
+        }
+
+        // One axis at a time, so a blocked step still slides along the
+        // obstacle. An enemy that spawned inside scenery may walk out.
+        let travel = velocity * dt;
+        let half = hurtbox.half_extents;
+        let free = !blocked(pos, half, colliders.iter());
+        let try_x = pos + Vec2::new(travel.x, 0.0);
+        let pos = if free && blocked(try_x, half, colliders.iter()) {
+            pos
+        } else {
+            try_x
+        };
+        let try_y = pos + Vec2::new(0.0, travel.y);
+        let pos = if free && blocked(try_y, half, colliders.iter()) {
+            pos
+        } else {
+            try_y
+        };
+        tf.translation.x = pos.x;
+        tf.translation.y = pos.y;
+        tf.translation.z = Layer::World.z_f32() - tf.translation.y * Y_SORT_SCALE;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    fn fox() -> (EnemyStats, Attack) {
+        let stats = EnemyStats::for_kind(EnemyKind::DiseasedFox);
+        (stats, stats.attack)
+    }
+
+    fn in_state(state: EnemyState) -> EnemyAi {
+        let mut ai = EnemyAi::new(Vec2::ZERO);
+        ai.enter(state, 0.0);
+        ai
+    }
+
+    #[test]
+    fn aggro_radius_starts_a_chase() {
+        let (stats, mut attack) = fox();
+        let edge = stats.aggro_radius_px;
+
+        let mut ai = EnemyAi::new(Vec2::ZERO);
+        ai.step(
+            &stats,
+            &mut attack,
+            0.0,
+            FRAME,
+            Vec2::ZERO,
+            Some(Vec2::X * (edge + 1.0)),
+        );
+        assert_eq!(ai.state, EnemyState::Idle);
+        ai.step(
+            &stats,
+            &mut attack,
+            0.0,
+            FRAME,
+            Vec2::ZERO,
+            Some(Vec2::X * (edge - 1.0)),
+        );
+        assert_eq!(ai.state, EnemyState::Chase);
+
+        let mut ai = in_state(EnemyState::Patrol(Vec2::Y));
+        ai.timer = Timer::from_seconds(PATROL_MAX_SECS, TimerMode::Once);
+        ai.step(
+            &stats,
+            &mut attack,
+            0.0,
+            FRAME,
+            Vec2::ZERO,
+            Some(Vec2::Y * (edge - 1.0)),
+        );
+        assert_eq!(ai.state, EnemyState::Chase);
+
+        let mut ai = EnemyAi::new(Vec2::ZERO);
+        ai.step(&stats, &mut attack, 0.0, FRAME, Vec2::ZERO, None);
+        assert_eq!(ai.state, EnemyState::Idle);
+    }
+
+    #[test]
+    fn chases_until_the_player_gets_away() {
+        let (stats, mut attack) = fox();
+        let disengage = stats.aggro_radius_px * DISENGAGE_MULT;
+
+        // Past the aggro radius but not yet shaken off: keep running at them.
+        let mut ai = in_state(EnemyState::Chase);
+        let to_player = Vec2::X * (disengage - 1.0);
+        let step = ai.step(&stats, &mut attack, 0.0, FRAME, Vec2::ZERO, Some(to_player));
+        assert_eq!(ai.state, EnemyState::Chase);
+        assert_eq!(step.velocity, Vec2::X * stats.chase_speed_px);
+
+        let mut ai = in_state(EnemyState::Chase);
+        let to_player = Vec2::X * (disengage + 1.0);
+        ai.step(&stats, &mut attack, 0.0, FRAME, Vec2::ZERO, Some(to_player));
+        assert_eq!(ai.state, EnemyState::Return);
+    }
+
+    #[test]
+    fn leash_pulls_a_chase_home() {
+        let (stats, mut attack) = fox();
+        let to_player = Some(Vec2::X * stats.aggro_radius_px * 0.5);
+
+        let mut ai = in_state(EnemyState::Chase);
+        let inside = Vec2::Y * (LEASH_RADIUS_PX - 1.0);
+        ai.step(&stats, &mut attack, 0.0, FRAME, inside, to_player);
+        assert_eq!(ai.state, EnemyState::Chase);
+
+        let outside = Vec2::Y * (LEASH_RADIUS_PX + 1.0);
+        ai.step(&stats, &mut attack, 0.0, FRAME, outside, to_player);
+        assert_eq!(ai.state, EnemyState::Return);
+
+        // Walks back at patrol speed, then settles once home.
+        let step = ai.step(&stats, &mut attack, 0.0, FRAME, outside, None);
+        assert_eq!(step.velocity, Vec2::NEG_Y * stats.patrol_speed_px);
+        ai.step(
+            &stats,
+            &mut attack,
+            0.0,
+            FRAME,
+            Vec2::Y * HOME_EPSILON_PX,
+            None,
+        );
+        assert_eq!(ai.state, EnemyState::Idle);
+    }
+
+    #[test]
+    fn telegraph_strikes_then_recovers_for_the_cooldown() {
+        let (stats, mut attack) = fox();
+        let to_player = Some(Vec2::X * (stats.attack_range_px() - 1.0));
+        let telegraph = Duration::from_secs_f32(stats.telegraph_secs);
+        let cooldown = Duration::from_secs(u64::from(attack.cooldown.0));
+
+        let mut ai = in_state(EnemyState::Chase);
+        let step = ai.step(&stats, &mut attack, 0.0, FRAME, Vec2::ZERO, to_player);
+        assert_eq!(ai.state, EnemyState::Telegraph);
+        assert_eq!(step.cue, Some(AiCue::WindUp));
+
+        // Rooted for the whole wind-up.
+        let step = ai.step(
+            &stats,
+            &mut attack,
+            0.2,
+            telegraph - FRAME,
+            Vec2::ZERO,
+            to_player,
+        );
+        assert_eq!(ai.state, EnemyState::Telegraph);
+        assert_eq!(step, AiStep::STILL);
+
+        let step = ai.step(&stats, &mut attack, 0.4, FRAME, Vec2::ZERO, to_player);
+        assert_eq!(ai.state, EnemyState::Recover);
+        assert_eq!(step.cue, Some(AiCue::Strike));
+        assert_eq!(attack.last, Some(0.4));
+        assert_eq!(ai.timer.duration(), cooldown);
+
+        ai.step(
+            &stats,
+            &mut attack,
+            1.0,
+            cooldown - FRAME,
+            Vec2::ZERO,
+            to_player,
+        );
+        assert_eq!(ai.state, EnemyState::Recover);
+        ai.step(&stats, &mut attack, 1.4, FRAME, Vec2::ZERO, to_player);
+        assert_eq!(ai.state, EnemyState::Chase);
+    }
+
+    #[test]
+    fn recovery_loses_interest_in_a_distant_player() {
+        let (stats, mut attack) = fox();
+        let mut ai = in_state(EnemyState::Recover);
+        let far = Some(Vec2::X * (stats.aggro_radius_px + 1.0));
+        ai.step(&stats, &mut attack, 0.0, FRAME, Vec2::ZERO, far);
+        assert_eq!(ai.state, EnemyState::Idle);
+    }
+
+    #[test]
+    fn waits_in_range_while_the_attack_cools_down() {
+        let (stats, mut attack) = fox();
+        attack.mark_used(0.0);
+        let to_player = Some(Vec2::X * (stats.attack_range_px() - 1.0));
+
+        let mut ai = in_state(EnemyState::Chase);
+        let step = ai.step(&stats, &mut attack, 0.5, FRAME, Vec2::ZERO, to_player);
+        assert_eq!(ai.state, EnemyState::Chase);
+        assert_eq!(step, AiStep::STILL);
+    }
+}
@@ -56,6 +56,7 @@ pub fn spawn_galen(
         &mut atlas_layouts,
         &shadow_assets,
         world.current,
+        world.talker_id(world.current, "Galen"),
     );
 }
 
@@ -65,6 +66,7 @@ fn spawn_galen_entity(
     atlas_layouts: &mut Assets<TextureAtlasLayout>,
     shadow_assets: &DropShadowAssets,
     start_area: IVec2,
+    talker_id: String,
 ) {
     let layout = TextureAtlasLayout::from_grid(
         UVec2::splat(FRAME_SIZE_PX),
@@ -115,16 +117,16 @@ fn spawn_galen_entity(
             NpcAnimTimer::default(),
             NpcWander::new(pos.truncate()),
             // Non-repeating: Galen only asks one question, then stops offering dialogue.
-            Talker::new(chosen),
-            BarkPool {
-                barks: vec![
+            Talker::new(talker_id, chosen),
+            BarkPool::new(
+                vec![
                     asset_server.load("dialogue/barks/galen_bark1.dialog.ron"),
                     asset_server.load("dialogue/barks/galen_bark2.dialog.ron"),
                     asset_server.load("dialogue/barks/galen_bark3.dialog.ron"),
                 ],
-                trigger_radius_px: BARK_RADIUS_PX,
-                cooldown: Timer::from_seconds(BARK_COOLDOWN_SECS, TimerMode::Once),
-            },
+                BARK_RADIUS_PX,
+                Timer::from_seconds(BARK_COOLDOWN_SECS, TimerMode::Once),
+            ),
         ))
         .id();
 
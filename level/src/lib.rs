@@ -3,12 +3,16 @@ pub mod bark_bubbles;
 pub mod beach;
 pub mod biome_registry;
 pub mod blending;
+pub mod checkpoint;
 pub mod creatures;
 pub mod decorations;
+pub mod dialogue_actions;
 pub mod enemies;
+pub mod enemy_ai;
 pub mod exit;
 pub mod galen;
 pub mod grass;
+pub mod loot;
 pub mod npc_anim;
 pub mod npc_labels;
 pub mod npc_wander;
@@ -16,6 +20,8 @@ pub mod npcs;
 pub mod plugin;
 pub mod portal;
 pub mod puddles;
+pub mod purify;
+pub mod quest_progress;
 pub mod reveal;
 pub mod scenery;
 pub mod shadows;
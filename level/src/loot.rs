@@ -0,0 +1,331 @@
+//! Loot: what enemies leave behind when they die.
+//!
+//! Each `EnemyKind` has a [`LootTable`] -- a handful of weighted entries
+//! rolled a fixed number of times -- read from [`LOOT_TABLES_PATH`]
+//! (`assets/loot/enemies.loot.ron`). Rolls are seeded from the map seed and
+//! the enemy's area and slot -- never its `Entity`, which changes every
+//! time the area respawns -- so the same fight on the same map always
+//! drops the same thing.
+//!
+//! Drops become world [`Pickup`] entities: a small y-sorted sprite with a
+//! drop shadow, scattered around the body. The player collects them by
+//! walking over them (see the player crate), which writes an
+//! [`ItemPickedUp`] message for the inventory to consume.
+
+use std::collections::HashMap;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+use combat::DeathOccurred;
+use models::item::ItemKind;
+use models::layer::Layer;
+use models::palette;
+use models::shadow::{LOOT_SHADOW_HALF_PX, LOOT_SHADOW_OFFSET_Y_PX};
+use serde::Deserialize;
+
+use crate::area::EnemyKind;
+use crate::enemies::Enemy;
+use crate::purify;
+use crate::shadows::{spawn_drop_shadow, DropShadowAssets};
+use crate::world::WorldMap;
+
+/// Y-sort scale -- must match the value used in level scenery/decorations.
+const Y_SORT_SCALE: f32 = 0.001;
+/// Rendered size of a placeholder pickup sprite (pixels).
+const PICKUP_SIZE_PX: f32 = 6.0;
+/// Drops land within this distance of the body (pixels).
+const SCATTER_RADIUS_PX: f32 = 10.0;
+
+/// Asset path of the enemy loot tables.
+pub const LOOT_TABLES_PATH: &str = "loot/enemies.loot.ron";
+
+/// One weighted outcome of a roll. `item: None` (or leaving it out) is an
+/// explicit "nothing" share of the weight.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct LootEntry {
+    #[serde(default)]
+    pub item: Option<ItemKind>,
+    pub weight: u16,
+    /// Inclusive stack-size range for the drop.
+    #[serde(default)]
+    pub min: u8,
+    #[serde(default)]
+    pub max: u8,
+}
+
+/// What one enemy kind can drop: `rolls` independent weighted picks from
+/// `entries`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LootTable {
+    pub rolls: u8,
+    pub entries: Vec<LootEntry>,
+}
+
+/// Every enemy kind's [`LootTable`], loaded from a `.loot.ron` file. Kinds
+/// without a table drop nothing.
+#[derive(Asset, TypePath, Debug, Clone, Default, Deserialize)]
+pub struct LootTables(pub HashMap<EnemyKind, LootTable>);
+
+impl LootTables {
+    pub fn for_kind(&self, kind: EnemyKind) -> Option<&LootTable> {
+        self.0.get(&kind)
+    }
+}
+
+/// Keeps [`LOOT_TABLES_PATH`] loaded.
+#[derive(Resource, Default)]
+pub struct LootLibrary(pub Handle<LootTables>);
+
+/// Startup system: loads [`LOOT_TABLES_PATH`].
+pub fn load_loot_tables(asset_server: Res<AssetServer>, mut library: ResMut<LootLibrary>) {
+    library.0 = asset_server.load(LOOT_TABLES_PATH);
+}
+
+/// Loads `.loot.ron` files into [`LootTables`] assets.
+#[derive(Default, TypePath)]
+pub struct LootTablesLoader;
+
+impl AssetLoader for LootTablesLoader {
+    type Asset = LootTables;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _ctx: &mut LoadContext<'_>,
+    ) -> Result<LootTables, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let text = std::str::from_utf8(&bytes)?;
+        Ok(ron::from_str(text)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["loot.ron"]
+    }
+}
+
+impl LootTable {
+    /// Roll the table. Deterministic in `seed`; repeat drops of the same
+    /// item are merged into one stack.
+    pub fn roll(&self, seed: u64) -> Vec<(ItemKind, u8)> {
+        let total: u64 = self.entries.iter().map(|e| u64::from(e.weight)).sum();
+        let mut drops: Vec<(ItemKind, u8)> = Vec::new();
+        if total == 0 {
+            return drops;
+        }
+        let mut rng = lcg(seed);
+        for _ in 0..self.rolls {
+            rng = lcg(rng);
+            let mut pick = (rng >> 33) % total;
+            let Some(entry) = self.entries.iter().find(|e| {
+                let w = u64::from(e.weight);
+                if pick < w {
+                    return true;
+                }
+                pick -= w;
+                false
+            }) else {
+                continue;
+            };
+            let Some(item) = entry.item else {
+                continue;
+            };
+            rng = lcg(rng);
+            let span = u64::from(entry.max.saturating_sub(entry.min)) + 1;
+            let extra = u8::try_from((rng >> 33) % span).unwrap_or(0);
+            let count = entry.min.max(1).saturating_add(extra);
+            match drops.iter_mut().find(|(i, _)| *i == item) {
+                Some((_, n)) => *n = n.saturating_add(count),
+                None => drops.push((item, count)),
+            }
+        }
+        drops
+    }
+}
+
+/// A dropped item lying in the world, waiting to be walked over.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Pickup {
+    pub item: ItemKind,
+    pub count: u8,
+}
+
+/// Message: `collector` picked up `count` of `item`.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ItemPickedUp {
+    pub collector: Entity,
+    pub item: ItemKind,
+    pub count: u8,
+}
+
+/// Placeholder tint for a pickup until item art lands.
+fn item_color(item: ItemKind) -> Color {
+    match item {
+        ItemKind::SlimeGel => palette::LOOT_SLIME_GEL,
+        ItemKind::FoxTuft => palette::LOOT_FOX_TUFT,
+        ItemKind::Antler => palette::LOOT_ANTLER,
+        ItemKind::BearClaw => palette::LOOT_BEAR_CLAW,
+        ItemKind::BlightSpore => palette::LOOT_BLIGHT_SPORE,
+    }
+}
+
+/// Observer: roll the dying enemy's table and scatter the drops around
+/// its body. Purified creatures walk away with everything they had.
+pub fn drop_loot(
+    on: On<DeathOccurred>,
+    mut commands: Commands,
+    enemies: Query<(&Enemy, &Transform)>,
+    world: Res<WorldMap>,
+    library: Res<LootLibrary>,
+    tables: Res<Assets<LootTables>>,
+    shadow_assets: Res<DropShadowAssets>,
+) {
+    let Ok((enemy, tf)) = enemies.get(on.entity) else {
+        return;
+    };
+    if purify::is_restored(enemy.kind, &on.context) {
+        return;
+    }
+    let Some(table) = tables.get(&library.0).and_then(|t| t.for_kind(enemy.kind)) else {
+        warn!("No loot table for {:?}; dropping nothing.", enemy.kind);
+        return;
+    };
+    let seed = loot_seed(world.seed(), enemy);
+    let origin = tf.translation.truncate();
+    let mut rng = seed;
+    for (item, count) in table.roll(seed) {
+        rng = lcg(rng);
+        let angle = unit(rng) * std::f32::consts::TAU;
+        rng = lcg(rng);
+        let dist = unit(rng) * SCATTER_RADIUS_PX;
+        let pos = origin + Vec2::new(angle.cos(), angle.sin()) * dist;
+        let pickup = commands
+            .spawn((
+                Pickup { item, count },
+                Sprite::from_color(item_color(item), Vec2::splat(PICKUP_SIZE_PX)),
+                Transform::from_xyz(pos.x, pos.y, Layer::World.z_f32() - pos.y * Y_SORT_SCALE),
+            ))
+            .id();
+        spawn_drop_shadow(
+            &mut commands,
+            &shadow_assets,
+            pickup,
+            LOOT_SHADOW_HALF_PX,
+            LOOT_SHADOW_OFFSET_Y_PX,
+        );
+    }
+}
+
+/// Despawn every pickup on world teardown.
+pub fn despawn_pickups(mut commands: Commands, q: Query<Entity, With<Pickup>>) {
+    for entity in &q {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Roll seed for `enemy` on the map with `map_seed`. The area and spawn
+/// slot pick out the same enemy every time the area is spawned.
+fn loot_seed(map_seed: u64, enemy: &Enemy) -> u64 {
+    let ax = u64::from(u32::from_ne_bytes(enemy.area.x.to_ne_bytes()));
+    let ay = u64::from(u32::from_ne_bytes(enemy.area.y.to_ne_bytes()));
+    lcg(lcg(map_seed ^ (ax << 32 | ay)) ^ u64::from(enemy.slot))
+}
+
+/// Map the high bits of `state` to `[0, 1)`.
+fn unit(state: u64) -> f32 {
+    f32::from(u16::try_from(state >> 48).unwrap_or(0)) / 65_536.0
+}
+
+fn lcg(state: u64) -> u64 {
+    state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_KINDS: [EnemyKind; 4] = [
+        EnemyKind::PurpleSlime,
+        EnemyKind::DiseasedFox,
+        EnemyKind::DiseasedDeer,
+        EnemyKind::DiseasedBear,
+    ];
+
+    fn shipped_tables() -> LootTables {
+        ron::from_str(include_str!("../../assets/loot/enemies.loot.ron"))
+            .expect("shipped loot tables parse")
+    }
+
+    fn table(src: &str) -> LootTable {
+        ron::from_str(src).expect("test table parses")
+    }
+
+    #[test]
+    fn every_enemy_kind_has_a_table() {
+        let tables = shipped_tables();
+        for kind in ALL_KINDS {
+            let table = tables.for_kind(kind);
+            assert!(
+                table.is_some_and(|t| t.rolls > 0 && !t.entries.is_empty()),
+                "{kind:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rolls_are_deterministic() {
+        let tables = shipped_tables();
+        for table in ALL_KINDS.iter().filter_map(|kind| tables.for_kind(*kind)) {
+            for seed in 0..64 {
+                assert_eq!(table.roll(seed), table.roll(seed));
+            }
+        }
+    }
+
+    #[test]
+    fn counts_stay_in_range() {
+        let tables = shipped_tables();
+        let Some(table) = tables.for_kind(EnemyKind::DiseasedBear) else {
+            panic!("bear table missing");
+        };
+        let max_stack: u8 = table.entries.iter().map(|e| e.max).max().unwrap_or(0);
+        for seed in 0..256 {
+            for (_, count) in table.roll(seed) {
+                assert!(count >= 1);
+                assert!(count <= max_stack * table.rolls);
+            }
+        }
+    }
+
+    #[test]
+    fn weights_shape_the_outcome() {
+        let always_gel = table(
+            "(rolls: 3, entries: [(item: Some(SlimeGel), weight: 1, min: 1, max: 1), (weight: 0)])",
+        );
+        for seed in 0..32 {
+            assert_eq!(always_gel.roll(seed), vec![(ItemKind::SlimeGel, 3)]);
+        }
+        let never = table("(rolls: 3, entries: [(weight: 10)])");
+        assert!(never.roll(7).is_empty());
+    }
+
+    #[test]
+    fn seeds_follow_the_enemy_not_the_entity() {
+        let enemy = |x, slot| Enemy {
+            kind: EnemyKind::DiseasedFox,
+            area: IVec2::new(x, -2),
+            slot,
+        };
+        // A respawned enemy is a new entity with the same area and slot.
+        assert_eq!(loot_seed(42, &enemy(3, 1)), loot_seed(42, &enemy(3, 1)));
+        assert_ne!(loot_seed(42, &enemy(3, 1)), loot_seed(42, &enemy(3, 2)));
+        assert_ne!(loot_seed(42, &enemy(3, 1)), loot_seed(42, &enemy(4, 1)));
+        assert_ne!(loot_seed(42, &enemy(3, 1)), loot_seed(43, &enemy(3, 1)));
+    }
+}
@@ -5,7 +5,8 @@
 //! current area are spawned.
 
 use bevy::prelude::*;
-use dialog::components::{BarkPool, Talker};
+use dialog::components::{BarkPool, GreetedNpcs, Talker};
+use models::decoration::Biome;
 use models::layer::Layer;
 use models::npc_anim::{NpcAnimFrame, NpcAnimKind, NpcAnimTimer, NpcFacing, NpcSheet};
 use models::scenery::SceneryCollider;
@@ -14,6 +15,7 @@ use crate::area::{AreaEvent, NpcKind, MAP_HEIGHT, MAP_WIDTH};
 use crate::npc_wander::NpcWander;
 use crate::shadows::{spawn_drop_shadow, DropShadowAssets};
 use crate::spawning::TILE_SIZE_PX;
+use crate::world::WorldMap;
 use models::shadow::{NPC_SHADOW_HALF_PX, NPC_SHADOW_OFFSET_Y_PX};
 
 // All NPCs spawn at the path intersection, which is guaranteed dirt.
@@ -36,9 +38,10 @@ const WALK_FRAMES: usize = 4;
 // Marker component
 // ---------------------------------------------------------------------------
 
-/// Shared marker for all area-event NPCs (not Galen, who is separate).
+/// Shared marker for all area-event NPCs (not Galen, who is separate),
+/// naming which NPC this is.
 #[derive(Component)]
-pub struct EventNpc;
+pub struct EventNpc(pub NpcKind);
 
 // ---------------------------------------------------------------------------
 // Spawn / despawn systems
@@ -50,6 +53,16 @@ pub fn despawn_npcs(mut commands: Commands, q: Query<Entity, With<EventNpc>>) {
     }
 }
 
+/// Forgets greetings from other maps. Every portal crossing rolls a fresh
+/// seed, so those talker ids can never come up again and would otherwise
+/// pile up in the save.
+pub fn prune_greeted_npcs(world: Res<WorldMap>, mut greeted: ResMut<GreetedNpcs>) {
+    if greeted.0.iter().all(|id| world.owns_talker_id(id)) {
+        return;
+    }
+    greeted.0.retain(|id| world.owns_talker_id(id));
+}
+
 /// Spawn an NPC for the given area at its absolute world position.
 /// Called from `spawning::ensure_area_spawned`.
 pub fn spawn_npc_for_area(
@@ -59,11 +72,13 @@ pub fn spawn_npc_for_area(
     shadow_assets: &DropShadowAssets,
     area: &crate::area::Area,
     area_pos: IVec2,
+    world: &WorldMap,
 ) {
     let AreaEvent::NpcEncounter(npc_kind) = area.event else {
         return;
     };
     let base = crate::spawning::area_world_offset(area_pos);
+    let talker_id = world.talker_id(area_pos, npc_kind.as_str());
     spawn_npc(
         commands,
         asset_server,
@@ -71,9 +86,12 @@ pub fn spawn_npc_for_area(
         shadow_assets,
         npc_kind,
         base,
+        talker_id,
+        Biome::from_alignment(area.alignment),
     );
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_npc(
     commands: &mut Commands,
     asset_server: &AssetServer,
@@ -81,20 +99,22 @@ fn spawn_npc(
     shadow_assets: &DropShadowAssets,
     kind: NpcKind,
     base: Vec2,
+    talker_id: String,
+    biome: Biome,
 ) {
     let (name, sheet, script, barks) = npc_data(kind);
     let pos = tile_world_pos(PATH_CENTER_X, PATH_CENTER_Y, base);
 
     let parent = commands
         .spawn((
-            EventNpc,
+            EventNpc(kind),
             Name::new(name),
             npc_sprite(asset_server, atlas_layouts, sheet),
             npc_collider(),
             Transform::from_translation(pos),
             npc_anim_bundle(pos.truncate()),
-            Talker::new(asset_server.load(script)),
-            bark_pool(asset_server, barks),
+            Talker::new(talker_id, asset_server.load(script)),
+            bark_pool(asset_server, barks, biome),
         ))
         .id();
 
@@ -125,6 +145,7 @@ fn npc_data(
                 "dialogue/barks/mordred_barks.dialog.ron",
                 "dialogue/barks/mordred_barks2.dialog.ron",
                 "dialogue/barks/mordred_barks3.dialog.ron",
+                "dialogue/barks/mordred_rain.dialog.ron",
             ],
         ),
         NpcKind::Drizella => (
@@ -135,6 +156,7 @@ fn npc_data(
                 "dialogue/barks/drizella_barks.dialog.ron",
                 "dialogue/barks/drizella_barks2.dialog.ron",
                 "dialogue/barks/drizella_barks3.dialog.ron",
+                "dialogue/barks/drizella_night.dialog.ron",
             ],
         ),
         NpcKind::Bigby => (
@@ -155,6 +177,7 @@ fn npc_data(
                 "dialogue/barks/mother_gothel_barks.dialog.ron",
                 "dialogue/barks/mother_gothel_barks2.dialog.ron",
                 "dialogue/barks/mother_gothel_barks3.dialog.ron",
+                "dialogue/barks/mother_gothel_darkwoods.dialog.ron",
             ],
         ),
         NpcKind::Morgana => (
@@ -212,12 +235,13 @@ pub fn update_npc_z(mut query: Query<&mut Transform, With<EventNpc>>) {
     }
 }
 
-fn bark_pool(asset_server: &AssetServer, paths: &[&'static str]) -> BarkPool {
-    BarkPool {
-        barks: paths.iter().map(|p| asset_server.load(*p)).collect(),
-        trigger_radius_px: BARK_RADIUS_PX,
-        cooldown: Timer::from_seconds(BARK_COOLDOWN_SECS, TimerMode::Once),
-    }
+fn bark_pool(asset_server: &AssetServer, paths: &[&'static str], biome: Biome) -> BarkPool {
+    BarkPool::new(
+        paths.iter().map(|p| asset_server.load(*p)).collect(),
+        BARK_RADIUS_PX,
+        Timer::from_seconds(BARK_COOLDOWN_SECS, TimerMode::Once),
+    )
+    .in_biome(biome)
 }
 
 fn npc_sprite(
@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::TilemapPlugin;
 use models::alignment::PlayerAlignment;
 use models::game_states::{should_despawn_world, GameState};
-use models::multiverse::MultiverseSave;
+use models::multiverse::{MultiverseSave, PurifiedEnemies};
 use models::time::GameClock;
 
 use models::weather::WeatherState;
@@ -11,17 +11,23 @@ use models::wind::{WindDirection, WindStrength};
 use crate::bark_bubbles;
 use crate::beach;
 use crate::biome_registry::BiomeRegistry;
+use crate::checkpoint::RespawnRequested;
 use crate::creatures;
 use crate::decorations;
+use crate::dialogue_actions;
 use crate::enemies;
+use crate::enemy_ai;
 use crate::galen;
 use crate::grass;
+use crate::loot::{self, ItemPickedUp};
 use crate::npc_anim;
 use crate::npc_labels::{self, InteractIconState};
 use crate::npc_wander;
 use crate::npcs;
 use crate::portal::{self, PortalCrossed};
 use crate::puddles;
+use crate::purify;
+use crate::quest_progress;
 use crate::reveal;
 use crate::scenery;
 use crate::shadows;
@@ -47,21 +53,34 @@ impl Plugin for LevelPlugin {
             .init_resource::<WeatherState>()
             .init_resource::<puddles::PuddleSpawnTimer>()
             .init_resource::<puddles::SteamAccumulator>()
+            .init_resource::<loot::LootLibrary>()
             .insert_resource(BiomeRegistry::build())
             .add_plugins(TilemapPlugin)
+            .init_asset::<loot::LootTables>()
+            .init_asset_loader::<loot::LootTablesLoader>()
             .add_systems(
                 Startup,
-                (shadows::init_shadow_assets, wang::init_wang_tilesets),
+                (
+                    shadows::init_shadow_assets,
+                    wang::init_wang_tilesets,
+                    loot::load_loot_tables,
+                ),
             )
             .add_message::<AreaChanged>()
             .add_message::<PortalCrossed>()
+            .add_message::<ItemPickedUp>()
+            .add_observer(loot::drop_loot)
+            .add_observer(purify::purify_enemy)
+            .add_observer(quest_progress::report_enemy_death)
             .init_resource::<portal::PendingPortal>()
             .init_resource::<portal::MapsTraversed>()
+            .init_resource::<RespawnRequested>()
             .insert_resource(WorldMap::new(rand::random(), DEFAULT_PLAYER_ALIGNMENT))
             .add_systems(
                 OnEnter(GameState::Playing),
                 (
                     regenerate_world,
+                    npcs::prune_greeted_npcs,
                     spawning::spawn_initial_areas,
                     galen::spawn_galen,
                 )
@@ -95,13 +114,19 @@ impl Plugin for LevelPlugin {
             )
             .add_systems(
                 Update,
-                (portal::enter_map_transition, portal::animate_mirror_mary)
+                (
+                    portal::enter_map_transition,
+                    portal::animate_mirror_mary,
+                    quest_progress::report_area_entered,
+                )
                     .run_if(in_state(GameState::Playing)),
             )
             .add_systems(
                 OnEnter(GameState::MapTransition),
                 portal::apply_map_transition,
             )
+            // The conversation has already switched the state to Dialogue.
+            .add_systems(Update, quest_progress::report_talked_to)
             .add_systems(
                 Update,
                 (
@@ -128,6 +153,7 @@ impl Plugin for LevelPlugin {
                     creatures::creature_movement,
                     creatures::creature_animation,
                     creatures::creature_flying_bob,
+                    enemy_ai::run_enemy_ai,
                 )
                     .run_if(in_state(GameState::Playing)),
             )
@@ -152,9 +178,12 @@ impl Plugin for LevelPlugin {
                     beach::despawn_piers,
                     portal::despawn_portals,
                     enemies::despawn_enemies,
+                    loot::despawn_pickups,
                 )
                     .run_if(should_despawn_world),
             );
+
+        dialogue_actions::register(app);
     }
 }
 
@@ -166,7 +195,9 @@ const DEFAULT_PLAYER_ALIGNMENT: u8 = 50;
 
 /// Regenerate the world with a fresh seed, biased toward the player's
 /// dominant faction alignment.  Skips if a world is already loaded
-/// (e.g. returning from Dialogue or Paused).
+/// (e.g. returning from Dialogue or Paused), and keeps the current map
+/// when respawning after a game over.
+#[allow(clippy::too_many_arguments)]
 fn regenerate_world(
     mut world: ResMut<WorldMap>,
     mut clock: ResMut<GameClock>,
@@ -174,11 +205,20 @@ fn regenerate_world(
     spawned: Res<SpawnedAreas>,
     mut pending: ResMut<portal::PendingPortal>,
     mut save: ResMut<MultiverseSave>,
+    mut purified: ResMut<PurifiedEnemies>,
     mut traversed: ResMut<portal::MapsTraversed>,
+    mut respawn: ResMut<RespawnRequested>,
 ) {
     if !spawned.0.is_empty() {
         return;
     }
+    // Back on our feet after a game over: same map, same save, just the
+    // checkpoint area.
+    if respawn.0 {
+        respawn.0 = false;
+        world.return_to_start();
+        return;
+    }
     // If we just arrived here from a portal crossing, leave the
     // portal-generated `WorldMap` intact and consume the flag.
     if pending.just_transitioned {
@@ -196,12 +236,16 @@ fn regenerate_world(
             save.current_alignment,
             save.maps_traversed,
         );
+        world.restore_purified(&purified);
         clock.hour = rand::random::<f32>() * HOURS_PER_DAY;
         return;
     }
     let dominant = alignment.dominant_area_alignment();
     let new_seed: u64 = rand::random();
     *world = WorldMap::new(new_seed, dominant);
+    if !purified.0.is_empty() {
+        purified.0.clear();
+    }
     clock.hour = rand::random::<f32>() * HOURS_PER_DAY;
     // Stamp the freshly-generated root map into the save resource so we
     // can resume on next launch.
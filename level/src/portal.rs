@@ -212,13 +212,15 @@ pub fn enter_map_transition(
 /// `OnEnter(MapTransition)` system: regenerate [`crate::world::WorldMap`] at
 /// the pending alignment and immediately switch back to `Playing`, which
 /// fires the respawn chain. Updates [`models::multiverse::MultiverseSave`]
-/// so a reload after this point lands the player in the same destination.
+/// so a reload after this point lands the player in the same destination,
+/// and forgets the previous map's purified enemies.
 pub fn apply_map_transition(
     mut pending: ResMut<PendingPortal>,
     mut world: ResMut<crate::world::WorldMap>,
     mut spawned: ResMut<crate::spawning::SpawnedAreas>,
     mut traversed: ResMut<MapsTraversed>,
     mut save: ResMut<models::multiverse::MultiverseSave>,
+    mut purified: ResMut<models::multiverse::PurifiedEnemies>,
     mut next: ResMut<NextState<models::game_states::GameState>>,
 ) {
     let alignment = pending.alignment.take().unwrap_or(world.alignment);
@@ -233,5 +235,6 @@ pub fn apply_map_transition(
     save.current_seed = new_seed;
     save.current_alignment = alignment;
     save.maps_traversed = traversed.0;
+    purified.0.clear();
     next.set(models::game_states::GameState::Playing);
 }
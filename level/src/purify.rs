@@ -0,0 +1,110 @@
+//! Purification: the druid's answer to the blight.
+//!
+//! Diseased foxes, deer and bears are ordinary wildlife under the purple
+//! rot. When `DamageContext::Purify` brings one to zero, the enemy entity
+//! is still despawned by combat's default death observer, but
+//! [`purify_enemy`] puts the healthy animal in its place -- a plain
+//! `Creature` that bolts away from the player -- drops no loot, and marks
+//! the enemy's slot purified in `WorldMap` -- and in the saved
+//! `PurifiedEnemies` -- so the area never respawns it.
+//! Next time the area is spawned, the healed animal is there instead.
+//!
+//! Slimes have nothing underneath; purifying one just pops it.
+
+use bevy::prelude::*;
+use combat::{DamageContext, DeathOccurred};
+use models::creature::{Creature, CreatureAi, CreatureState, MovementType};
+use models::multiverse::PurifiedEnemies;
+use models::palette;
+use models::shadow::{NPC_SHADOW_HALF_PX, NPC_SHADOW_OFFSET_Y_PX};
+
+use crate::area::EnemyKind;
+use crate::enemies::Enemy;
+use crate::shadows::{spawn_drop_shadow, DropShadowAssets};
+use crate::world::WorldMap;
+
+/// Wander speeds for the healed animals (pixels per second).
+const SPEED_FOX_PX: f32 = 56.0;
+const SPEED_DEER_PX: f32 = 48.0;
+const SPEED_BEAR_PX: f32 = 32.0;
+
+/// Wander speed of the healthy animal under `kind`, or `None` when there
+/// is nothing to restore.
+pub fn healthy_speed(kind: EnemyKind) -> Option<f32> {
+    match kind {
+        EnemyKind::PurpleSlime => None,
+        EnemyKind::DiseasedFox => Some(SPEED_FOX_PX),
+        EnemyKind::DiseasedDeer => Some(SPEED_DEER_PX),
+        EnemyKind::DiseasedBear => Some(SPEED_BEAR_PX),
+    }
+}
+
+/// True when this death is a purification that restores the creature
+/// rather than killing it.
+pub fn is_restored(kind: EnemyKind, context: &DamageContext) -> bool {
+    matches!(context, DamageContext::Purify) && healthy_speed(kind).is_some()
+}
+
+/// Spawn the healed animal. `sprite` is the enemy's sheet sprite; it's
+/// pinned to the first (south-facing) frame and tinted.
+pub(crate) fn spawn_healed_creature(
+    commands: &mut Commands,
+    shadow_assets: &DropShadowAssets,
+    mut sprite: Sprite,
+    speed: f32,
+    transform: Transform,
+    seed: u32,
+    fleeing: bool,
+) {
+    if let Some(atlas) = &mut sprite.texture_atlas {
+        atlas.index = 0;
+    }
+    // Placeholder until healthy fox / deer / bear art exists.
+    sprite.color = palette::PURIFIED_TINT;
+    let mut ai = CreatureAi::new(speed, MovementType::Ground, seed);
+    if fleeing {
+        ai.state = CreatureState::Flee;
+        ai.timer = Timer::from_seconds(0.0, TimerMode::Once);
+    }
+    let parent = commands.spawn((Creature, ai, sprite, transform)).id();
+    spawn_drop_shadow(
+        commands,
+        shadow_assets,
+        parent,
+        NPC_SHADOW_HALF_PX,
+        NPC_SHADOW_OFFSET_Y_PX,
+    );
+}
+
+/// Observer: swap a purified enemy for its healthy animal.
+pub fn purify_enemy(
+    on: On<DeathOccurred>,
+    mut commands: Commands,
+    enemies: Query<(&Enemy, &Transform, &Sprite)>,
+    mut world: ResMut<WorldMap>,
+    mut purified: ResMut<PurifiedEnemies>,
+    shadow_assets: Res<DropShadowAssets>,
+) {
+    let Ok((enemy, tf, sprite)) = enemies.get(on.entity) else {
+        return;
+    };
+    if !is_restored(enemy.kind, &on.context) {
+        return;
+    }
+    let Some(speed) = healthy_speed(enemy.kind) else {
+        return;
+    };
+    world.mark_purified(enemy.area, enemy.slot);
+    purified
+        .0
+        .insert(([enemy.area.x, enemy.area.y], enemy.slot));
+    spawn_healed_creature(
+        &mut commands,
+        &shadow_assets,
+        sprite.clone(),
+        speed,
+        *tf,
+        u32::from(enemy.slot),
+        true,
+    );
+}
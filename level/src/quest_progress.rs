@@ -0,0 +1,68 @@
+//! Reports what the player does in the world to the quest system
+//! (`dialog::quest`).
+//!
+//! - Crossing into an area sends `QuestProgress::EnteredArea`.
+//! - Starting a conversation with an area-event NPC sends
+//!   `QuestProgress::TalkedTo` with its kind.
+//! - An enemy dying sends `QuestProgress::Purified` when purification
+//!   restored it (see [`crate::purify::is_restored`]), `Defeated` otherwise.
+
+use bevy::prelude::*;
+use combat::DeathOccurred;
+use dialog::events::{DialogueStarted, QuestProgress};
+use dialog::quest::AreaVisit;
+use models::decoration::Biome;
+
+use crate::area::AreaEvent;
+use crate::enemies::Enemy;
+use crate::npcs::EventNpc;
+use crate::purify::is_restored;
+use crate::world::{AreaChanged, WorldMap};
+
+/// System: reports the area the player just crossed into.
+pub fn report_area_entered(
+    mut changes: MessageReader<AreaChanged>,
+    world: Res<WorldMap>,
+    mut progress: MessageWriter<QuestProgress>,
+) {
+    if changes.read().last().is_none() {
+        return;
+    }
+    let area = world.current_area();
+    let npc = match area.event {
+        AreaEvent::NpcEncounter(kind) => Some(kind),
+        AreaEvent::None | AreaEvent::Enemy { .. } => None,
+    };
+    progress.write(QuestProgress::EnteredArea(AreaVisit {
+        biome: Biome::from_alignment(area.alignment),
+        npc,
+        exit: world.current == world.exit_area,
+    }));
+}
+
+/// System: reports which NPC a conversation just started with.
+pub fn report_talked_to(
+    mut started: MessageReader<DialogueStarted>,
+    npcs: Query<&EventNpc>,
+    mut progress: MessageWriter<QuestProgress>,
+) {
+    for npc in npcs.iter_many(started.read().map(|started| started.npc)) {
+        progress.write(QuestProgress::TalkedTo(npc.0));
+    }
+}
+
+/// Observer: reports a dying enemy as purified or defeated.
+pub fn report_enemy_death(
+    on: On<DeathOccurred>,
+    enemies: Query<&Enemy>,
+    mut progress: MessageWriter<QuestProgress>,
+) {
+    let Ok(enemy) = enemies.get(on.entity) else {
+        return;
+    };
+    progress.write(if is_restored(enemy.kind, &on.context) {
+        QuestProgress::Purified(enemy.kind)
+    } else {
+        QuestProgress::Defeated(enemy.kind)
+    });
+}
@@ -241,8 +241,17 @@ fn ensure_area_spawned(
         shadow_assets,
         area,
         area_pos,
+        world,
+    );
+    crate::enemies::spawn_area_enemies(
+        commands,
+        asset_server,
+        atlas_layouts,
+        shadow_assets,
+        area,
+        area_pos,
+        world,
     );
-    crate::enemies::spawn_area_enemies(commands, asset_server, atlas_layouts, area, area_pos);
     spawn_portal_for_area(commands, asset_server, atlas_layouts, world, area_pos);
     spawned.0.insert(area_pos);
 }
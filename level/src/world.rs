@@ -2,6 +2,7 @@ use std::collections::{BTreeSet, HashMap, HashSet};
 
 use bevy::math::IVec2;
 use bevy::prelude::*;
+use models::multiverse::PurifiedEnemies;
 
 use crate::area::{
     Area, AreaAlignment, AreaEvent, Direction, EnemyKind, NpcKind, ALL_NPCS, MAP_HEIGHT, MAP_WIDTH,
@@ -122,6 +123,9 @@ pub struct WorldMap {
     areas: HashMap<IVec2, Area>,
     /// Player's current area within this map.
     pub current: IVec2,
+    /// The dead-end area the player entered this map through. Doubles as
+    /// the respawn checkpoint.
+    start: IVec2,
     /// RNG seed for this map.
     seed: u64,
     /// Uniform biome alignment -- every area in this map shares it.
@@ -136,6 +140,10 @@ pub struct WorldMap {
     visited: HashSet<IVec2>,
     /// Areas visible on the minimap (visited + their exit neighbors).
     revealed: HashSet<IVec2>,
+    /// Enemy slots (`Enemy::slot`) per area that the player has purified.
+    /// Those enemies don't respawn when the area is spawned again. Mirrored
+    /// in the saved `PurifiedEnemies`, which restores it on reload.
+    purified: HashMap<IVec2, HashSet<u8>>,
     /// The dead-end area containing the level exit.
     pub exit_area: IVec2,
     /// Water tiles (ponds, hot springs, lakes, rivers, ocean) generated after terrain.
@@ -211,6 +219,7 @@ impl WorldMap {
             id,
             areas: HashMap::new(),
             current: IVec2::ZERO,
+            start: IVec2::ZERO,
             seed,
             alignment,
             area_count_target: target,
@@ -218,6 +227,7 @@ impl WorldMap {
             npc_count: 0,
             visited: HashSet::new(),
             revealed: HashSet::new(),
+            purified: HashMap::new(),
             exit_area: IVec2::ZERO,
             water: WaterMap::default(),
             has_ocean,
@@ -273,6 +283,7 @@ impl WorldMap {
         // begins on the map's edge and explores inward.
         let start = pick_start_dead_end(&dead_ends, seed);
         map.current = start;
+        map.start = start;
         map.visited.insert(start);
         map.revealed.insert(start);
         map.reveal_exits(start);
@@ -354,6 +365,19 @@ impl WorldMap {
         self.seed
     }
 
+    /// Stable `Talker::id` for `npc` standing in `area` on this map. Keyed
+    /// by seed rather than `MapId` so a new game never inherits an old run's
+    /// greetings, and by area so two NPCs of one kind keep separate state.
+    pub fn talker_id(&self, area: IVec2, npc: &str) -> String {
+        format!("{:016x}/{},{}/{npc}", self.seed, area.x, area.y)
+    }
+
+    /// Whether `id` came from [`Self::talker_id`] on this map.
+    pub fn owns_talker_id(&self, id: &str) -> bool {
+        id.strip_prefix(&format!("{:016x}", self.seed))
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+
     /// Look up terrain at `(local_x, local_y)` relative to `area_pos`.
     ///
     /// Coordinates outside the 32x18 area bounds wrap into the adjacent area.
@@ -408,6 +432,42 @@ impl WorldMap {
         self.reveal_exits(new_pos);
     }
 
+    /// The area the player entered this map through -- the start area of a
+    /// fresh map, or the arrival point of a portal crossing.
+    pub fn start_area(&self) -> IVec2 {
+        self.start
+    }
+
+    /// Put the player back in the start area, keeping everything they've
+    /// visited and revealed.
+    pub fn return_to_start(&mut self) {
+        self.current = self.start;
+    }
+
+    /// Remember that enemy `slot` in `area` was purified.
+    pub fn mark_purified(&mut self, area: IVec2, slot: u8) {
+        self.purified.entry(area).or_default().insert(slot);
+    }
+
+    /// Re-mark the slots a save recorded as purified on this map.
+    pub fn restore_purified(&mut self, saved: &PurifiedEnemies) {
+        for &([x, y], slot) in &saved.0 {
+            self.mark_purified(IVec2::new(x, y), slot);
+        }
+    }
+
+    pub fn is_purified(&self, area: IVec2, slot: u8) -> bool {
+        self.purified.get(&area).is_some_and(|s| s.contains(&slot))
+    }
+
+    /// True once every enemy the area's event places has been purified.
+    pub fn is_cleansed(&self, area: IVec2) -> bool {
+        let Some(AreaEvent::Enemy { count, .. }) = self.get_area(area).map(|a| a.event) else {
+            return false;
+        };
+        (0..count).all(|slot| self.is_purified(area, slot))
+    }
+
     /// Returns the uniform biome alignment for any position in this map.
     /// Areas no longer vary their alignment within a map.
     pub fn alignment_at(&self, _pos: IVec2) -> AreaAlignment {
@@ -1,21 +1,53 @@
 use bevy::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 const MAX_ALIGNMENT: u8 = 10;
 const GREENWOODS_START: u8 = 5;
 
 /// Identifies one of the three faction alignments.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AlignmentFaction {
     Greenwoods,
     Darkwoods,
     Cities,
 }
 
+impl AlignmentFaction {
+    pub const ALL: [Self; 3] = [Self::Greenwoods, Self::Darkwoods, Self::Cities];
+
+    /// Stable id, used as a locale `select` value. Never rename one.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Greenwoods => "Greenwoods",
+            Self::Darkwoods => "Darkwoods",
+            Self::Cities => "Cities",
+        }
+    }
+
+    /// The two factions other than this one.
+    pub fn opposing(self) -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter().filter(move |f| *f != self)
+    }
+}
+
+/// A change to the player's standing, e.g. from a dialogue choice.
+///
+/// `amount` may be negative. `decay` is taken from each opposing faction
+/// as well, so siding with one faction costs standing with the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlignmentGrant {
+    pub faction: AlignmentFaction,
+    pub amount: i8,
+    #[serde(default)]
+    pub decay: u8,
+}
+
 /// Tracks the player's standing with each faction (0–10).
 ///
-/// Greenwoods starts at 5; Darkwoods and Cities start at 0.
-#[derive(Resource, Debug)]
+/// Greenwoods starts at 5; Darkwoods and Cities start at 0. Persisted by
+/// `save::SavePlugin`; missing fields load as their defaults.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PlayerAlignment {
     pub greenwoods: u8,
     pub darkwoods: u8,
@@ -35,12 +67,26 @@ impl Default for PlayerAlignment {
 impl PlayerAlignment {
     /// Grant +1 alignment to `faction`, capped at 10.
     pub fn grant(&mut self, faction: AlignmentFaction) {
+        self.adjust(faction, 1);
+    }
+
+    /// Apply `grant`: its `amount` to its faction and `-decay` to each
+    /// opposing one. Scores stay within 0-10.
+    pub fn apply(&mut self, grant: &AlignmentGrant) {
+        self.adjust(grant.faction, i16::from(grant.amount));
+        for faction in grant.faction.opposing() {
+            self.adjust(faction, -i16::from(grant.decay));
+        }
+    }
+
+    fn adjust(&mut self, faction: AlignmentFaction, delta: i16) {
         let val = match faction {
             AlignmentFaction::Greenwoods => &mut self.greenwoods,
             AlignmentFaction::Darkwoods => &mut self.darkwoods,
             AlignmentFaction::Cities => &mut self.cities,
         };
-        *val = val.saturating_add(1).min(MAX_ALIGNMENT);
+        let next = (i16::from(*val) + delta).clamp(0, i16::from(MAX_ALIGNMENT));
+        *val = u8::try_from(next).unwrap_or(*val);
     }
 
     /// Current alignment score for `faction`.
@@ -52,25 +98,67 @@ impl PlayerAlignment {
         }
     }
 
+    /// The faction with the highest score. Ties go to Cities, then
+    /// Darkwoods.
+    pub fn dominant(&self) -> AlignmentFaction {
+        if self.cities >= self.greenwoods && self.cities >= self.darkwoods {
+            AlignmentFaction::Cities
+        } else if self.darkwoods >= self.greenwoods {
+            AlignmentFaction::Darkwoods
+        } else {
+            AlignmentFaction::Greenwoods
+        }
+    }
+
     /// Convert to a 1-100 area alignment scale based on the dominant faction.
     /// 1 = city, 50 = greenwood, 100 = darkwood.
     pub fn dominant_area_alignment(&self) -> u8 {
-        if self.cities >= self.greenwoods && self.cities >= self.darkwoods {
-            // City dominant: scale 0-10 → 1-25
-            let t = f32::from(self.cities) / f32::from(MAX_ALIGNMENT);
-            #[allow(clippy::as_conversions)]
-            return 1 + (t * 24.0) as u8;
-        }
-        if self.darkwoods >= self.greenwoods {
-            // Darkwood dominant: scale 0-10 → 76-100
-            let t = f32::from(self.darkwoods) / f32::from(MAX_ALIGNMENT);
-            #[allow(clippy::as_conversions)]
-            return 76 + (t * 24.0) as u8;
-        }
-        // Greenwood dominant: scale 0-10 → 26-75
-        let t = f32::from(self.greenwoods) / f32::from(MAX_ALIGNMENT);
+        // Each faction's 0-10 score maps onto its band of the scale:
+        // city 1-25, greenwood 26-75, darkwood 76-100.
+        let (score, base, span) = match self.dominant() {
+            AlignmentFaction::Cities => (self.cities, 1, 24.0),
+            AlignmentFaction::Greenwoods => (self.greenwoods, 26, 49.0),
+            AlignmentFaction::Darkwoods => (self.darkwoods, 76, 24.0),
+        };
+        let t = f32::from(score) / f32::from(MAX_ALIGNMENT);
         #[allow(clippy::as_conversions)]
-        let val = 26 + (t * 49.0) as u8;
+        let val = base + (t * span) as u8;
         val
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grants_clamp_and_decay_opposing_factions() {
+        let mut alignment = PlayerAlignment::default();
+        alignment.apply(&AlignmentGrant {
+            faction: AlignmentFaction::Darkwoods,
+            amount: 3,
+            decay: 2,
+        });
+        assert_eq!(
+            (alignment.greenwoods, alignment.darkwoods, alignment.cities),
+            (3, 3, 0)
+        );
+        assert_eq!(alignment.dominant(), AlignmentFaction::Darkwoods);
+
+        alignment.apply(&AlignmentGrant {
+            faction: AlignmentFaction::Cities,
+            amount: 20,
+            decay: 0,
+        });
+        alignment.apply(&AlignmentGrant {
+            faction: AlignmentFaction::Darkwoods,
+            amount: -5,
+            decay: 0,
+        });
+        assert_eq!(
+            (alignment.greenwoods, alignment.darkwoods, alignment.cities),
+            (3, 0, 10)
+        );
+        assert_eq!(alignment.dominant(), AlignmentFaction::Cities);
+    }
+}
@@ -7,6 +7,7 @@ use derive_more::Display;
 
 const MELEE_RANGE: Distance = Distance(200);
 const MELEE_COOLDOWN: Seconds = Seconds(1);
+const RANGED_COOLDOWN: Seconds = Seconds(2);
 const DEFAULT_DAMAGE: Health = Health(1);
 
 #[derive(Component, Display, Clone, Copy, Debug)]
@@ -30,6 +31,18 @@ impl Attack {
         }
     }
 
+    /// A shot that travels `range` before fizzling. Spawned as a
+    /// `combat::Projectile` rather than resolved on the spot.
+    pub fn ranged(damage: Health, range: Distance) -> Self {
+        Self {
+            damage,
+            damage_type: DamageType::Physical,
+            range,
+            cooldown: RANGED_COOLDOWN,
+            last: None,
+        }
+    }
+
     pub fn fall(damage: Health) -> Self {
         Self {
             damage,
@@ -39,6 +52,30 @@ impl Attack {
             last: None,
         }
     }
+
+    /// One tick of a damage-over-time effect (disease, poison, burning).
+    /// No range or cooldown -- the status effect's own timer paces it.
+    pub fn over_time(damage: Health, damage_type: DamageType) -> Self {
+        Self {
+            damage,
+            damage_type,
+            range: Distance(0),
+            cooldown: Seconds(0),
+            last: None,
+        }
+    }
+
+    /// True once `cooldown` seconds have elapsed since the attack was last
+    /// used. `now` is the caller's clock in seconds (`Time::elapsed_secs`).
+    pub fn is_ready(&self, now: f32) -> bool {
+        self.last
+            .is_none_or(|last| now - last >= f32::from(self.cooldown.0))
+    }
+
+    /// Stamp the attack as used at `now`, starting its cooldown.
+    pub fn mark_used(&mut self, now: f32) {
+        self.last = Some(now);
+    }
 }
 
 impl Default for Attack {
@@ -1,7 +1,14 @@
 use derive_more::Display;
 
-#[derive(Display, Clone, Copy, Debug)]
+#[derive(Display, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum DamageType {
     Physical,
     Fall,
+    /// The purple blight carried by diseased creatures.
+    Disease,
+    Poison,
+    Fire,
+    /// Druidic cleansing. Brings diseased creatures to zero without killing
+    /// them -- see `level::purify`.
+    Purify,
 }
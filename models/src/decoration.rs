@@ -1,11 +1,12 @@
 use bevy::prelude::Component;
+use serde::Deserialize;
 
 /// Marker for decoration entities (ground clutter).
 #[derive(Component, Default)]
 pub struct Decoration;
 
 /// Biome classification derived from area alignment.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 pub enum Biome {
     City,
     Greenwood,
@@ -22,6 +22,14 @@ use derive_more::{Add, AddAssign, Display, From, Mul, MulAssign, Sub, SubAssign}
 )]
 pub struct Distance(pub u16);
 
+impl Distance {
+    /// Distances are authored in hundredths of a tile (melee = 200 = two
+    /// tiles). Multiply by the tile size to get world pixels.
+    pub fn tiles(self) -> f32 {
+        f32::from(self.0) / 100.0
+    }
+}
+
 /// Clamp a float to the valid `u16` range and round.
 #[allow(clippy::as_conversions)]
 fn f64_to_u16_saturating(value: f64) -> u16 {
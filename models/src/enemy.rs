@@ -0,0 +1,15 @@
+//! Enemy kinds. Spawned and fought in the level crate, counted by quest
+//! objectives in the dialog crate.
+
+use serde::Deserialize;
+
+/// Diseased creature / slime kinds that spawn in greenwood maps as the
+/// `AreaEvent::Enemy` content. Per-tile entity spawning lives in
+/// `level::enemies`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum EnemyKind {
+    PurpleSlime,
+    DiseasedFox,
+    DiseasedDeer,
+    DiseasedBear,
+}
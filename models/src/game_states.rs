@@ -11,6 +11,10 @@ pub enum GameState {
     Dialogue,
     /// Lore browser accessed from the main menu.
     LorePage,
+    /// Quest journal, accessible from the main menu next to the lore page.
+    Journal,
+    /// Save slot list, opened from the main menu to load or start a game.
+    SlotPicker,
     /// Key remapping UI, accessible from the settings screen.
     KeybindConfig,
     /// Settings hub: audio, video, and keybind navigation.
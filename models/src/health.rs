@@ -22,3 +22,38 @@ use derive_more::{Add, AddAssign, AsRef, Display, From, Mul, MulAssign, Sub, Sub
     Default,
 )]
 pub struct Health(pub u16);
+
+/// Ceiling for an entity's `Health`. Healing is clamped to it, and the HUD
+/// uses it to tell missing petals from ones the player never had.
+#[derive(
+    Component, From, Display, AsRef, Ord, PartialOrd, Eq, PartialEq, Debug, Clone, Copy, Default,
+)]
+pub struct MaxHealth(pub u16);
+
+impl Health {
+    /// `self + amount`, clamped to `max`.
+    pub fn healed(self, amount: Health, max: MaxHealth) -> Health {
+        Health(self.0.saturating_add(amount.0).min(max.0))
+    }
+}
+
+impl From<MaxHealth> for Health {
+    fn from(max: MaxHealth) -> Self {
+        Health(max.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn healing_clamps_to_max() {
+        assert_eq!(Health(8).healed(Health(5), MaxHealth(10)), Health(10));
+        assert_eq!(Health(3).healed(Health(2), MaxHealth(10)), Health(5));
+        assert_eq!(
+            Health(u16::MAX).healed(Health(1), MaxHealth(u16::MAX)),
+            Health(u16::MAX)
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Items that can be carried. Dropped into the world as loot by
+//! `level::loot` and announced to whoever keeps the inventory through
+//! `level::loot::ItemPickedUp`.
+
+use serde::Deserialize;
+
+/// Every kind of carryable item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum ItemKind {
+    /// Sticky purple residue left by a popped slime.
+    SlimeGel,
+    /// Matted tuft from a diseased fox.
+    FoxTuft,
+    /// Shed antler from a diseased deer.
+    Antler,
+    /// Claw from a diseased bear.
+    BearClaw,
+    /// A spore of the purple blight. Rare from any diseased creature.
+    BlightSpore,
+}
+
+impl ItemKind {
+    /// Parse the snake_case id scripts use (`"bear_claw"`), i.e. the last
+    /// segment of [`ItemKind::name_key`].
+    pub fn from_id(id: &str) -> Option<Self> {
+        [
+            Self::SlimeGel,
+            Self::FoxTuft,
+            Self::Antler,
+            Self::BearClaw,
+            Self::BlightSpore,
+        ]
+        .into_iter()
+        .find(|kind| kind.name_key().strip_prefix("item.") == Some(id))
+    }
+
+    /// Locale key for the item's display name.
+    pub fn name_key(self) -> &'static str {
+        match self {
+            Self::SlimeGel => "item.slime_gel",
+            Self::FoxTuft => "item.fox_tuft",
+            Self::Antler => "item.antler",
+            Self::BearClaw => "item.bear_claw",
+            Self::BlightSpore => "item.blight_spore",
+        }
+    }
+}
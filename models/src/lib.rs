@@ -7,13 +7,16 @@ pub mod creature;
 pub mod damage_type;
 pub mod decoration;
 pub mod distance;
+pub mod enemy;
 pub mod game_states;
 pub mod grass;
 pub mod hardness;
 pub mod health;
+pub mod item;
 pub mod layer;
 pub mod multiverse;
 pub mod name;
+pub mod npc;
 pub mod npc_anim;
 pub mod palette;
 pub mod player;
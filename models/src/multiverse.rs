@@ -2,7 +2,9 @@
 //!
 //! Captures just enough state to regenerate the current map verbatim on
 //! reload (id + seed + alignment) plus the global `maps_traversed` counter
-//! used to scale enemy difficulty.
+//! used to scale enemy difficulty, and the enemies already purified there.
+
+use std::collections::BTreeSet;
 
 use bevy::prelude::Resource;
 use serde::{Deserialize, Serialize};
@@ -27,3 +29,9 @@ pub struct MultiverseSave {
     /// per-area enemy counts via `enemy_count_for_traversal`.
     pub maps_traversed: u32,
 }
+
+/// Enemy slots the player has purified on the current map, as
+/// `([area x, area y], slot)`. Restored into the regenerated map on reload
+/// so those enemies stay healed; a portal crossing starts it over.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PurifiedEnemies(pub BTreeSet<([i32; 2], u8)>);
@@ -0,0 +1,73 @@
+//! Named NPCs. Spawned by the level crate and named in quest objectives by
+//! the dialog crate, so the kind lives here where both can see it.
+
+use serde::Deserialize;
+
+/// Identifies an NPC for area events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum NpcKind {
+    Mordred,
+    Drizella,
+    Bigby,
+    Gothel,
+    Morgana,
+    Cadwallader,
+    BloodyMary,
+}
+
+impl NpcKind {
+    /// Stable id, used as the last segment of the NPC's talker id. Saved
+    /// with greeting state, so never rename one.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Mordred => "Mordred",
+            Self::Drizella => "Drizella",
+            Self::Bigby => "Bigby",
+            Self::Gothel => "Gothel",
+            Self::Morgana => "Morgana",
+            Self::Cadwallader => "Cadwallader",
+            Self::BloodyMary => "BloodyMary",
+        }
+    }
+
+    /// Alignment range this NPC will spawn in (min, max).
+    pub fn alignment_range(self) -> (u8, u8) {
+        match self {
+            // City-aligned
+            Self::Cadwallader => (1, 35),
+            Self::Bigby => (1, 35),
+            // Greenwood-aligned
+            Self::Drizella => (25, 75),
+            Self::Gothel => (25, 75),
+            // Darkwood-aligned
+            Self::Mordred => (60, 100),
+            Self::Morgana => (60, 100),
+            Self::BloodyMary => (60, 100),
+        }
+    }
+}
+
+/// All available NPC kinds, used for random selection.
+pub const ALL_NPCS: [NpcKind; 7] = [
+    NpcKind::Mordred,
+    NpcKind::Drizella,
+    NpcKind::Bigby,
+    NpcKind::Gothel,
+    NpcKind::Morgana,
+    NpcKind::Cadwallader,
+    NpcKind::BloodyMary,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_match_variant_names() {
+        // Talker ids were built from `Debug` before `as_str` existed; keep
+        // old saves' greeting state valid.
+        for kind in ALL_NPCS {
+            assert_eq!(kind.as_str(), format!("{kind:?}"));
+        }
+    }
+}
@@ -116,6 +116,31 @@ pub const FISH_SHADOW_TINT: Color = Color::srgba(0.05, 0.08, 0.15, 0.45);
 /// lifetime via `Sprite::with_alpha`.
 pub const SPLASH_TINT: Color = Color::srgba(1.0, 1.0, 1.0, 0.55);
 
+/// Wind-up tint on an enemy about to strike, so the player can read the
+/// telegraph and step out of range.
+pub const ENEMY_TELEGRAPH: Color = tw::ROSE_300;
+
+/// Faded rose petal in the HUD -- health the player has lost but can heal
+/// back.
+pub const PETAL_MISSING: Color = Color::srgba(0.3, 0.3, 0.3, 0.35);
+
+/// Tint flashed on a sprite the moment it takes a hit.
+pub const HIT_FLASH: Color = tw::RED_400;
+
+/// Glob of purple spit lobbed by slimes.
+pub const SLIME_SPIT: Color = tw::PURPLE_400;
+
+/// Tint on a purified fox / deer / bear, reusing the diseased sheet until
+/// healthy art exists.
+pub const PURIFIED_TINT: Color = tw::EMERALD_100;
+
+/// Placeholder loot pickups until item art lands -- one tint per item.
+pub const LOOT_SLIME_GEL: Color = tw::PURPLE_300;
+pub const LOOT_FOX_TUFT: Color = tw::ORANGE_400;
+pub const LOOT_ANTLER: Color = tw::STONE_300;
+pub const LOOT_BEAR_CLAW: Color = tw::STONE_700;
+pub const LOOT_BLIGHT_SPORE: Color = tw::FUCHSIA_500;
+
 /// Linearly interpolate between two colors in linear (non-gamma) color space.
 pub fn lerp_linear_color(a: Color, b: Color, t: f32) -> Color {
     let a = a.to_linear();
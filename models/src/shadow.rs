@@ -21,3 +21,6 @@ pub const CREATURE_SHADOW_OFFSET_Y_PX: f32 = -3.0;
 
 pub const GALEN_SHADOW_HALF_PX: Vec2 = Vec2::new(7.0, 2.5);
 pub const GALEN_SHADOW_OFFSET_Y_PX: f32 = -10.0;
+
+pub const LOOT_SHADOW_HALF_PX: Vec2 = Vec2::new(3.0, 1.0);
+pub const LOOT_SHADOW_OFFSET_Y_PX: f32 = -3.0;
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
 /// Possible weather states the world can be in.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum WeatherKind {
     Clear,
     Breezy,
@@ -32,6 +33,31 @@ const STORM_WIND_MIN: f32 = 0.8;
 const STORM_WIND_MAX: f32 = 1.0;
 
 impl WeatherKind {
+    /// Every weather state, calmest first.
+    pub const ALL: [Self; 5] = [
+        Self::Clear,
+        Self::Breezy,
+        Self::Windy,
+        Self::Rain,
+        Self::Storm,
+    ];
+
+    /// Parse the id scripts use, i.e. [`WeatherKind::as_str`] (`"Rain"`).
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == id)
+    }
+
+    /// Stable id, spelled like the variant so it matches RON content.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Clear => "Clear",
+            Self::Breezy => "Breezy",
+            Self::Windy => "Windy",
+            Self::Rain => "Rain",
+            Self::Storm => "Storm",
+        }
+    }
+
     /// Wind strength range for this weather state (min, max).
     pub fn wind_range(self) -> (f32, f32) {
         match self {
@@ -112,3 +138,17 @@ pub struct WeatherParticle {
     /// Visual variant.
     pub variant: ParticleVariant,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_round_trip_and_match_variant_names() {
+        for kind in WeatherKind::ALL {
+            assert_eq!(WeatherKind::from_id(kind.as_str()), Some(kind));
+            assert_eq!(kind.as_str(), format!("{kind:?}"));
+        }
+        assert_eq!(WeatherKind::from_id("rain"), None);
+    }
+}
@@ -2,19 +2,32 @@ use bevy::prelude::*;
 use keybinds::Keybinds;
 use std::time::Duration;
 
+use crate::attack::Swinging;
 use crate::input::{is_sprinting, read_movement_input};
 
 pub const SHEET_COLS: usize = 12; // 4 idle + 4 walk + 4 run
-pub const SHEET_ROWS: usize = 8; // S, SW, W, NW, N, NE, E, SE
+pub const SHEET_ROWS: usize = 16; // 8 facings (S, SW, W, NW, N, NE, E, SE), then 8 swing rows
 pub const FRAME_W_PX: u32 = 32;
 pub const FRAME_H_PX: u32 = 64;
 
 const IDLE_FRAMES: usize = 4;
 const WALK_FRAMES: usize = 4;
 const RUN_FRAMES: usize = 4;
+const SWING_FRAMES: u8 = 4;
 const IDLE_FPS: f32 = 3.0;
 const WALK_FPS: f32 = 8.0;
 const RUN_FPS: f32 = 12.0;
+const SWING_FPS: f32 = 16.0;
+
+/// First atlas row of the swing strip. Swing rows follow the movement rows
+/// in the same facing order, so `SWING_ROW_START + facing.row()` is the
+/// swing row for that facing.
+const SWING_ROW_START: usize = 8;
+
+/// How long one full swing strip takes to play, in seconds.
+pub fn swing_duration_s() -> f32 {
+    f32::from(SWING_FRAMES) / SWING_FPS
+}
 
 const DIRECTION_COUNT: u8 = 8;
 
@@ -74,6 +87,21 @@ impl FacingDirection {
         usize::from(u8::from(self))
     }
 
+    /// Unit vector pointing the way this facing looks.
+    pub fn to_vec2(self) -> Vec2 {
+        let diag = std::f32::consts::FRAC_1_SQRT_2;
+        match self {
+            Self::South => Vec2::NEG_Y,
+            Self::SouthWest => Vec2::new(-diag, -diag),
+            Self::West => Vec2::NEG_X,
+            Self::NorthWest => Vec2::new(-diag, diag),
+            Self::North => Vec2::Y,
+            Self::NorthEast => Vec2::new(diag, diag),
+            Self::East => Vec2::X,
+            Self::SouthEast => Vec2::new(diag, -diag),
+        }
+    }
+
     /// Map a (non-zero) velocity vector to the nearest of the eight facings.
     ///
     /// East is octant 0 in atan2 space, then we walk counter-clockwise. The
@@ -104,6 +132,9 @@ pub enum AnimationKind {
     Idle,
     Walk,
     Run,
+    /// Melee swing. Lives in its own row block rather than extra columns,
+    /// so the movement strips keep their atlas indices.
+    Swing,
 }
 
 impl AnimationKind {
@@ -112,6 +143,7 @@ impl AnimationKind {
             Self::Idle => IDLE_FPS,
             Self::Walk => WALK_FPS,
             Self::Run => RUN_FPS,
+            Self::Swing => SWING_FPS,
         }
     }
 
@@ -120,16 +152,24 @@ impl AnimationKind {
             Self::Idle => IDLE_FRAMES,
             Self::Walk => WALK_FRAMES,
             Self::Run => RUN_FRAMES,
+            Self::Swing => usize::from(SWING_FRAMES),
         }
     }
 
     fn col_start(self) -> usize {
         match self {
-            Self::Idle => 0,
+            Self::Idle | Self::Swing => 0,
             Self::Walk => 4,
             Self::Run => 8,
         }
     }
+
+    fn row_start(self) -> usize {
+        match self {
+            Self::Swing => SWING_ROW_START,
+            _ => 0,
+        }
+    }
 }
 
 /// Logical movement state of the player, separated from the render-side
@@ -156,16 +196,21 @@ impl Default for AnimationTimer {
 }
 
 /// Updates facing direction, animation kind, and movement state from input.
+/// Skipped while a swing is playing so the strip and facing stay locked.
+#[allow(clippy::type_complexity)]
 pub fn update_animation_state(
     keyboard: Res<ButtonInput<KeyCode>>,
     bindings: Res<Keybinds>,
-    mut query: Query<(
-        &mut FacingDirection,
-        &mut AnimationKind,
-        &mut MovementState,
-        &mut AnimationFrame,
-        &mut AnimationTimer,
-    )>,
+    mut query: Query<
+        (
+            &mut FacingDirection,
+            &mut AnimationKind,
+            &mut MovementState,
+            &mut AnimationFrame,
+            &mut AnimationTimer,
+        ),
+        Without<Swinging>,
+    >,
 ) {
     let Ok((mut facing, mut kind, mut movement, mut frame, mut timer)) = query.single_mut() else {
         return;
@@ -183,12 +228,7 @@ pub fn update_animation_state(
     };
 
     if new_kind != *kind {
-        *kind = new_kind;
-        timer
-            .0
-            .set_duration(Duration::from_secs_f32(1.0 / new_kind.fps()));
-        timer.0.reset();
-        frame.0 = 0;
+        restart_strip(&mut kind, &mut frame, &mut timer, new_kind);
     }
 
     if new_movement != *movement {
@@ -200,6 +240,21 @@ pub fn update_animation_state(
     }
 }
 
+/// Switch to `new_kind` and play it from its first frame at its own FPS.
+pub(crate) fn restart_strip(
+    kind: &mut AnimationKind,
+    frame: &mut AnimationFrame,
+    timer: &mut AnimationTimer,
+    new_kind: AnimationKind,
+) {
+    *kind = new_kind;
+    timer
+        .0
+        .set_duration(Duration::from_secs_f32(1.0 / new_kind.fps()));
+    timer.0.reset();
+    frame.0 = 0;
+}
+
 /// Ticks the animation timer and updates the sprite atlas index.
 pub fn advance_frame(
     time: Res<Time>,
@@ -220,7 +275,7 @@ pub fn advance_frame(
         frame.0 = (frame.0 + 1) % kind.frame_count();
     }
 
-    let index = facing.row() * SHEET_COLS + kind.col_start() + frame.0;
+    let index = (kind.row_start() + facing.row()) * SHEET_COLS + kind.col_start() + frame.0;
     if let Some(atlas) = sprite.texture_atlas.as_mut() {
         atlas.index = index;
     }
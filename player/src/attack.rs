@@ -0,0 +1,177 @@
+//! Player melee attack. Pressing the bound `Action::Attack` key starts a
+//! swing (sprite strip + short input lock on facing), then every living
+//! `Enemy` inside the player's `Attack::range` and in front of their facing
+//! receives a `DamageEvent`. The swing respects `Attack::cooldown` via the
+//! attack's `last` timestamp.
+//!
+//! `Action::Purify` is the same swing carrying `DamageType::Purify`: it
+//! still wears enemies down, but the blow that finishes a diseased animal
+//! heals it instead (see `level::purify`). Both share one cooldown.
+
+use bevy::prelude::*;
+use combat::{DamageContext, DamageEvent, HitStun};
+use keybinds::{Action, Keybinds};
+use level::enemies::Enemy;
+use level::plugin::TILE_SIZE_PX;
+use models::attack::Attack;
+use models::damage_type::DamageType;
+use models::health::Health;
+
+use crate::animation::{
+    restart_strip, swing_duration_s, AnimationFrame, AnimationKind, AnimationTimer, FacingDirection,
+};
+use crate::spawning::Player;
+
+/// Damage dealt by one swing of the player's staff.
+pub const PLAYER_MELEE_DAMAGE: Health = Health(1);
+
+/// Cosine of the half-angle of the hit arc. 0.5 = 60 degrees either side
+/// of the facing direction (a 120 degree wedge).
+const HIT_ARC_COS: f32 = 0.5;
+
+/// Targets closer than this are hit regardless of facing, so an enemy
+/// standing on top of the player can't dodge the arc test.
+const POINT_BLANK_PX: f32 = 4.0;
+
+/// Present on the player while the swing strip is playing.
+#[derive(Component)]
+pub struct Swinging(pub Timer);
+
+/// True when `target` lies inside the wedge `facing` sweeps out to `reach`.
+fn in_hit_arc(origin: Vec2, facing: Vec2, target: Vec2, reach: f32) -> bool {
+    let offset = target - origin;
+    let dist = offset.length();
+    if dist > reach {
+        return false;
+    }
+    dist <= POINT_BLANK_PX || offset.normalize().dot(facing) >= HIT_ARC_COS
+}
+
+/// Start a swing on `Action::Attack` / `Action::Purify` and damage every
+/// enemy in the arc.
+#[allow(clippy::type_complexity)]
+pub fn start_attack(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<Keybinds>,
+    time: Res<Time>,
+    mut commands: Commands,
+    mut player_q: Query<
+        (
+            Entity,
+            &Transform,
+            &FacingDirection,
+            &mut Attack,
+            &mut AnimationKind,
+            &mut AnimationFrame,
+            &mut AnimationTimer,
+        ),
+        (With<Player>, Without<Swinging>, Without<HitStun>),
+    >,
+    enemies: Query<(Entity, &Transform), (With<Enemy>, With<Health>, Without<Player>)>,
+    mut damage: MessageWriter<DamageEvent>,
+) {
+    let purify = keyboard.just_pressed(bindings.key(Action::Purify));
+    if !purify && !keyboard.just_pressed(bindings.key(Action::Attack)) {
+        return;
+    }
+    let Ok((player, tf, facing, mut attack, mut kind, mut frame, mut timer)) =
+        player_q.single_mut()
+    else {
+        return;
+    };
+    let now = time.elapsed_secs();
+    if !attack.is_ready(now) {
+        return;
+    }
+    attack.mark_used(now);
+
+    restart_strip(&mut kind, &mut frame, &mut timer, AnimationKind::Swing);
+    commands.entity(player).insert(Swinging(Timer::from_seconds(
+        swing_duration_s(),
+        TimerMode::Once,
+    )));
+
+    let origin = tf.translation.truncate();
+    let dir = facing.to_vec2();
+    let reach = attack.range.tiles() * f32::from(TILE_SIZE_PX);
+    let (hit, context) = if purify {
+        (
+            Attack {
+                damage_type: DamageType::Purify,
+                ..*attack
+            },
+            DamageContext::Purify,
+        )
+    } else {
+        (*attack, DamageContext::DEFAULT_PHYSICAL)
+    };
+    for (enemy, enemy_tf) in &enemies {
+        if in_hit_arc(origin, dir, enemy_tf.translation.truncate(), reach) {
+            damage.write(DamageEvent::with_context(enemy, hit, player, context));
+        }
+    }
+}
+
+/// Tick the swing timer; once the strip has played out, drop back to idle
+/// and hand animation control back to `update_animation_state`.
+pub fn finish_swing(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut player_q: Query<
+        (
+            Entity,
+            &mut Swinging,
+            &mut AnimationKind,
+            &mut AnimationFrame,
+            &mut AnimationTimer,
+        ),
+        With<Player>,
+    >,
+) {
+    let Ok((player, mut swing, mut kind, mut frame, mut timer)) = player_q.single_mut() else {
+        return;
+    };
+    swing.0.tick(time.delta());
+    if !swing.0.is_finished() {
+        return;
+    }
+    restart_strip(&mut kind, &mut frame, &mut timer, AnimationKind::Idle);
+    commands.entity(player).remove::<Swinging>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REACH: f32 = 32.0;
+
+    #[test]
+    fn misses_targets_out_of_reach() {
+        assert!(in_hit_arc(Vec2::ZERO, Vec2::X, Vec2::X * REACH, REACH));
+        assert!(!in_hit_arc(
+            Vec2::ZERO,
+            Vec2::X,
+            Vec2::X * (REACH + 0.5),
+            REACH
+        ));
+    }
+
+    #[test]
+    fn hits_up_to_the_arc_edge() {
+        let inside = Vec2::from_angle(HIT_ARC_COS.acos() - 0.05);
+        let outside = Vec2::from_angle(HIT_ARC_COS.acos() + 0.05);
+        assert!(in_hit_arc(Vec2::ZERO, Vec2::X, inside * REACH, REACH));
+        assert!(!in_hit_arc(Vec2::ZERO, Vec2::X, outside * REACH, REACH));
+        assert!(!in_hit_arc(Vec2::ZERO, Vec2::X, Vec2::NEG_X * 10.0, REACH));
+    }
+
+    #[test]
+    fn point_blank_ignores_facing() {
+        let origin = Vec2::new(100.0, -40.0);
+        let behind = origin + Vec2::NEG_X * POINT_BLANK_PX;
+        assert!(in_hit_arc(origin, Vec2::X, behind, REACH));
+        assert!(in_hit_arc(origin, Vec2::X, origin, REACH));
+        let just_past = origin + Vec2::NEG_X * (POINT_BLANK_PX + 0.5);
+        assert!(!in_hit_arc(origin, Vec2::X, just_past, REACH));
+    }
+}
@@ -0,0 +1,21 @@
+//! Player death. `combat`'s default death observer leaves the player in
+//! place; this one ends the run by moving to `GameState::GameOver`, whose
+//! `OnExit(Playing)` teardown despawns the body with the rest of the world.
+//! See `level::checkpoint` for the way back.
+
+use bevy::prelude::*;
+use combat::DeathOccurred;
+use models::game_states::GameState;
+
+use crate::spawning::Player;
+
+/// Observer: the player hit zero health -- show the game-over screen.
+pub fn on_player_death(
+    on: On<DeathOccurred>,
+    players: Query<(), With<Player>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if players.contains(on.entity) {
+        next_state.set(GameState::GameOver);
+    }
+}
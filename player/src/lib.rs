@@ -1,9 +1,12 @@
 mod animation;
+mod attack;
 mod collision;
+mod death;
 mod exit_check;
 mod hop;
 mod input;
 mod movement;
+mod pickup;
 pub mod plugin;
 mod portal_trigger;
 mod rustle;
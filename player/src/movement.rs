@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use combat::{HitStun, StatusEffects};
 use keybinds::Keybinds;
 use level::area::{Direction, MAP_HEIGHT, MAP_WIDTH};
 use level::plugin::TILE_SIZE_PX;
@@ -29,15 +30,19 @@ const MAP_W_PX: f32 = MAP_WIDTH as f32 * TILE_SIZE_PX as f32;
 #[allow(clippy::as_conversions)]
 const MAP_H_PX: f32 = MAP_HEIGHT as f32 * TILE_SIZE_PX as f32;
 
+#[allow(clippy::type_complexity)]
 pub fn move_player(
     keyboard: Res<ButtonInput<KeyCode>>,
     bindings: Res<Keybinds>,
     time: Res<Time>,
     world: Res<WorldMap>,
     water_state: Res<PlayerWaterState>,
-    mut query: Query<(&MovementState, &mut Transform), With<Player>>,
+    mut query: Query<
+        (&MovementState, &mut Transform, Option<&StatusEffects>),
+        (With<Player>, Without<HitStun>),
+    >,
 ) {
-    let Ok((movement_state, mut transform)) = query.single_mut() else {
+    let Ok((movement_state, mut transform, status)) = query.single_mut() else {
         return;
     };
 
@@ -57,6 +62,9 @@ pub fn move_player(
     if water_state.on_shallow {
         mult *= SHALLOW_SPEED_MULT;
     }
+    if let Some(status) = status {
+        mult *= status.speed_mult();
+    }
     let delta =
         direction * f32::from(speed.0) * f32::from(TILE_SIZE_PX) * mult * time.delta_secs();
     transform.translation += delta.extend(0.0);
@@ -0,0 +1,35 @@
+//! Collect loot by walking over it. Each pickup the player touches is
+//! despawned and announced with a `level::loot::ItemPickedUp` message.
+
+use bevy::prelude::*;
+use level::loot::{ItemPickedUp, Pickup};
+use models::shadow::PLAYER_SHADOW_OFFSET_Y_PX;
+
+use crate::spawning::Player;
+
+/// Pickups within this distance of the player's feet (where their drop
+/// shadow sits) are collected (pixels).
+const PICKUP_RADIUS_PX: f32 = 10.0;
+
+pub fn collect_pickups(
+    mut commands: Commands,
+    player: Query<(Entity, &Transform), With<Player>>,
+    pickups: Query<(Entity, &Pickup, &Transform), Without<Player>>,
+    mut picked: MessageWriter<ItemPickedUp>,
+) {
+    let Ok((collector, tf)) = player.single() else {
+        return;
+    };
+    let feet = tf.translation.truncate() + Vec2::new(0.0, PLAYER_SHADOW_OFFSET_Y_PX);
+    for (entity, pickup, pickup_tf) in &pickups {
+        if feet.distance(pickup_tf.translation.truncate()) > PICKUP_RADIUS_PX {
+            continue;
+        }
+        picked.write(ItemPickedUp {
+            collector,
+            item: pickup.item,
+            count: pickup.count,
+        });
+        commands.entity(entity).despawn();
+    }
+}
@@ -2,15 +2,19 @@ use bevy::prelude::*;
 use models::game_states::{should_despawn_world, GameState};
 
 use crate::animation;
+use crate::attack;
 use crate::collision;
+use crate::death;
 use crate::exit_check;
 use crate::hop;
 use crate::movement;
+use crate::pickup;
 use crate::portal_trigger;
 use crate::rustle;
 use crate::spawning;
 use crate::water_state::{
-    spawn_splashes, tick_splashes, update_player_water_state, PlayerWaterState, SplashTimer,
+    soak_in_hot_spring, spawn_splashes, tick_splashes, update_player_water_state, PlayerWaterState,
+    SplashTimer,
 };
 use crate::y_sort;
 
@@ -27,7 +31,10 @@ impl Plugin for PlayerPlugin {
                 Update,
                 (
                     update_player_water_state,
+                    soak_in_hot_spring,
+                    attack::finish_swing,
                     animation::update_animation_state,
+                    attack::start_attack,
                     animation::advance_frame,
                     movement::move_player,
                     collision::resolve_scenery_collisions,
@@ -39,6 +46,7 @@ impl Plugin for PlayerPlugin {
                     spawn_splashes,
                     tick_splashes,
                     portal_trigger::detect_portal_overlap,
+                    pickup::collect_pickups,
                 )
                     .chain()
                     .run_if(in_state(GameState::Playing)),
@@ -46,6 +54,7 @@ impl Plugin for PlayerPlugin {
             .add_systems(
                 OnExit(GameState::Playing),
                 spawning::despawn.run_if(should_despawn_world),
-            );
+            )
+            .add_observer(death::on_player_death);
     }
 }
@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use combat::{HitResponse, Hurtbox, Regeneration, Team};
 use level::plugin::tile_size;
 use level::shadows::{spawn_drop_shadow, DropShadowAssets};
 use level::spawning::area_world_offset;
 use level::world::WorldMap;
+use models::attack::Attack;
 use models::camera_follow::CameraFollow;
-use models::health::Health;
+use models::health::{Health, MaxHealth};
 use models::layer::Layer;
 use models::shadow::{PLAYER_SHADOW_HALF_PX, PLAYER_SHADOW_OFFSET_Y_PX};
 use models::speed::Speed;
@@ -14,12 +16,28 @@ use crate::animation::{
     AnimationFrame, AnimationKind, AnimationTimer, FacingDirection, MovementState, FRAME_H_PX,
     FRAME_W_PX, SHEET_COLS, SHEET_ROWS,
 };
+use crate::attack::PLAYER_MELEE_DAMAGE;
 use crate::hop::HopBob;
 
 const PLAYER_WIDTH: Tile = Tile(1);
 const PLAYER_HEIGHT: Tile = Tile(2);
 pub const PLAYER_SPEED: Speed = Speed(6); // run speed: 6 tiles/s; walk is 2 tiles/s (see movement.rs)
-pub const PLAYER_MAX_HEALTH: Health = Health(10);
+pub const PLAYER_MAX_HEALTH: MaxHealth = MaxHealth(10);
+/// Out of combat the player slowly knits back together: one petal every
+/// eight seconds once they've gone twelve without a hit.
+const PLAYER_REGEN_AMOUNT: Health = Health(1);
+const PLAYER_REGEN_INTERVAL_S: f32 = 8.0;
+const PLAYER_REGEN_DELAY_S: f32 = 12.0;
+/// Half-size of the player's projectile hurtbox (body, not the full
+/// 1x2-tile sprite).
+const PLAYER_HURTBOX_HALF_PX: Vec2 = Vec2::new(6.0, 12.0);
+/// A full second of i-frames keeps contact damage from chaining through
+/// all ten petals; the short stun sells the hit without feeling sticky.
+const PLAYER_HIT_RESPONSE: HitResponse = HitResponse {
+    invulnerable_secs: 1.0,
+    knockback_px: 20.0,
+    stun_secs: 0.2,
+};
 
 pub use models::player::Player;
 
@@ -50,13 +68,29 @@ pub fn spawn(
             Player,
             CameraFollow,
             PLAYER_SPEED,
-            PLAYER_MAX_HEALTH,
-            FacingDirection::default(),
-            AnimationKind::default(),
-            MovementState::default(),
-            AnimationFrame::default(),
-            AnimationTimer::default(),
-            HopBob::default(),
+            (
+                Health::from(PLAYER_MAX_HEALTH),
+                PLAYER_MAX_HEALTH,
+                Regeneration::new(
+                    PLAYER_REGEN_AMOUNT,
+                    PLAYER_REGEN_INTERVAL_S,
+                    PLAYER_REGEN_DELAY_S,
+                ),
+                Attack::melee(PLAYER_MELEE_DAMAGE),
+                Hurtbox {
+                    half_extents: PLAYER_HURTBOX_HALF_PX,
+                    team: Team::Player,
+                },
+                PLAYER_HIT_RESPONSE,
+            ),
+            (
+                FacingDirection::default(),
+                AnimationKind::default(),
+                MovementState::default(),
+                AnimationFrame::default(),
+                AnimationTimer::default(),
+                HopBob::default(),
+            ),
             Sprite {
                 image: asset_server.load("sprites/player/briar_sheet.webp"),
                 texture_atlas: Some(TextureAtlas {
@@ -2,17 +2,20 @@
 //!   - 50% movement-speed multiplier (consumed via [`shallow_speed_mult`]).
 //!   - Sprite tint to a dark "fish-shadow" silhouette while submerged.
 //!   - Periodic splash ripple sprites while moving through shallow water.
+//!   - Heal-over-time while soaking in a hot spring.
 //!
 //! Deep water blocks the player (via colliders in `level::water::shore`); pier
 //! tiles are detected separately and walkable at full speed.
 
 use bevy::math::{IVec2, UVec2};
 use bevy::prelude::*;
+use combat::{ApplyStatus, StatusEffect};
 use level::area::{MAP_HEIGHT, MAP_WIDTH};
 use level::plugin::TILE_SIZE_PX;
 use level::spawning::area_world_offset;
-use level::water::{WaterDepth, WaterMap};
+use level::water::{WaterDepth, WaterKind, WaterMap};
 use level::world::WorldMap;
+use models::health::Health;
 use models::layer::Layer;
 use models::palette;
 
@@ -28,10 +31,19 @@ const SPLASH_LIFETIME_S: f32 = 0.4;
 const SPLASH_SIZE_PX: f32 = 14.0;
 const SPLASH_SPRITE: &str = "sprites/effects/splash.webp";
 
-/// Whether the player's center tile is shallow water.
+/// Health restored per hot-spring tick.
+const HOT_SPRING_HEAL: Health = Health(1);
+/// Seconds between hot-spring heals.
+const HOT_SPRING_TICK_S: f32 = 1.5;
+/// How long the warmth lingers after stepping out (seconds).
+const HOT_SPRING_LINGER_S: f32 = 1.0;
+
+/// Whether the player's center tile is shallow water, and whether that
+/// water is a hot spring.
 #[derive(Resource, Default, Debug, Clone, Copy)]
 pub struct PlayerWaterState {
     pub on_shallow: bool,
+    pub in_hot_spring: bool,
 }
 
 #[derive(Component)]
@@ -49,17 +61,45 @@ pub fn update_player_water_state(
 ) {
     let Ok(transform) = player.single() else {
         state.on_shallow = false;
+        state.in_hot_spring = false;
         return;
     };
     let pos = transform.translation.truncate();
-    state.on_shallow = match locate_player_tile(pos, &world) {
-        Some((area, local)) => {
-            // Pier overrides any underlying water -- treat as solid ground.
-            !world.water.has_pier(area, local)
-                && matches!(world.water.depth_at(area, local), Some(WaterDepth::Shallow))
-        }
-        None => false,
+    let (on_shallow, in_hot_spring) = match locate_player_tile(pos, &world) {
+        // Pier overrides any underlying water -- treat as solid ground.
+        Some((area, local)) if !world.water.has_pier(area, local) => (
+            matches!(world.water.depth_at(area, local), Some(WaterDepth::Shallow)),
+            world.water.get(area, local) == Some(WaterKind::HotSpring),
+        ),
+        _ => (false, false),
+    };
+    state.on_shallow = on_shallow;
+    state.in_hot_spring = in_hot_spring;
+}
+
+/// Keep a heal-over-time effect topped up while the player soaks in a hot
+/// spring. Reapplying refreshes the duration without restarting the tick,
+/// so the heal lands on a steady cadence and fades shortly after leaving.
+pub fn soak_in_hot_spring(
+    state: Res<PlayerWaterState>,
+    player: Query<Entity, With<Player>>,
+    mut status: MessageWriter<ApplyStatus>,
+) {
+    if !state.in_hot_spring {
+        return;
+    }
+    let Ok(entity) = player.single() else {
+        return;
     };
+    status.write(ApplyStatus {
+        target: entity,
+        effect: StatusEffect::heal_over_time(
+            entity,
+            HOT_SPRING_HEAL,
+            HOT_SPRING_TICK_S,
+            HOT_SPRING_LINGER_S,
+        ),
+    });
 }
 
 /// Map a world-space position to its `(area, local)` tile coordinates.
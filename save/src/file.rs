@@ -3,24 +3,199 @@
 //! Each [`Persistable`](crate::Persistable) resource owns one slot keyed by
 //! its `KEY` constant. Slot values are stored as `serde_json::Value` so the
 //! envelope itself never depends on the concrete resource types.
+//!
+//! Every save slot is its own envelope carrying a [`SlotMeta`] header; the
+//! [`GlobalSaveFile`] shared between them has none.
+//!
+//! On disk an envelope is [sealed](SaveFile::seal): wrapped together with a
+//! checksum of its exact bytes, so a truncated or bit-rotted save is
+//! rejected (and a backup used instead) rather than half-loaded.
 
 use std::collections::HashMap;
 
-use bevy::prelude::Resource;
+use bevy::prelude::{Deref, DerefMut, Resource};
+use models::alignment::AlignmentFaction;
 use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
 
 /// Current on-disk schema version. Bump when the envelope format itself
 /// changes (slot ownership, key conventions, etc.). Slot-internal migrations
 /// are the responsibility of each `Persistable`'s [`Migrator`](crate::Migrator).
-pub const SAVE_VERSION: u32 = 2;
+///
+/// Version 3 split the single envelope into a global one plus one per save
+/// slot; a version 2 envelope is adopted as the first slot on load.
+pub const SAVE_VERSION: u32 = 3;
 
 /// Top-level save envelope. Stored as a single JSON document.
-#[derive(Resource, Debug, Default, Serialize, Deserialize)]
+///
+/// As a resource, this is the active save slot's envelope.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SaveFile {
     /// Schema version of this envelope.
     #[serde(default)]
     pub version: u32,
+    /// Slot header shown by the slot picker. `None` for the global envelope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<SlotMeta>,
     /// Per-resource slots keyed by [`Persistable::KEY`](crate::Persistable::KEY).
     #[serde(default)]
     pub slots: HashMap<String, serde_json::Value>,
+    /// [`Persistable::VERSION`](crate::Persistable::VERSION) each slot value
+    /// was written at, keyed like `slots`. Missing entries mean version 0.
+    #[serde(default)]
+    pub versions: HashMap<String, u32>,
+    /// Values that failed to migrate or decode, keyed `<KEY>.v<version>`
+    /// (with a `.2`, `.3`, ... suffix for repeat failures at that version).
+    /// Kept so a bad release can't destroy progress; nothing reads them
+    /// back automatically.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub quarantine: HashMap<String, serde_json::Value>,
+}
+
+impl SaveFile {
+    /// An empty envelope for a slot started now.
+    pub fn new_slot() -> Self {
+        let now = unix_now();
+        Self {
+            version: SAVE_VERSION,
+            meta: Some(SlotMeta {
+                created: now,
+                updated: now,
+                ..SlotMeta::default()
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Serializes the envelope wrapped with a checksum of its bytes, as it
+    /// is stored.
+    pub fn seal(&self) -> serde_json::Result<String> {
+        let body = serde_json::to_string(self)?;
+        Ok(format!(
+            "{SEAL_PREFIX}{:016x}\",\"envelope\":{body}}}",
+            fnv1a(body.as_bytes())
+        ))
+    }
+
+    /// Parses a stored envelope, rejecting it if its checksum doesn't match.
+    /// Envelopes written before sealing are accepted unchecked.
+    pub fn unseal(raw: &str) -> Result<Self, String> {
+        if !raw.starts_with(SEAL_PREFIX) {
+            return serde_json::from_str(raw).map_err(|e| e.to_string());
+        }
+        let sealed: Sealed = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+        let body = sealed.envelope.get();
+        let actual = format!("{:016x}", fnv1a(body.as_bytes()));
+        if actual != sealed.checksum {
+            return Err(format!(
+                "checksum mismatch (stored {}, computed {actual})",
+                sealed.checksum
+            ));
+        }
+        serde_json::from_str(body).map_err(|e| e.to_string())
+    }
+}
+
+/// How every sealed envelope begins; anything else predates sealing.
+const SEAL_PREFIX: &str = "{\"checksum\":\"";
+
+/// A stored envelope: its JSON kept verbatim so the checksum is taken over
+/// the bytes that were written, not a re-serialization.
+#[derive(Deserialize)]
+struct Sealed<'a> {
+    checksum: String,
+    #[serde(borrow)]
+    envelope: &'a RawValue,
+}
+
+/// 64-bit FNV-1a. Guards against truncation and corruption, not tampering.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The envelope shared by every save slot, holding the
+/// [`SaveScope::Global`](crate::SaveScope::Global) resources.
+#[derive(Resource, Debug, Default, Deref, DerefMut)]
+pub struct GlobalSaveFile(pub SaveFile);
+
+/// Summary of a playthrough, kept in its slot's envelope so the slot picker
+/// can describe a save without decoding it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlotMeta {
+    /// Unix time, in seconds, the slot was started.
+    pub created: u64,
+    /// Unix time, in seconds, of the last write.
+    pub updated: u64,
+    /// Seconds spent in `GameState::Playing`.
+    pub playtime_secs: f64,
+    /// Biome alignment (1-100) of the map the player is on.
+    pub map_alignment: u8,
+    /// Portals crossed so far.
+    pub maps_traversed: u32,
+    /// The player's dominant faction, once known.
+    pub dominant: Option<AlignmentFaction>,
+}
+
+/// Seconds since the Unix epoch, or 0 if the clock is unavailable.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Seconds since the Unix epoch. `SystemTime` panics in the browser, so
+/// this asks JS instead.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn unix_now() -> u64 {
+    #[allow(clippy::as_conversions)]
+    let secs = (web_sys::js_sys::Date::now() / 1000.0) as u64;
+    secs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> SaveFile {
+        let mut file = SaveFile::new_slot();
+        file.slots
+            .insert("multiverse".to_owned(), serde_json::json!({ "seed": 7 }));
+        file.versions.insert("multiverse".to_owned(), 1);
+        file
+    }
+
+    #[test]
+    fn fnv1a_matches_reference_vectors() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn sealed_envelopes_round_trip() {
+        let sealed = sample().seal().unwrap_or_default();
+        assert!(sealed.starts_with(SEAL_PREFIX));
+        let file = SaveFile::unseal(&sealed);
+        assert_eq!(
+            file.ok().and_then(|f| f.slots.get("multiverse").cloned()),
+            Some(serde_json::json!({ "seed": 7 }))
+        );
+    }
+
+    #[test]
+    fn corrupt_or_truncated_envelopes_are_rejected() {
+        let sealed = sample().seal().unwrap_or_default();
+        let flipped = sealed.replace("\"seed\":7", "\"seed\":8");
+        assert!(SaveFile::unseal(&flipped).is_err_and(|e| e.contains("checksum mismatch")));
+        assert!(SaveFile::unseal(&sealed[..sealed.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn unsealed_envelopes_still_load() {
+        let legacy = serde_json::to_string(&sample()).unwrap_or_default();
+        assert!(SaveFile::unseal(&legacy).is_ok_and(|f| f.versions.get("multiverse") == Some(&1)));
+    }
 }
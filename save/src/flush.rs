@@ -0,0 +1,219 @@
+//! Coalesced envelope writes.
+//!
+//! `save_slot::<T>` only re-encodes its resource into the in-memory
+//! envelope and marks that envelope dirty in [`PendingWrites`].
+//! [`flush_saves`] then writes each dirty envelope at most once per frame,
+//! and no sooner than [`SaveThrottle::min_interval`] after the previous
+//! flush -- unless the flush is forced by a [`FlushSaves`] message, an
+//! `AppExit`, or any `GameState` transition (portal crossings pass through
+//! `MapTransition`, so they count).
+//!
+//! A browser tab can be closed or frozen without another frame running, so
+//! on WASM envelopes held back by the throttle are also sealed into a
+//! staging area that the page's `visibilitychange`/`beforeunload` handlers
+//! write straight to `localStorage`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use models::game_states::GameState;
+
+use crate::file::{GlobalSaveFile, SaveFile};
+use crate::persistable::{write_envelope, SaveScope};
+use crate::slots::ActiveSlot;
+use crate::storage::{SaveLocation, StorageBackend};
+
+/// How often dirty envelopes may be written.
+#[derive(Resource, Debug, Clone)]
+pub struct SaveThrottle {
+    /// Minimum time between two unforced flushes.
+    pub min_interval: Duration,
+}
+
+impl Default for SaveThrottle {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Send to write every dirty envelope at the end of this frame, ignoring
+/// [`SaveThrottle`].
+#[derive(Message, Debug, Clone, Copy, Default)]
+pub struct FlushSaves;
+
+/// Envelopes changed since they were last written.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct PendingWrites {
+    pub(crate) global: bool,
+    /// The active slot's envelope. Cleared when a slot switch writes it.
+    pub(crate) slot: bool,
+    /// Real time of the last flush, `None` before the first.
+    last_flush: Option<Duration>,
+}
+
+impl PendingWrites {
+    pub(crate) fn mark(&mut self, scope: SaveScope) {
+        match scope {
+            SaveScope::Global => self.global = true,
+            SaveScope::Slot => self.slot = true,
+        }
+    }
+
+    /// Whether an unforced flush may happen at real time `now`.
+    fn interval_elapsed(&self, now: Duration, min_interval: Duration) -> bool {
+        self.last_flush
+            .is_none_or(|last| now >= last + min_interval)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn flush_saves<B: StorageBackend>(
+    mut requests: MessageReader<FlushSaves>,
+    mut exits: MessageReader<AppExit>,
+    mut transitions: MessageReader<StateTransitionEvent<GameState>>,
+    time: Res<Time<Real>>,
+    throttle: Res<SaveThrottle>,
+    mut pending: ResMut<PendingWrites>,
+    global: Res<GlobalSaveFile>,
+    save: Res<SaveFile>,
+    active: Res<ActiveSlot>,
+    backend: Res<B>,
+) {
+    // Drain every reader so a stale message can't force a later flush.
+    let forced = [
+        requests.read().count(),
+        exits.read().count(),
+        transitions.read().count(),
+    ]
+    .iter()
+    .any(|count| *count > 0);
+
+    let slot = active.0.filter(|_| pending.slot);
+    if !pending.global && slot.is_none() {
+        return;
+    }
+
+    let now = time.elapsed();
+    if !forced && !pending.interval_elapsed(now, throttle.min_interval) {
+        #[cfg(target_arch = "wasm32")]
+        if global.is_changed() || save.is_changed() {
+            let global = pending.global.then_some((SaveLocation::Global, &global.0));
+            let slot = slot.map(|slot| (SaveLocation::Slot(slot), &*save));
+            unload::stage(global.into_iter().chain(slot));
+        }
+        return;
+    }
+
+    if pending.global {
+        write_envelope(&*backend, SaveLocation::Global, &global);
+    }
+    if let Some(slot) = slot {
+        write_envelope(&*backend, SaveLocation::Slot(slot), &save);
+    }
+    *pending = PendingWrites {
+        last_flush: Some(now),
+        ..PendingWrites::default()
+    };
+    #[cfg(target_arch = "wasm32")]
+    unload::clear();
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use unload::install_unload_hooks;
+
+#[cfg(target_arch = "wasm32")]
+mod unload {
+    use std::cell::RefCell;
+
+    use bevy::prelude::warn;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    use crate::file::SaveFile;
+    use crate::storage::{SaveLocation, StorageBackend, WasmBackend};
+
+    thread_local! {
+        /// Sealed envelopes the throttle is holding back.
+        static STAGED: RefCell<Vec<(SaveLocation, String)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(super) fn stage<'a>(envelopes: impl Iterator<Item = (SaveLocation, &'a SaveFile)>) {
+        let sealed = envelopes
+            .filter_map(|(at, envelope)| Some((at, envelope.seal().ok()?)))
+            .collect();
+        STAGED.with(|staged| *staged.borrow_mut() = sealed);
+    }
+
+    pub(super) fn clear() {
+        STAGED.with(|staged| staged.borrow_mut().clear());
+    }
+
+    fn write_staged() {
+        for (at, content) in STAGED.with(|staged| staged.take()) {
+            WasmBackend.write(at, &content);
+        }
+    }
+
+    /// Writes staged envelopes when the tab is hidden or the page unloads.
+    pub(crate) fn install_unload_hooks() {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let Some(document) = window.document() else {
+            return;
+        };
+
+        let hidden_document = document.clone();
+        let on_visibility = Closure::<dyn FnMut()>::new(move || {
+            if hidden_document.hidden() {
+                write_staged();
+            }
+        });
+        let on_unload = Closure::<dyn FnMut()>::new(write_staged);
+
+        let result = document
+            .add_event_listener_with_callback(
+                "visibilitychange",
+                on_visibility.as_ref().unchecked_ref(),
+            )
+            .and_then(|()| {
+                window.add_event_listener_with_callback(
+                    "beforeunload",
+                    on_unload.as_ref().unchecked_ref(),
+                )
+            });
+        if let Err(e) = result {
+            warn!("Failed to hook page unload for saves: {e:?}");
+        }
+        // The listeners live as long as the page.
+        on_visibility.forget();
+        on_unload.forget();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttle_spaces_unforced_flushes() {
+        let interval = Duration::from_secs(2);
+        let mut pending = PendingWrites::default();
+        assert!(pending.interval_elapsed(Duration::ZERO, interval));
+
+        pending.last_flush = Some(Duration::from_secs(10));
+        assert!(!pending.interval_elapsed(Duration::from_millis(11_999), interval));
+        assert!(pending.interval_elapsed(Duration::from_secs(12), interval));
+    }
+
+    #[test]
+    fn marks_the_envelope_of_the_scope() {
+        let mut pending = PendingWrites::default();
+        pending.mark(SaveScope::Global);
+        assert!(pending.global && !pending.slot);
+        pending.mark(SaveScope::Slot);
+        assert!(pending.slot);
+    }
+}
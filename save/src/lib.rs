@@ -1,14 +1,23 @@
 //! Unified save crate -- owns all platform I/O for persistent state.
 //!
-//! See [`Persistable`] for how to add a new persisted resource and
-//! [`SavePlugin`] for the wiring entry point.
+//! See [`Persistable`] for how to add a new persisted resource,
+//! [`SlotCommand`] for switching between save slots, and [`SavePlugin`] for
+//! the wiring entry point.
 
 mod file;
+mod flush;
 mod persistable;
 mod plugin;
+mod slots;
 mod storage;
+mod transfer;
 
-pub use file::{SaveFile, SAVE_VERSION};
-pub use persistable::{Migrator, PersistableAppExt, Persistable};
+pub use file::{GlobalSaveFile, SaveFile, SlotMeta, SAVE_VERSION};
+pub use flush::{FlushSaves, SaveThrottle};
+pub use persistable::{Migrator, Persistable, PersistableAppExt, SaveScope, SaveWrites};
 pub use plugin::SavePlugin;
-pub use storage::{NativeBackend, StorageBackend, WasmBackend};
+pub use slots::{calendar_date, ActiveSlot, SlotCommand, SlotIndex, SlotSummary, SlotSwitched};
+pub use storage::{
+    NativeBackend, SaveLocation, SlotId, StorageBackend, WasmBackend, BACKUP_COUNT, SLOT_COUNT,
+};
+pub use transfer::{export_string, import_string, SaveTransfer, TransferOutcome, EXPORT_PREFIX};
@@ -2,21 +2,44 @@
 //! the unified save file.
 //!
 //! A [`Persistable`] resource declares a stable [`KEY`](Persistable::KEY) and
-//! gets two systems registered automatically by
-//! [`PersistableAppExt::register_persistable`]:
+//! a [`SCOPE`](Persistable::SCOPE), and gets its systems registered
+//! automatically by [`PersistableAppExt::register_persistable`]:
 //!
 //! - `load_slot::<T>` (PreStartup, after `load_save_file`) -- decodes the slot
-//!   from [`SaveFile`] into the resource, falling back to `T::default()` if
-//!   the slot is missing or malformed.
+//!   from [`GlobalSaveFile`] or [`SaveFile`] into the resource, falling back
+//!   to `T::default()` if the slot is missing or malformed.
+//! - `reload_slot::<T>` (PreUpdate, slot-scoped only) -- re-decodes the
+//!   resource after [`crate::SlotSwitched`], resetting it when the new slot
+//!   has no value for it.
 //! - `save_slot::<T>` (PostUpdate, on `resource_changed::<T>`) -- re-encodes
-//!   the resource into [`SaveFile`] and writes the envelope through the
-//!   active [`StorageBackend`].
+//!   the resource into its envelope and marks the envelope dirty; the
+//!   [`flush`](crate::flush) systems write it through the active
+//!   [`StorageBackend`].
+//!
+//! Each value is stored alongside the [`VERSION`](Persistable::VERSION) it
+//! was written at. Older values are upgraded one version at a time by the
+//! [`Migrator`]s registered with [`PersistableAppExt::register_migrator`]
+//! before decoding. A value that still can't be decoded is moved to
+//! [`SaveFile::quarantine`] rather than being overwritten by the next save.
+
+use std::collections::{BTreeMap, HashMap};
 
 use bevy::prelude::*;
 use serde::{de::DeserializeOwned, Serialize};
 
-use crate::file::{SaveFile, SAVE_VERSION};
-use crate::storage::StorageBackend;
+use crate::file::{unix_now, GlobalSaveFile, SaveFile, SlotMeta, SAVE_VERSION};
+use crate::flush::PendingWrites;
+use crate::slots::{handle_slot_commands, ActiveSlot, SlotIndex, SlotSwitched};
+use crate::storage::{SaveLocation, SlotId, StorageBackend, BACKUP_COUNT};
+
+/// Which envelope a [`Persistable`] is stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveScope {
+    /// Shared by every save slot, e.g. settings and keybinds.
+    Global,
+    /// Part of a playthrough; stored in the active slot.
+    Slot,
+}
 
 /// A resource that can be saved to and loaded from a slot in [`SaveFile`].
 ///
@@ -26,43 +49,137 @@ pub trait Persistable: Resource + Serialize + DeserializeOwned + Default {
     /// Stable key used as the slot name in [`SaveFile::slots`].
     /// Must be unique across all `Persistable` impls.
     const KEY: &'static str;
+    /// Envelope the resource lives in. Defaults to the active save slot.
+    const SCOPE: SaveScope = SaveScope::Slot;
+    /// Schema version of the serialized value, stored beside it in
+    /// [`SaveFile::versions`]. Bump when a change would stop older values
+    /// from decoding, and register a [`Migrator`] from the previous version.
+    /// Values saved before versions were recorded count as 0.
+    const VERSION: u32 = 0;
 }
 
-/// Future hook for slot-level migrations between schema versions.
+/// Upgrades a stored slot value by one schema version, from
+/// [`FROM`](Migrator::FROM) to `FROM + 1`.
 ///
-/// Not yet wired into [`PersistableAppExt::register_persistable`]; see the
-/// `version` field on [`SaveFile`] and [`SAVE_VERSION`] for the envelope-level
-/// counterpart.
+/// Registered with [`PersistableAppExt::register_migrator`]; loading chains
+/// every step between the stored version and [`Persistable::VERSION`]. See
+/// the `version` field on [`SaveFile`] and [`SAVE_VERSION`] for the
+/// envelope-level counterpart.
 pub trait Migrator {
     /// The resource type this migrator targets.
     type Target: Persistable;
-    /// Attempt to upgrade a stored slot value from `from_version` to the
-    /// current version.
-    fn migrate(value: serde_json::Value, from_version: u32) -> Option<serde_json::Value>;
+    /// The version this migrator upgrades from.
+    const FROM: u32;
+    /// Attempt to rewrite `value` into the shape of version `FROM + 1`.
+    /// `None` means the value can't be upgraded and will be quarantined.
+    fn migrate(value: serde_json::Value) -> Option<serde_json::Value>;
+}
+
+type MigrateFn = fn(serde_json::Value) -> Option<serde_json::Value>;
+type CheckFn = fn(&SaveFile, &Migrations) -> Result<(), String>;
+
+/// Registered [`Migrator`] steps, keyed by [`Persistable::KEY`] and then by
+/// the version each step upgrades from.
+#[derive(Resource, Default)]
+pub struct Migrations {
+    steps: HashMap<&'static str, BTreeMap<u32, MigrateFn>>,
+    /// One dry-run decode per slot-scoped persistable, for vetting imports.
+    checks: Vec<CheckFn>,
+    /// [`Persistable::KEY`] of every slot-scoped persistable.
+    slot_keys: Vec<&'static str>,
+}
+
+impl Migrations {
+    /// Whether every value in `envelope` would migrate and decode on load.
+    /// Values for keys this build doesn't know are ignored, as on load.
+    pub(crate) fn check(&self, envelope: &SaveFile) -> Result<(), String> {
+        self.checks
+            .iter()
+            .try_for_each(|check| check(envelope, self))
+    }
+
+    /// Runs the steps for `key` that take `value` from version `from` up to
+    /// `to`. Fails on a missing or failing step, or a value newer than `to`.
+    fn upgrade(
+        &self,
+        key: &str,
+        value: serde_json::Value,
+        from: u32,
+        to: u32,
+    ) -> Result<serde_json::Value, String> {
+        if from > to {
+            return Err(format!(
+                "stored version {from} is newer than this build's {to}"
+            ));
+        }
+        let steps = self.steps.get(key);
+        (from..to).try_fold(value, |value, version| {
+            let step = steps
+                .and_then(|steps| steps.get(&version))
+                .ok_or_else(|| format!("no migrator from version {version}"))?;
+            step(value).ok_or_else(|| format!("migration from version {version} failed"))
+        })
+    }
 }
 
+/// Systems that encode resources into their envelopes in `PostUpdate`.
+/// Anything that edits [`SaveFile`] before a write orders itself
+/// `.before(SaveWrites)`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SaveWrites;
+
 /// `App` extension that wires a [`Persistable`] into the load/save lifecycle.
 pub trait PersistableAppExt {
     /// Registers `T` as a persistable resource.
     ///
     /// Inserts `T::default()` if not already present, then schedules
-    /// `load_slot::<T>` in `PreStartup` (after [`load_save_file`]) and
+    /// `load_slot::<T>` in `PreStartup` (after [`load_save_file`]),
+    /// `reload_slot::<T>` in `PreUpdate` for slot-scoped resources, and
     /// `save_slot::<T>` in `PostUpdate` (gated on `resource_changed::<T>`).
     fn register_persistable<T: Persistable>(&mut self) -> &mut Self;
+
+    /// Registers `M` as the step from version `M::FROM` of its target's
+    /// stored value. Must be called before the app runs.
+    fn register_migrator<M: Migrator>(&mut self) -> &mut Self;
 }
 
 impl PersistableAppExt for App {
     fn register_persistable<T: Persistable>(&mut self) -> &mut Self {
+        self.init_resource::<Migrations>();
+        if T::SCOPE == SaveScope::Slot {
+            self.add_systems(
+                PreUpdate,
+                reload_slot::<T>
+                    .after(handle_slot_commands::<WasmOrNativeBackend>)
+                    .run_if(on_message::<SlotSwitched>),
+            );
+            let mut migrations = self.world_mut().resource_mut::<Migrations>();
+            migrations.checks.push(check::<T>);
+            migrations.slot_keys.push(T::KEY);
+        }
         self.init_resource::<T>()
             .add_systems(PreStartup, load_slot::<T>.after(load_save_file))
             .add_systems(
                 PostUpdate,
-                save_slot::<T, WasmOrNativeBackend>.run_if(resource_changed::<T>),
+                save_slot::<T>
+                    .in_set(SaveWrites)
+                    .run_if(resource_changed::<T>),
             )
     }
+
+    fn register_migrator<M: Migrator>(&mut self) -> &mut Self {
+        self.init_resource::<Migrations>();
+        self.world_mut()
+            .resource_mut::<Migrations>()
+            .steps
+            .entry(<M::Target as Persistable>::KEY)
+            .or_default()
+            .insert(M::FROM, M::migrate);
+        self
+    }
 }
 
-// The save_slot system needs a concrete backend type at registration time.
+// Systems that touch storage need a concrete backend type at registration time.
 // `WasmOrNativeBackend` is a typedef chosen by `cfg`; the plugin inserts the
 // matching `Resource` impl exactly once.
 #[cfg(target_arch = "wasm32")]
@@ -71,73 +188,522 @@ pub(crate) type WasmOrNativeBackend = crate::storage::WasmBackend;
 pub(crate) type WasmOrNativeBackend = crate::storage::NativeBackend;
 
 // ---------------------------------------------------------------------------
-// PreStartup: load envelope from disk/localStorage into `SaveFile` resource.
+// PreStartup: load envelopes from disk/localStorage into resources.
 // ---------------------------------------------------------------------------
 
-/// Reads the persisted envelope (if any) and inserts it as a `SaveFile`
-/// resource. Runs at `PreStartup` order 0 so all `load_slot::<T>` systems
-/// (registered with `.after(load_save_file)`) see a populated envelope.
-pub fn load_save_file(mut commands: Commands, backend: Res<WasmOrNativeBackend>) {
-    let file = backend
-        .read()
-        .and_then(|raw| {
-            serde_json::from_str::<SaveFile>(&raw)
-                .map_err(|e| warn!("Save file corrupt, using defaults: {e}"))
-                .ok()
-        })
-        .inspect(|loaded| {
-            if loaded.version != SAVE_VERSION {
-                warn!(
-                    "Save file version mismatch: found {}, expected {}. Slots will be \
-                     loaded best-effort and missing fields filled from defaults.",
-                    loaded.version, SAVE_VERSION
-                );
-            }
-        })
+/// Reads the global envelope and the most recently played slot, inserting
+/// them as [`GlobalSaveFile`] and [`SaveFile`]. Runs at `PreStartup` order 0
+/// so all `load_slot::<T>` systems (registered with
+/// `.after(load_save_file)`) see populated envelopes.
+///
+/// A pre-slot save holds the whole playthrough in the global envelope; the
+/// first time it is seen with no slots on disk its slot-scoped values are
+/// moved into slot 1.
+pub fn load_save_file(
+    mut commands: Commands,
+    migrations: Res<Migrations>,
+    backend: Res<WasmOrNativeBackend>,
+) {
+    let mut global = read_envelope(&*backend, SaveLocation::Global).unwrap_or_default();
+
+    if global.version < SAVE_VERSION && !global.slots.is_empty() && backend.list_slots().is_empty()
+    {
+        let legacy = adopt_legacy_slot(&mut global, &migrations.slot_keys);
+        write_envelope(&*backend, SaveLocation::Slot(SlotId(1)), &legacy);
+        write_envelope(&*backend, SaveLocation::Global, &global);
+        info!("Moved single-slot save into slot 1.");
+    }
+
+    let index = SlotIndex::scan(&*backend);
+    let active = index.most_recent();
+    let mut backups = SessionBackups::default();
+    backups.back_up(&*backend, SaveLocation::Global);
+    if let Some(slot) = active {
+        backups.back_up(&*backend, SaveLocation::Slot(slot));
+    }
+    let file = active
+        .and_then(|slot| read_envelope(&*backend, SaveLocation::Slot(slot)))
         .unwrap_or_default();
 
+    commands.insert_resource(GlobalSaveFile(global));
     commands.insert_resource(file);
+    commands.insert_resource(ActiveSlot(active));
+    commands.insert_resource(index);
+    commands.insert_resource(backups);
+}
+
+/// Moves the values for `slot_keys` out of a pre-slot `global` envelope
+/// into a new slot envelope, leaving only the global values behind.
+fn adopt_legacy_slot(global: &mut SaveFile, slot_keys: &[&str]) -> SaveFile {
+    let mut legacy = SaveFile {
+        version: SAVE_VERSION,
+        meta: Some(SlotMeta {
+            created: unix_now(),
+            ..SlotMeta::default()
+        }),
+        ..SaveFile::default()
+    };
+    for key in slot_keys {
+        if let Some(value) = global.slots.remove(*key) {
+            legacy.slots.insert((*key).to_owned(), value);
+        }
+        if let Some(version) = global.versions.remove(*key) {
+            legacy.versions.insert((*key).to_owned(), version);
+        }
+    }
+    global.version = SAVE_VERSION;
+    legacy
+}
+
+/// Reads and decodes the envelope at `at`, falling back to the newest
+/// backup that passes its checksum when the live one is missing or corrupt.
+/// Yields `None` if nothing usable is stored.
+pub(crate) fn read_envelope<B: StorageBackend>(backend: &B, at: SaveLocation) -> Option<SaveFile> {
+    let live = backend.read(at).and_then(|raw| {
+        SaveFile::unseal(&raw)
+            .map_err(|e| warn!("Save file '{}' corrupt: {e}", at.key()))
+            .ok()
+    });
+    let loaded = match live {
+        Some(loaded) => loaded,
+        None => (1..=BACKUP_COUNT).find_map(|generation| {
+            let raw = backend.read_backup(at, generation)?;
+            SaveFile::unseal(&raw)
+                .map_err(|e| warn!("Backup {generation} of '{}' corrupt: {e}", at.key()))
+                .inspect(|_| warn!("Restored '{}' from backup {generation}.", at.key()))
+                .ok()
+        })?,
+    };
+    if loaded.version != SAVE_VERSION {
+        warn!(
+            "Save file '{}' version mismatch: found {}, expected {}. Slots will be \
+             loaded best-effort and missing fields filled from defaults.",
+            at.key(),
+            loaded.version,
+            SAVE_VERSION
+        );
+    }
+    Some(loaded)
+}
+
+/// Copies the live blob at `at` into its backups, if it is intact and not
+/// already the newest backup. A corrupt live blob is left out so it can't
+/// push a good backup off the end of the chain, and an unchanged one so
+/// reloading the same save doesn't either.
+///
+/// Called when a save is loaded or about to be replaced wholesale, not on
+/// every write, so the backups span sessions rather than seconds.
+pub(crate) fn back_up<B: StorageBackend>(backend: &B, at: SaveLocation) {
+    let Some(raw) = backend.read(at) else {
+        return;
+    };
+    if backend
+        .read_backup(at, 1)
+        .is_some_and(|newest| newest == raw)
+    {
+        return;
+    }
+    match SaveFile::unseal(&raw) {
+        Ok(_) => backend.write_backup(at, &raw),
+        Err(e) => warn!("Not backing up '{}', it is corrupt: {e}", at.key()),
+    }
+}
+
+/// Envelopes backed up since launch. Loading one of them again (e.g.
+/// "Continue" on the slot [`load_save_file`] just picked) doesn't back it
+/// up a second time.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct SessionBackups(Vec<SaveLocation>);
+
+impl SessionBackups {
+    /// [`back_up`]s `at` unless it was already backed up this session.
+    pub(crate) fn back_up<B: StorageBackend>(&mut self, backend: &B, at: SaveLocation) {
+        if !self.0.contains(&at) {
+            self.0.push(at);
+            back_up(backend, at);
+        }
+    }
+}
+
+/// Seals `envelope` and writes it through `backend`.
+pub(crate) fn write_envelope<B: StorageBackend>(
+    backend: &B,
+    at: SaveLocation,
+    envelope: &SaveFile,
+) {
+    match envelope.seal() {
+        Ok(json) => backend.write(at, &json),
+        Err(e) => warn!("Failed to serialize save envelope: {e}"),
+    }
 }
 
 // ---------------------------------------------------------------------------
-// PreStartup (per resource): decode slot into resource.
+// PreStartup / PreUpdate (per resource): decode slot into resource.
 // ---------------------------------------------------------------------------
 
-fn load_slot<T: Persistable>(save: Res<SaveFile>, mut target: ResMut<T>) {
-    let Some(raw) = save.slots.get(T::KEY) else {
-        return;
+fn load_slot<T: Persistable>(
+    mut save: ResMut<SaveFile>,
+    mut global: ResMut<GlobalSaveFile>,
+    migrations: Res<Migrations>,
+    mut target: ResMut<T>,
+) {
+    let envelope = match T::SCOPE {
+        SaveScope::Global => &mut global.0,
+        SaveScope::Slot => &mut *save,
     };
-    match serde_json::from_value::<T>(raw.clone()) {
-        Ok(decoded) => *target = decoded,
-        Err(e) => warn!(
-            "Save slot '{}' could not be decoded ({e}); keeping default.",
-            T::KEY
-        ),
+    if let Some(decoded) = decode::<T>(envelope, &migrations) {
+        *target = decoded;
+    }
+}
+
+/// After a slot switch the previous playthrough's value must not leak
+/// through, so a missing or malformed value resets to the default.
+fn reload_slot<T: Persistable>(
+    mut save: ResMut<SaveFile>,
+    migrations: Res<Migrations>,
+    mut target: ResMut<T>,
+) {
+    *target = decode::<T>(&mut save, &migrations).unwrap_or_default();
+}
+
+/// Migrates and decodes `T`'s value, or `None` if the envelope has none.
+fn migrate_value<T: Persistable>(
+    envelope: &SaveFile,
+    migrations: &Migrations,
+) -> Option<Result<T, String>> {
+    let raw = envelope.slots.get(T::KEY)?;
+    let from = envelope.versions.get(T::KEY).copied().unwrap_or(0);
+    Some(
+        migrations
+            .upgrade(T::KEY, raw.clone(), from, T::VERSION)
+            .and_then(|value| serde_json::from_value::<T>(value).map_err(|e| e.to_string())),
+    )
+}
+
+fn check<T: Persistable>(envelope: &SaveFile, migrations: &Migrations) -> Result<(), String> {
+    match migrate_value::<T>(envelope, migrations) {
+        Some(Err(e)) => Err(format!("'{}': {e}", T::KEY)),
+        _ => Ok(()),
+    }
+}
+
+/// First free quarantine entry for `key` at `version`: `<KEY>.v<version>`,
+/// then `<KEY>.v<version>.2`, `.3`, ... so a value that fails again at the
+/// same version never replaces one already kept.
+fn quarantine_key(
+    quarantine: &HashMap<String, serde_json::Value>,
+    key: &str,
+    version: u32,
+) -> String {
+    let base = format!("{key}.v{version}");
+    std::iter::once(base.clone())
+        .chain((2..).map(|n| format!("{base}.{n}")))
+        .find(|candidate| !quarantine.contains_key(candidate))
+        .unwrap_or(base)
+}
+
+/// Migrates and decodes `T`'s value from `envelope`. A value that fails is
+/// moved to the envelope's quarantine under a fresh
+/// [`quarantine_key`], so the default that replaces it never overwrites the
+/// player's data.
+fn decode<T: Persistable>(envelope: &mut SaveFile, migrations: &Migrations) -> Option<T> {
+    let decoded = migrate_value::<T>(envelope, migrations)?;
+
+    match decoded {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!(
+                "Save slot '{}' could not be decoded ({e}); quarantined, using default.",
+                T::KEY
+            );
+            let from = envelope.versions.get(T::KEY).copied().unwrap_or(0);
+            if let Some(raw) = envelope.slots.remove(T::KEY) {
+                let key = quarantine_key(&envelope.quarantine, T::KEY, from);
+                envelope.quarantine.insert(key, raw);
+            }
+            envelope.versions.remove(T::KEY);
+            None
+        }
     }
 }
 
 // ---------------------------------------------------------------------------
-// PostUpdate (per resource): encode resource and persist envelope.
+// PostUpdate (per resource): encode resource and mark its envelope dirty.
 // ---------------------------------------------------------------------------
 
-fn save_slot<T: Persistable, B: StorageBackend>(
+fn save_slot<T: Persistable>(
     source: Res<T>,
     mut save: ResMut<SaveFile>,
-    backend: Res<B>,
+    mut global: ResMut<GlobalSaveFile>,
+    active: Res<ActiveSlot>,
+    mut pending: ResMut<PendingWrites>,
 ) {
+    let envelope = match T::SCOPE {
+        SaveScope::Global => &mut global.0,
+        // Nothing is written until a slot has been continued, loaded or
+        // started.
+        SaveScope::Slot if active.0.is_some() => &mut *save,
+        SaveScope::Slot => return,
+    };
+
     match serde_json::to_value(&*source) {
         Ok(value) => {
-            save.slots.insert(T::KEY.to_owned(), value);
-            save.version = SAVE_VERSION;
+            envelope.slots.insert(T::KEY.to_owned(), value);
+            envelope.versions.insert(T::KEY.to_owned(), T::VERSION);
+            envelope.version = SAVE_VERSION;
         }
         Err(e) => {
             warn!("Failed to encode save slot '{}': {e}", T::KEY);
             return;
         }
     }
+    if let Some(meta) = envelope.meta.as_mut() {
+        meta.updated = unix_now();
+    }
 
-    match serde_json::to_string(&*save) {
-        Ok(json) => backend.write(&json),
-        Err(e) => warn!("Failed to serialize save envelope: {e}"),
+    pending.mark(T::SCOPE);
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::slots::SlotCommand;
+
+    #[derive(Resource, Default, Debug, PartialEq, Serialize, Deserialize)]
+    struct Volume {
+        level: u8,
+    }
+
+    impl Persistable for Volume {
+        const KEY: &'static str = "volume";
+        const VERSION: u32 = 2;
+    }
+
+    /// v0 stored a bare number.
+    struct WrapLevel;
+
+    impl Migrator for WrapLevel {
+        type Target = Volume;
+        const FROM: u32 = 0;
+        fn migrate(value: serde_json::Value) -> Option<serde_json::Value> {
+            Some(json!({ "volume": value }))
+        }
+    }
+
+    /// v1 called the field `volume`.
+    struct RenameField;
+
+    impl Migrator for RenameField {
+        type Target = Volume;
+        const FROM: u32 = 1;
+        fn migrate(value: serde_json::Value) -> Option<serde_json::Value> {
+            Some(json!({ "level": value.get("volume")? }))
+        }
+    }
+
+    fn migrations() -> Migrations {
+        let mut app = App::new();
+        app.register_migrator::<RenameField>()
+            .register_migrator::<WrapLevel>();
+        app.world_mut()
+            .remove_resource::<Migrations>()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn chains_migrators_from_stored_version() {
+        let mut envelope = SaveFile::default();
+        envelope.slots.insert("volume".into(), json!(7));
+        assert_eq!(
+            decode::<Volume>(&mut envelope, &migrations()),
+            Some(Volume { level: 7 })
+        );
+
+        envelope
+            .slots
+            .insert("volume".into(), json!({ "volume": 3 }));
+        envelope.versions.insert("volume".into(), 1);
+        assert_eq!(
+            decode::<Volume>(&mut envelope, &migrations()),
+            Some(Volume { level: 3 })
+        );
+        assert!(envelope.quarantine.is_empty());
+    }
+
+    #[test]
+    fn quarantines_values_that_cannot_be_decoded() {
+        let mut envelope = SaveFile::default();
+        envelope
+            .slots
+            .insert("volume".into(), json!({ "level": 1 }));
+        envelope.versions.insert("volume".into(), 5);
+
+        assert_eq!(decode::<Volume>(&mut envelope, &migrations()), None);
+        assert!(!envelope.slots.contains_key("volume"));
+        assert!(!envelope.versions.contains_key("volume"));
+        assert_eq!(
+            envelope.quarantine.get("volume.v5"),
+            Some(&json!({ "level": 1 }))
+        );
+
+        // A second failure at the same version is kept beside the first.
+        envelope
+            .slots
+            .insert("volume".into(), json!({ "level": 2 }));
+        envelope.versions.insert("volume".into(), 5);
+        assert_eq!(decode::<Volume>(&mut envelope, &migrations()), None);
+        assert_eq!(
+            envelope.quarantine.get("volume.v5"),
+            Some(&json!({ "level": 1 }))
+        );
+        assert_eq!(
+            envelope.quarantine.get("volume.v5.2"),
+            Some(&json!({ "level": 2 }))
+        );
+    }
+
+    #[test]
+    fn adopting_a_legacy_save_moves_only_slot_values() {
+        let mut global = SaveFile::default();
+        global.slots.insert("volume".into(), json!({ "level": 3 }));
+        global.versions.insert("volume".into(), 2);
+        global.slots.insert("keybinds".into(), json!({}));
+
+        let legacy = adopt_legacy_slot(&mut global, &["volume"]);
+        assert_eq!(legacy.slots.get("volume"), Some(&json!({ "level": 3 })));
+        assert_eq!(legacy.versions.get("volume"), Some(&2));
+        assert!(!legacy.slots.contains_key("keybinds"));
+        assert!(!global.slots.contains_key("volume"));
+        assert!(!global.versions.contains_key("volume"));
+        assert!(global.slots.contains_key("keybinds"));
+        assert_eq!(global.version, SAVE_VERSION);
+    }
+
+    /// Live blobs and backups in memory, keyed like `localStorage`.
+    #[derive(Resource, Default)]
+    struct MemoryBackend(std::sync::Mutex<HashMap<String, String>>);
+
+    impl MemoryBackend {
+        fn get(&self, key: &str) -> Option<String> {
+            self.0.lock().ok()?.get(key).cloned()
+        }
+
+        fn set(&self, key: String, value: &str) {
+            if let Ok(mut blobs) = self.0.lock() {
+                blobs.insert(key, value.to_owned());
+            }
+        }
+    }
+
+    impl StorageBackend for MemoryBackend {
+        fn read(&self, at: SaveLocation) -> Option<String> {
+            self.get(&at.key())
+        }
+        fn read_backup(&self, at: SaveLocation, generation: u8) -> Option<String> {
+            self.get(&at.backup_key(generation))
+        }
+        fn write(&self, at: SaveLocation, content: &str) {
+            self.set(at.key(), content);
+        }
+        fn write_backup(&self, at: SaveLocation, content: &str) {
+            for generation in (1..BACKUP_COUNT).rev() {
+                if let Some(older) = self.get(&at.backup_key(generation)) {
+                    self.set(at.backup_key(generation + 1), &older);
+                }
+            }
+            self.set(at.backup_key(1), content);
+        }
+        fn list_slots(&self) -> Vec<SlotId> {
+            Vec::new()
+        }
+        fn delete_slot(&self, _slot: SlotId) {}
+    }
+
+    #[test]
+    fn backs_up_only_intact_saves() {
+        let backend = MemoryBackend::default();
+        let at = SaveLocation::Slot(SlotId(1));
+        let mut envelope = SaveFile::new_slot();
+        envelope
+            .slots
+            .insert("volume".into(), json!({ "level": 1 }));
+        write_envelope(&backend, at, &envelope);
+        back_up(&backend, at);
+        assert_eq!(backend.read_backup(at, 1), backend.read(at));
+
+        // A torn write is neither backed up nor allowed to push the good
+        // copy out of the chain.
+        let good = backend.read(at).unwrap_or_default();
+        backend.write(at, &good[..good.len() / 2]);
+        back_up(&backend, at);
+        assert_eq!(backend.read_backup(at, 1), Some(good));
+        assert_eq!(backend.read_backup(at, 2), None);
+
+        // ...and loading falls back to it.
+        assert!(read_envelope(&backend, at)
+            .is_some_and(|file| file.slots.get("volume") == Some(&json!({ "level": 1 }))));
+    }
+
+    #[test]
+    fn reloading_a_save_keeps_older_backups() {
+        let at = SaveLocation::Slot(SlotId(1));
+        let mut app = App::new();
+        app.add_message::<SlotCommand>()
+            .add_message::<SlotSwitched>()
+            .init_resource::<SaveFile>()
+            .init_resource::<ActiveSlot>()
+            .init_resource::<SlotIndex>()
+            .init_resource::<PendingWrites>()
+            .init_resource::<MemoryBackend>()
+            .add_systems(Update, handle_slot_commands::<MemoryBackend>);
+
+        // Two sessions' worth of backups.
+        let backend = app.world().resource::<MemoryBackend>();
+        for level in [1, 2] {
+            let mut envelope = SaveFile::new_slot();
+            envelope
+                .slots
+                .insert("volume".into(), json!({ "level": level }));
+            write_envelope(backend, at, &envelope);
+            back_up(backend, at);
+        }
+        let oldest = backend.read_backup(at, 2);
+        assert!(oldest.is_some());
+
+        // Launch backs up the slot it resumes, then "Continue" and later
+        // trips through the menu load it again.
+        let mut backups = SessionBackups::default();
+        backups.back_up(app.world().resource::<MemoryBackend>(), at);
+        app.insert_resource(backups);
+        for slot in [1, 2, 1, 1] {
+            app.world_mut()
+                .write_message(SlotCommand::Load(SlotId(slot)));
+            app.update();
+        }
+
+        let backend = app.world().resource::<MemoryBackend>();
+        assert_eq!(backend.read_backup(at, 2), oldest);
+        assert_eq!(backend.read_backup(at, 3), None);
+    }
+
+    #[test]
+    fn checks_envelopes_without_changing_them() {
+        let mut app = App::new();
+        app.register_persistable::<Volume>()
+            .register_migrator::<WrapLevel>()
+            .register_migrator::<RenameField>();
+        let migrations = app.world().resource::<Migrations>();
+
+        let mut envelope = SaveFile::default();
+        envelope.slots.insert("volume".into(), json!(4));
+        envelope.slots.insert("unknown".into(), json!(null));
+        assert_eq!(migrations.check(&envelope), Ok(()));
+
+        envelope.versions.insert("volume".into(), 5);
+        assert!(migrations.check(&envelope).is_err());
+        assert!(envelope.quarantine.is_empty());
     }
 }
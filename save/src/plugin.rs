@@ -2,17 +2,33 @@
 //!
 //! Owns the cfg gate that selects [`WasmBackend`] vs [`NativeBackend`] and
 //! holds the orphan-rule [`Persistable`] impls for the foreign resources
-//! (`Keybinds`, `LoreBook`, `GameSettings`) that the project persists.
+//! (`Keybinds`, `LoreBook`, `GameSettings`, story and dialogue state) that the project
+//! persists. Keybinds and settings are shared by every save slot; the rest
+//! belong to the active slot.
 
 use bevy::prelude::*;
+use dialog::components::GreetedNpcs;
+use dialog::flags::DialogueFlags;
 use dialog::history::LoreBook;
+use dialog::quest::QuestLog;
+use dialog::runner::DialogueSnapshot;
 use keybinds::Keybinds;
-use models::multiverse::MultiverseSave;
+use models::alignment::PlayerAlignment;
+use models::game_states::GameState;
+use models::multiverse::{MultiverseSave, PurifiedEnemies};
 use models::settings::GameSettings;
 
-use crate::file::SaveFile;
-use crate::persistable::{load_save_file, PersistableAppExt};
-use crate::Persistable;
+use crate::file::{GlobalSaveFile, SaveFile};
+use crate::flush::{flush_saves, FlushSaves, PendingWrites, SaveThrottle};
+use crate::persistable::{
+    load_save_file, PersistableAppExt, SaveWrites, SessionBackups, WasmOrNativeBackend,
+};
+use crate::slots::{
+    handle_slot_commands, sync_slot_index, tick_playtime, track_slot_meta, ActiveSlot, SlotCommand,
+    SlotIndex, SlotSwitched,
+};
+use crate::transfer::{handle_save_transfers, SaveTransfer, TransferOutcome};
+use crate::{Persistable, SaveScope};
 
 pub struct SavePlugin;
 
@@ -28,15 +44,67 @@ impl Plugin for SavePlugin {
         // during their own Startup before persistable load runs.
         app.init_resource::<GameSettings>();
 
-        // Envelope: load before any per-slot loads.
-        app.init_resource::<SaveFile>()
+        // Envelopes: load before any per-slot loads.
+        app.init_resource::<GlobalSaveFile>()
+            .init_resource::<SaveFile>()
             .add_systems(PreStartup, load_save_file);
 
+        // Writes: dirty envelopes are flushed together at the very end of
+        // the frame, after anything that may have sent `AppExit`.
+        app.init_resource::<SaveThrottle>()
+            .init_resource::<PendingWrites>()
+            .add_message::<FlushSaves>()
+            .add_systems(Last, flush_saves::<WasmOrNativeBackend>);
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(Startup, crate::flush::install_unload_hooks);
+
+        // Save slots: menus send `SlotCommand`s, applied before state
+        // transitions so `OnEnter(Playing)` sees the picked slot.
+        app.init_resource::<ActiveSlot>()
+            .init_resource::<SlotIndex>()
+            .init_resource::<SessionBackups>()
+            .add_message::<SlotCommand>()
+            .add_message::<SlotSwitched>()
+            .add_systems(PreUpdate, handle_slot_commands::<WasmOrNativeBackend>)
+            .add_systems(Update, tick_playtime.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                PostUpdate,
+                (
+                    track_slot_meta.before(SaveWrites).run_if(
+                        resource_changed::<MultiverseSave>.or(resource_changed::<PlayerAlignment>),
+                    ),
+                    sync_slot_index
+                        .after(SaveWrites)
+                        .run_if(resource_changed::<SaveFile>),
+                ),
+            );
+
+        // Export/import, applied before slot commands so an import can be
+        // loaded in the same frame.
+        app.add_message::<SaveTransfer>()
+            .add_message::<TransferOutcome>()
+            .add_systems(
+                PreUpdate,
+                handle_save_transfers::<WasmOrNativeBackend>
+                    .before(handle_slot_commands::<WasmOrNativeBackend>),
+            );
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(
+            Startup,
+            crate::transfer::load_save_from_args::<WasmOrNativeBackend>,
+        );
+
         // Per-resource slots.
         app.register_persistable::<Keybinds>()
             .register_persistable::<LoreBook>()
             .register_persistable::<GameSettings>()
-            .register_persistable::<MultiverseSave>();
+            .register_persistable::<MultiverseSave>()
+            .register_persistable::<PurifiedEnemies>()
+            .register_persistable::<PlayerAlignment>()
+            .register_persistable::<DialogueFlags>()
+            .register_persistable::<GreetedNpcs>()
+            .register_persistable::<DialogueSnapshot>()
+            .register_persistable::<QuestLog>();
     }
 }
 
@@ -46,6 +114,7 @@ impl Plugin for SavePlugin {
 
 impl Persistable for Keybinds {
     const KEY: &'static str = "keybinds";
+    const SCOPE: SaveScope = SaveScope::Global;
 }
 
 impl Persistable for LoreBook {
@@ -54,8 +123,33 @@ impl Persistable for LoreBook {
 
 impl Persistable for GameSettings {
     const KEY: &'static str = "settings";
+    const SCOPE: SaveScope = SaveScope::Global;
 }
 
 impl Persistable for MultiverseSave {
     const KEY: &'static str = "multiverse";
 }
+
+impl Persistable for PurifiedEnemies {
+    const KEY: &'static str = "purified_enemies";
+}
+
+impl Persistable for PlayerAlignment {
+    const KEY: &'static str = "alignment";
+}
+
+impl Persistable for DialogueFlags {
+    const KEY: &'static str = "dialogue_flags";
+}
+
+impl Persistable for GreetedNpcs {
+    const KEY: &'static str = "greeted_npcs";
+}
+
+impl Persistable for DialogueSnapshot {
+    const KEY: &'static str = "dialogue";
+}
+
+impl Persistable for QuestLog {
+    const KEY: &'static str = "quests";
+}
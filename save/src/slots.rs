@@ -0,0 +1,303 @@
+//! Save slots: which one is active, what each one holds, and the commands
+//! menus send to switch, copy or delete them.
+//!
+//! Menus never touch storage directly. They read [`SlotIndex`] and write
+//! [`SlotCommand`]s; [`handle_slot_commands`] applies them in `PreUpdate`
+//! so a state change requested in the same frame (e.g. to `Playing`) sees
+//! the new slot's resources on `OnEnter`.
+
+use bevy::prelude::*;
+use models::alignment::PlayerAlignment;
+use models::multiverse::MultiverseSave;
+
+use crate::file::{unix_now, SaveFile, SlotMeta};
+use crate::flush::PendingWrites;
+use crate::persistable::{back_up, read_envelope, write_envelope, SessionBackups};
+use crate::storage::{SaveLocation, SlotId, StorageBackend};
+
+/// The slot the running game reads from and writes to. `None` until a slot
+/// exists and has been picked; slot-scoped resources are not saved
+/// meanwhile.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveSlot(pub Option<SlotId>);
+
+/// One occupied slot as listed by the slot picker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotSummary {
+    pub id: SlotId,
+    pub meta: SlotMeta,
+}
+
+/// Headers of every occupied slot, kept in step with writes so menus can
+/// list saves without reading storage.
+#[derive(Resource, Debug, Default)]
+pub struct SlotIndex(Vec<SlotSummary>);
+
+impl SlotIndex {
+    /// Reads the header of every slot on disk. Unreadable slots are listed
+    /// with a blank header so they can still be overwritten or deleted.
+    pub(crate) fn scan<B: StorageBackend>(backend: &B) -> Self {
+        Self(
+            backend
+                .list_slots()
+                .into_iter()
+                .map(|id| SlotSummary {
+                    id,
+                    meta: read_envelope(backend, SaveLocation::Slot(id))
+                        .and_then(|file| file.meta)
+                        .unwrap_or_default(),
+                })
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, id: SlotId) -> Option<&SlotMeta> {
+        self.0.iter().find(|s| s.id == id).map(|s| &s.meta)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SlotSummary> {
+        self.0.iter()
+    }
+
+    /// Whether none of the slots the picker offers hold a save.
+    pub fn is_empty(&self) -> bool {
+        self.offered().next().is_none()
+    }
+
+    /// The offered slot written most recently -- what "Continue" resumes.
+    pub fn most_recent(&self) -> Option<SlotId> {
+        self.offered().max_by_key(|s| s.meta.updated).map(|s| s.id)
+    }
+
+    fn offered(&self) -> impl Iterator<Item = &SlotSummary> {
+        self.0.iter().filter(|s| s.id.is_offered())
+    }
+
+    /// The lowest-numbered slot with no save in it.
+    pub fn first_free(&self) -> Option<SlotId> {
+        SlotId::all().find(|id| self.get(*id).is_none())
+    }
+
+    pub(crate) fn upsert(&mut self, id: SlotId, meta: SlotMeta) {
+        match self.0.iter_mut().find(|s| s.id == id) {
+            Some(summary) => summary.meta = meta,
+            None => {
+                self.0.push(SlotSummary { id, meta });
+                self.0.sort_by_key(|s| s.id);
+            }
+        }
+    }
+
+    fn remove(&mut self, id: SlotId) {
+        self.0.retain(|s| s.id != id);
+    }
+}
+
+/// Requests from menus to change slots.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotCommand {
+    /// Make the slot active and load its resources.
+    Load(SlotId),
+    /// Start a fresh playthrough in the slot, overwriting whatever was there.
+    NewGame(SlotId),
+    /// Duplicate one slot over another.
+    Copy { from: SlotId, to: SlotId },
+    /// Remove the slot's save. Deleting the active slot leaves none active.
+    Delete(SlotId),
+}
+
+/// Written when [`SaveFile`] has been swapped for another slot's envelope.
+/// Slot-scoped persistables reload from it in the same `PreUpdate`.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SlotSwitched;
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handle_slot_commands<B: StorageBackend>(
+    mut requests: MessageReader<SlotCommand>,
+    mut switched: MessageWriter<SlotSwitched>,
+    mut save: ResMut<SaveFile>,
+    mut active: ResMut<ActiveSlot>,
+    mut index: ResMut<SlotIndex>,
+    mut pending: ResMut<PendingWrites>,
+    mut backups: ResMut<SessionBackups>,
+    backend: Res<B>,
+) {
+    for request in requests.read() {
+        match *request {
+            SlotCommand::Load(slot) | SlotCommand::NewGame(slot) => {
+                // Flush the outgoing slot so playtime since its last write
+                // is kept.
+                if let Some(current) = active.0 {
+                    flush(&*backend, current, &mut save);
+                }
+                // Back up what is there before the flush below replaces
+                // it: once per session for a load, always for a new game.
+                match request {
+                    SlotCommand::Load(_) => backups.back_up(&*backend, SaveLocation::Slot(slot)),
+                    _ => back_up(&*backend, SaveLocation::Slot(slot)),
+                }
+                let existing = match request {
+                    SlotCommand::Load(_) => read_envelope(&*backend, SaveLocation::Slot(slot)),
+                    _ => None,
+                };
+                *save = existing.unwrap_or_else(SaveFile::new_slot);
+                if save.meta.is_none() {
+                    save.meta = Some(SlotMeta::default());
+                }
+                flush(&*backend, slot, &mut save);
+                pending.slot = false;
+                if let Some(meta) = &save.meta {
+                    index.upsert(slot, meta.clone());
+                }
+                active.0 = Some(slot);
+                switched.write(SlotSwitched);
+            }
+            SlotCommand::Copy { from, to } => {
+                if active.0 == Some(from) {
+                    flush(&*backend, from, &mut save);
+                    pending.slot = false;
+                }
+                back_up(&*backend, SaveLocation::Slot(to));
+                if backend.copy_slot(from, to) {
+                    if let Some(meta) = index.get(from).cloned() {
+                        index.upsert(to, meta);
+                    }
+                }
+                if active.0 == Some(to) {
+                    // The copy replaced what the game is playing; pick it up.
+                    if let Some(file) = read_envelope(&*backend, SaveLocation::Slot(to)) {
+                        *save = file;
+                        switched.write(SlotSwitched);
+                    }
+                }
+            }
+            SlotCommand::Delete(slot) => {
+                backend.delete_slot(slot);
+                index.remove(slot);
+                if active.0 == Some(slot) {
+                    active.0 = None;
+                    pending.slot = false;
+                    *save = SaveFile::default();
+                    switched.write(SlotSwitched);
+                }
+            }
+        }
+    }
+}
+
+/// Stamps and writes the active envelope right away, bypassing the
+/// [`SaveThrottle`](crate::SaveThrottle).
+fn flush<B: StorageBackend>(backend: &B, slot: SlotId, save: &mut SaveFile) {
+    if let Some(meta) = save.meta.as_mut() {
+        meta.updated = unix_now();
+    }
+    write_envelope(backend, SaveLocation::Slot(slot), save);
+}
+
+/// Copies the slot header fields that mirror game resources into the
+/// active envelope, ahead of that frame's writes.
+pub(crate) fn track_slot_meta(
+    mut save: ResMut<SaveFile>,
+    multiverse: Res<MultiverseSave>,
+    alignment: Res<PlayerAlignment>,
+) {
+    let Some(meta) = save.bypass_change_detection().meta.as_mut() else {
+        return;
+    };
+    meta.map_alignment = multiverse.current_alignment;
+    meta.maps_traversed = multiverse.maps_traversed;
+    meta.dominant = Some(alignment.dominant());
+}
+
+/// Accumulates playtime on the active slot. Written along with the next
+/// save rather than forcing one.
+pub(crate) fn tick_playtime(time: Res<Time>, mut save: ResMut<SaveFile>) {
+    if let Some(meta) = save.bypass_change_detection().meta.as_mut() {
+        meta.playtime_secs += time.delta_secs_f64();
+    }
+}
+
+/// Mirrors the active envelope's header into [`SlotIndex`] after writes.
+pub(crate) fn sync_slot_index(
+    save: Res<SaveFile>,
+    active: Res<ActiveSlot>,
+    mut index: ResMut<SlotIndex>,
+) {
+    if let (Some(slot), Some(meta)) = (active.0, &save.meta) {
+        if index.get(slot) != Some(meta) {
+            index.upsert(slot, meta.clone());
+        }
+    }
+}
+
+/// Civil (year, month, day) in UTC for a Unix timestamp, for showing when a
+/// slot was last played without a date library.
+pub fn calendar_date(unix_secs: u64) -> (i64, u32, u32) {
+    // Howard Hinnant's days-to-civil algorithm.
+    let days = i64::try_from(unix_secs / 86_400).unwrap_or(0) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (
+        year,
+        u32::try_from(month).unwrap_or(1),
+        u32::try_from(day).unwrap_or(1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SLOT_COUNT;
+
+    fn meta(updated: u64) -> SlotMeta {
+        SlotMeta {
+            updated,
+            ..SlotMeta::default()
+        }
+    }
+
+    #[test]
+    fn index_tracks_recent_and_free_slots() {
+        let mut index = SlotIndex::default();
+        assert_eq!(index.most_recent(), None);
+        assert_eq!(index.first_free(), Some(SlotId(1)));
+
+        index.upsert(SlotId(2), meta(20));
+        index.upsert(SlotId(1), meta(10));
+        assert_eq!(index.most_recent(), Some(SlotId(2)));
+        assert_eq!(index.first_free(), Some(SlotId(3)));
+
+        index.upsert(SlotId(1), meta(30));
+        assert_eq!(index.most_recent(), Some(SlotId(1)));
+
+        index.remove(SlotId(1));
+        assert_eq!(index.iter().map(|s| s.id).collect::<Vec<_>>(), [SlotId(2)]);
+    }
+
+    #[test]
+    fn reserved_slots_are_never_resumed() {
+        let mut index = SlotIndex::default();
+        let reserved = SlotId(SLOT_COUNT + 1);
+        index.upsert(reserved, meta(50));
+        assert!(index.is_empty());
+        assert_eq!(index.most_recent(), None);
+
+        index.upsert(SlotId(3), meta(10));
+        assert!(!index.is_empty());
+        assert_eq!(index.most_recent(), Some(SlotId(3)));
+    }
+
+    #[test]
+    fn calendar_date_matches_known_days() {
+        assert_eq!(calendar_date(0), (1970, 1, 1));
+        assert_eq!(calendar_date(951_782_400), (2000, 2, 29));
+        assert_eq!(calendar_date(1_792_281_600), (2026, 10, 18));
+    }
+}
@@ -1,109 +1,389 @@
-//! Platform-specific storage backends for the unified save file.
+//! Platform-specific storage backends for the save envelopes.
 //!
 //! Implementations:
-//! - [`WasmBackend`] reads/writes `localStorage["evergreen.save"]`
+//! - [`WasmBackend`] reads/writes `localStorage["evergreen.save"]` and one
+//!   `localStorage["evergreen.slot.<n>"]` entry per slot
 //! - [`NativeBackend`] reads/writes `./evergreen_saves/evergreen.save.json`
+//!   and one `./evergreen_saves/evergreen.slot.<n>.json` file per slot
+//!
+//! Each location keeps a chain of [`BACKUP_COUNT`] backups (`<key>.bak<n>`,
+//! newest first), so a save that turns out to be truncated or corrupt can
+//! be recovered from an earlier one. Backups are taken at coarse
+//! boundaries -- when a slot is loaded or about to be replaced -- rather
+//! than on every write, so they reach back across play sessions instead of
+//! seconds. Native writes go to a temporary file that is synced and renamed
+//! over the live one, which stays in place until then, so a crash
+//! mid-write never leaves a half-written or missing save.
 //!
 //! [`SavePlugin`](crate::SavePlugin) selects the appropriate backend at insert
 //! time based on `cfg(target_arch = "wasm32")`. Systems use the
 //! [`StorageBackend`] trait so they remain platform-agnostic.
 
 use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
 
 pub(crate) const SAVE_KEY: &str = "evergreen.save";
 
+/// Slot keys are this prefix followed by the slot number.
+const SLOT_KEY_PREFIX: &str = "evergreen.slot.";
+
+/// Number of slots offered by the slot picker.
+pub const SLOT_COUNT: u8 = 3;
+
+/// Previous saves kept per location, in addition to the live one.
+pub const BACKUP_COUNT: u8 = 3;
+
+/// A numbered save slot, `1..=SLOT_COUNT` for the player's own saves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SlotId(pub u8);
+
+impl SlotId {
+    /// Every slot the picker offers, in order.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (1..=SLOT_COUNT).map(Self)
+    }
+
+    /// Whether the picker offers this slot. Slots past [`SLOT_COUNT`] are
+    /// reserved for tooling and only loaded on request.
+    pub fn is_offered(self) -> bool {
+        (1..=SLOT_COUNT).contains(&self.0)
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        key.strip_prefix(SLOT_KEY_PREFIX)?.parse().ok().map(Self)
+    }
+}
+
+/// Which envelope a read or write targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveLocation {
+    /// The envelope shared by every slot (settings, keybinds).
+    Global,
+    /// One playthrough.
+    Slot(SlotId),
+}
+
+impl SaveLocation {
+    pub(crate) fn key(self) -> String {
+        match self {
+            Self::Global => SAVE_KEY.to_owned(),
+            Self::Slot(slot) => format!("{SLOT_KEY_PREFIX}{}", slot.0),
+        }
+    }
+
+    /// Key of the `generation`th backup, 1 being the newest.
+    pub(crate) fn backup_key(self, generation: u8) -> String {
+        format!("{}.bak{generation}", self.key())
+    }
+}
+
 /// Trait abstracting persistent storage so save systems can be platform-agnostic.
 pub trait StorageBackend: Resource {
-    /// Reads the current save blob, or `None` if no save exists or read failed.
-    fn read(&self) -> Option<String>;
-    /// Writes the save blob, logging any failure (does not return an error).
-    fn write(&self, content: &str);
+    /// Reads the save blob at `at`, or `None` if none exists or read failed.
+    fn read(&self, at: SaveLocation) -> Option<String>;
+    /// Reads the `generation`th backup of `at` (`1..=BACKUP_COUNT`, newest
+    /// first), or `None` if there is none.
+    fn read_backup(&self, at: SaveLocation, generation: u8) -> Option<String>;
+    /// Replaces the save blob at `at`. Logs any failure (does not return
+    /// an error).
+    fn write(&self, at: SaveLocation, content: &str);
+    /// Shifts the backups of `at` one generation older, dropping the
+    /// oldest, and stores `content` as the newest. Logs any failure.
+    fn write_backup(&self, at: SaveLocation, content: &str);
+    /// Slots that currently hold a save, in ascending order.
+    fn list_slots(&self) -> Vec<SlotId>;
+    /// Removes a slot's save and its backups. Deleting an empty slot is a
+    /// no-op.
+    fn delete_slot(&self, slot: SlotId);
+
+    /// Copies `from` over `to`. Returns `false` if `from` is empty.
+    fn copy_slot(&self, from: SlotId, to: SlotId) -> bool {
+        let Some(content) = self.read(SaveLocation::Slot(from)) else {
+            return false;
+        };
+        self.write(SaveLocation::Slot(to), &content);
+        true
+    }
 }
 
 // ---------------------------------------------------------------------------
 // WASM (browser localStorage)
 // ---------------------------------------------------------------------------
 
-/// Stores the save blob in browser `localStorage`.
+/// Stores the save blobs in browser `localStorage`.
 #[derive(Resource, Default)]
 pub struct WasmBackend;
 
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Result<web_sys::Storage, String> {
+    web_sys::window()
+        .ok_or_else(|| "no window".to_owned())?
+        .local_storage()
+        .map_err(|e| format!("{e:?}"))?
+        .ok_or_else(|| "no localStorage".to_owned())
+}
+
 #[cfg(target_arch = "wasm32")]
 impl StorageBackend for WasmBackend {
-    fn read(&self) -> Option<String> {
-        use web_sys::window;
-        window()?.local_storage().ok()??.get_item(SAVE_KEY).ok()?
+    fn read(&self, at: SaveLocation) -> Option<String> {
+        local_storage().ok()?.get_item(&at.key()).ok()?
     }
 
-    fn write(&self, content: &str) {
+    fn read_backup(&self, at: SaveLocation, generation: u8) -> Option<String> {
+        local_storage()
+            .ok()?
+            .get_item(&at.backup_key(generation))
+            .ok()?
+    }
+
+    fn write(&self, at: SaveLocation, content: &str) {
         use bevy::prelude::warn;
-        use wasm_bindgen::JsValue;
-        use web_sys::window;
-
-        let result = window()
-            .ok_or_else(|| "no window".to_owned())
-            .and_then(|w| {
-                w.local_storage()
-                    .map_err(|e| format!("{e:?}"))?
-                    .ok_or_else(|| "no localStorage".to_owned())
-            })
-            .and_then(|storage| {
-                storage
-                    .set_item(SAVE_KEY, content)
-                    .map_err(|e: JsValue| format!("{e:?}"))
-            });
+
+        // `setItem` replaces a key atomically.
+        let result = local_storage().and_then(|storage| {
+            storage
+                .set_item(&at.key(), content)
+                .map_err(|e| format!("{e:?}"))
+        });
 
         if let Err(e) = result {
             warn!("Failed to write save to localStorage: {e}");
         }
     }
+
+    fn write_backup(&self, at: SaveLocation, content: &str) {
+        use bevy::prelude::warn;
+
+        let result = local_storage().and_then(|storage| {
+            let set =
+                |key: &str, value: &str| storage.set_item(key, value).map_err(|e| format!("{e:?}"));
+            for generation in (1..BACKUP_COUNT).rev() {
+                if let Ok(Some(older)) = storage.get_item(&at.backup_key(generation)) {
+                    set(&at.backup_key(generation + 1), &older)?;
+                }
+            }
+            set(&at.backup_key(1), content)
+        });
+
+        if let Err(e) = result {
+            warn!("Failed to back up save in localStorage: {e}");
+        }
+    }
+
+    fn list_slots(&self) -> Vec<SlotId> {
+        let Ok(storage) = local_storage() else {
+            return Vec::new();
+        };
+        let len = storage.length().unwrap_or(0);
+        let mut slots: Vec<SlotId> = (0..len)
+            .filter_map(|i| storage.key(i).ok().flatten())
+            .filter_map(|key| SlotId::from_key(&key))
+            .collect();
+        slots.sort();
+        slots
+    }
+
+    fn delete_slot(&self, slot: SlotId) {
+        use bevy::prelude::warn;
+
+        let at = SaveLocation::Slot(slot);
+        let result = local_storage().and_then(|storage| {
+            std::iter::once(at.key())
+                .chain((1..=BACKUP_COUNT).map(|generation| at.backup_key(generation)))
+                .try_for_each(|key| storage.remove_item(&key).map_err(|e| format!("{e:?}")))
+        });
+
+        if let Err(e) = result {
+            warn!("Failed to delete save slot {}: {e}", slot.0);
+        }
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 impl StorageBackend for WasmBackend {
-    fn read(&self) -> Option<String> {
+    fn read(&self, _at: SaveLocation) -> Option<String> {
         None
     }
-    fn write(&self, _content: &str) {}
+    fn read_backup(&self, _at: SaveLocation, _generation: u8) -> Option<String> {
+        None
+    }
+    fn write(&self, _at: SaveLocation, _content: &str) {}
+    fn write_backup(&self, _at: SaveLocation, _content: &str) {}
+    fn list_slots(&self) -> Vec<SlotId> {
+        Vec::new()
+    }
+    fn delete_slot(&self, _slot: SlotId) {}
 }
 
 // ---------------------------------------------------------------------------
 // Native (filesystem)
 // ---------------------------------------------------------------------------
 
-/// Stores the save blob at `./evergreen_saves/evergreen.save.json`.
+/// Stores the save blobs as `<key>.json` files under `./evergreen_saves/`.
 #[derive(Resource, Default)]
 pub struct NativeBackend;
 
 #[cfg(not(target_arch = "wasm32"))]
-const NATIVE_SAVE_PATH: &str = "./evergreen_saves/evergreen.save.json";
+pub(crate) const NATIVE_SAVE_DIR: &str = "./evergreen_saves";
+
+#[cfg(not(target_arch = "wasm32"))]
+fn native_path(at: SaveLocation) -> std::path::PathBuf {
+    std::path::Path::new(NATIVE_SAVE_DIR).join(format!("{}.json", at.key()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn native_backup_path(at: SaveLocation, generation: u8) -> std::path::PathBuf {
+    std::path::Path::new(NATIVE_SAVE_DIR).join(format!("{}.json", at.backup_key(generation)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn native_temp_path(at: SaveLocation) -> std::path::PathBuf {
+    std::path::Path::new(NATIVE_SAVE_DIR).join(format!("{}.json.tmp", at.key()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn native_backup_temp_path(at: SaveLocation) -> std::path::PathBuf {
+    std::path::Path::new(NATIVE_SAVE_DIR).join(format!("{}.json.tmp", at.backup_key(1)))
+}
+
+/// Renames `from` over `to`, treating a missing `from` as nothing to move.
+#[cfg(not(target_arch = "wasm32"))]
+fn rename_if_exists(from: &std::path::Path, to: &std::path::Path) -> std::io::Result<()> {
+    match std::fs::rename(from, to) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+/// Writes `content` to a synced temporary file at `temp` and renames it
+/// over `to`. Whatever was at `to` is only ever replaced, never moved
+/// away, so a crash at any point still leaves a complete file there.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_atomically(
+    temp: &std::path::Path,
+    to: &std::path::Path,
+    content: &str,
+) -> std::io::Result<()> {
+    use std::io::Write;
+
+    std::fs::create_dir_all(NATIVE_SAVE_DIR)?;
+    let mut file = std::fs::File::create(temp)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+    drop(file);
+    std::fs::rename(temp, to)
+}
 
 #[cfg(not(target_arch = "wasm32"))]
 impl StorageBackend for NativeBackend {
-    fn read(&self) -> Option<String> {
-        std::fs::read_to_string(NATIVE_SAVE_PATH).ok()
+    fn read(&self, at: SaveLocation) -> Option<String> {
+        std::fs::read_to_string(native_path(at)).ok()
+    }
+
+    fn read_backup(&self, at: SaveLocation, generation: u8) -> Option<String> {
+        std::fs::read_to_string(native_backup_path(at, generation)).ok()
     }
 
-    fn write(&self, content: &str) {
+    fn write(&self, at: SaveLocation, content: &str) {
         use bevy::prelude::warn;
-        use std::path::Path;
-        let path = Path::new(NATIVE_SAVE_PATH);
-        let result = path
-            .parent()
-            .ok_or_else(|| "save path has no parent".to_owned())
-            .and_then(|parent| std::fs::create_dir_all(parent).map_err(|e| e.to_string()))
-            .and_then(|()| std::fs::write(path, content).map_err(|e| e.to_string()));
 
-        if let Err(e) = result {
+        if let Err(e) = write_atomically(&native_temp_path(at), &native_path(at), content) {
             warn!("Failed to write save file: {e}");
         }
     }
+
+    fn write_backup(&self, at: SaveLocation, content: &str) {
+        use bevy::prelude::warn;
+
+        let result = (1..BACKUP_COUNT)
+            .rev()
+            .try_for_each(|generation| {
+                rename_if_exists(
+                    &native_backup_path(at, generation),
+                    &native_backup_path(at, generation + 1),
+                )
+            })
+            .and_then(|()| {
+                write_atomically(
+                    &native_backup_temp_path(at),
+                    &native_backup_path(at, 1),
+                    content,
+                )
+            });
+        if let Err(e) = result {
+            warn!("Failed to back up save file: {e}");
+        }
+    }
+
+    fn list_slots(&self) -> Vec<SlotId> {
+        let Ok(entries) = std::fs::read_dir(NATIVE_SAVE_DIR) else {
+            return Vec::new();
+        };
+        let mut slots: Vec<SlotId> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                SlotId::from_key(name.strip_suffix(".json")?)
+            })
+            .collect();
+        slots.sort();
+        slots
+    }
+
+    fn delete_slot(&self, slot: SlotId) {
+        use bevy::prelude::warn;
+
+        let at = SaveLocation::Slot(slot);
+        let paths = [
+            native_path(at),
+            native_temp_path(at),
+            native_backup_temp_path(at),
+        ]
+        .into_iter()
+        .chain((1..=BACKUP_COUNT).map(|generation| native_backup_path(at, generation)));
+        for path in paths {
+            match std::fs::remove_file(path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => warn!("Failed to delete save slot {}: {e}", slot.0),
+            }
+        }
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
 impl StorageBackend for NativeBackend {
-    fn read(&self) -> Option<String> {
+    fn read(&self, _at: SaveLocation) -> Option<String> {
         None
     }
-    fn write(&self, _content: &str) {}
+    fn read_backup(&self, _at: SaveLocation, _generation: u8) -> Option<String> {
+        None
+    }
+    fn write(&self, _at: SaveLocation, _content: &str) {}
+    fn write_backup(&self, _at: SaveLocation, _content: &str) {}
+    fn list_slots(&self) -> Vec<SlotId> {
+        Vec::new()
+    }
+    fn delete_slot(&self, _slot: SlotId) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slot_keys_round_trip() {
+        let key = SaveLocation::Slot(SlotId(2)).key();
+        assert_eq!(key, "evergreen.slot.2");
+        assert_eq!(SlotId::from_key(&key), Some(SlotId(2)));
+        assert_eq!(SlotId::from_key(SAVE_KEY), None);
+        assert_eq!(SlotId::from_key("evergreen.slot.x"), None);
+    }
+
+    #[test]
+    fn backup_keys_are_not_slots() {
+        let backup = SaveLocation::Slot(SlotId(1)).backup_key(2);
+        assert_eq!(backup, "evergreen.slot.1.bak2");
+        assert_eq!(SlotId::from_key(&backup), None);
+    }
 }
@@ -0,0 +1,301 @@
+//! Moving a save between machines as a portable string.
+//!
+//! An export is the active slot's [sealed](SaveFile::seal) envelope,
+//! deflated and base64-encoded behind [`EXPORT_PREFIX`], so it survives
+//! being pasted into chat or attached to a bug report. Importing reverses
+//! that, checks the seal, rejects envelopes from a newer build and dry-runs
+//! every value through the registered [`Migrator`](crate::Migrator)s before
+//! the save is written to a free slot. Values are then migrated for real by
+//! the usual load path when the slot is picked.
+//!
+//! Where the string goes depends on the platform:
+//! - native: written to and read from `./evergreen_saves/export.txt`, so an
+//!   export copied from another machine can be dropped in place; the game
+//!   also accepts `--load-save <path>` to import and load a file at startup,
+//!   always into the same slot past the picker's, so bug-report saves never
+//!   take a player's slot or pile up
+//! - WASM: exported as a file download, imported by pasting it into a
+//!   browser prompt
+
+use base64::prelude::{Engine as _, BASE64_STANDARD};
+use bevy::prelude::*;
+
+use crate::file::{unix_now, SaveFile, SlotMeta, SAVE_VERSION};
+use crate::persistable::{back_up, write_envelope, Migrations};
+use crate::slots::{ActiveSlot, SlotIndex};
+use crate::storage::{SaveLocation, SlotId, StorageBackend};
+
+/// How every export string begins.
+pub const EXPORT_PREFIX: &str = "evergreen-save:";
+
+/// Upper bound on an inflated import, so a crafted string can't exhaust
+/// memory.
+const MAX_IMPORT_BYTES: usize = 16 * 1024 * 1024;
+
+/// Requests from the settings screen.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveTransfer {
+    /// Export the active slot.
+    Export,
+    /// Import a save into the first free slot.
+    Import,
+}
+
+/// Result of a [`SaveTransfer`], for the screen that asked.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum TransferOutcome {
+    /// The export was written; `to` says where.
+    Exported { to: String },
+    /// The save now occupies this slot.
+    Imported(SlotId),
+    /// Nothing changed; the reason is shown to the player.
+    Failed(String),
+}
+
+/// Encodes `envelope` as an export string.
+pub fn export_string(envelope: &SaveFile) -> Result<String, String> {
+    let sealed = envelope.seal().map_err(|e| e.to_string())?;
+    let compressed = miniz_oxide::deflate::compress_to_vec(sealed.as_bytes(), 9);
+    Ok(format!(
+        "{EXPORT_PREFIX}{}",
+        BASE64_STANDARD.encode(compressed)
+    ))
+}
+
+/// Decodes an export string, checking its seal and envelope version.
+/// Whitespace, e.g. from line wrapping in a chat client, is ignored.
+pub fn import_string(text: &str) -> Result<SaveFile, String> {
+    let text: String = text.split_whitespace().collect();
+    let encoded = text
+        .strip_prefix(EXPORT_PREFIX)
+        .ok_or_else(|| "not an Evergreen save".to_owned())?;
+    let compressed = BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|e| format!("damaged text: {e}"))?;
+    let sealed = miniz_oxide::inflate::decompress_to_vec_with_limit(&compressed, MAX_IMPORT_BYTES)
+        .map_err(|e| format!("damaged data: {e}"))?;
+    let sealed = String::from_utf8(sealed).map_err(|e| format!("damaged data: {e}"))?;
+    let envelope = SaveFile::unseal(&sealed)?;
+    if envelope.version > SAVE_VERSION {
+        return Err(format!(
+            "made by a newer version of the game (save format {}, this build reads {SAVE_VERSION})",
+            envelope.version
+        ));
+    }
+    Ok(envelope)
+}
+
+/// Slot `--load-save` overwrites on every run. It is past the slots the
+/// picker offers, so "Continue" never resumes it; it is only loaded by the
+/// [`SlotCommand::Load`](crate::SlotCommand::Load) sent at startup.
+#[cfg(not(target_arch = "wasm32"))]
+const LOAD_SAVE_SLOT: SlotId = SlotId(crate::storage::SLOT_COUNT + 1);
+
+/// Vets `text` and writes it to the first free slot.
+fn import_to_free_slot<B: StorageBackend>(
+    text: &str,
+    backend: &B,
+    index: &mut SlotIndex,
+    migrations: &Migrations,
+) -> Result<SlotId, String> {
+    let slot = index
+        .first_free()
+        .ok_or_else(|| "every save slot is in use; delete one first".to_owned())?;
+    import_into(text, slot, backend, index, migrations)?;
+    Ok(slot)
+}
+
+/// Vets `text` and writes it to `slot`, replacing whatever is there.
+fn import_into<B: StorageBackend>(
+    text: &str,
+    slot: SlotId,
+    backend: &B,
+    index: &mut SlotIndex,
+    migrations: &Migrations,
+) -> Result<(), String> {
+    let mut envelope = import_string(text)?;
+    migrations.check(&envelope)?;
+
+    // Older envelopes are slot contents without a header, as on adoption.
+    envelope.version = SAVE_VERSION;
+    let meta = envelope.meta.get_or_insert_with(|| SlotMeta {
+        created: unix_now(),
+        ..SlotMeta::default()
+    });
+    meta.updated = unix_now();
+    let meta = meta.clone();
+
+    back_up(backend, SaveLocation::Slot(slot));
+    write_envelope(backend, SaveLocation::Slot(slot), &envelope);
+    index.upsert(slot, meta);
+    Ok(())
+}
+
+pub(crate) fn handle_save_transfers<B: StorageBackend>(
+    mut requests: MessageReader<SaveTransfer>,
+    mut outcomes: MessageWriter<TransferOutcome>,
+    save: Res<SaveFile>,
+    active: Res<ActiveSlot>,
+    mut index: ResMut<SlotIndex>,
+    migrations: Res<Migrations>,
+    backend: Res<B>,
+) {
+    for request in requests.read() {
+        let outcome = match request {
+            SaveTransfer::Export => active
+                .0
+                .ok_or_else(|| "there is no save to export yet".to_owned())
+                .and_then(|slot| {
+                    let text = export_string(&save)?;
+                    platform::deliver_export(slot, &text)
+                })
+                .map(|to| TransferOutcome::Exported { to }),
+            SaveTransfer::Import => platform::fetch_import()
+                .and_then(|text| import_to_free_slot(&text, &*backend, &mut index, &migrations))
+                .map(TransferOutcome::Imported),
+        };
+        let outcome = outcome.unwrap_or_else(|e| {
+            warn!("Save {request:?} failed: {e}");
+            TransferOutcome::Failed(e)
+        });
+        outcomes.write(outcome);
+    }
+}
+
+/// Imports and loads the file named by `--load-save <path>`, for
+/// reproducing bug reports. The file goes to [`LOAD_SAVE_SLOT`], replacing
+/// the previous run's import.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn load_save_from_args<B: StorageBackend>(
+    mut slot_commands: MessageWriter<crate::SlotCommand>,
+    mut index: ResMut<SlotIndex>,
+    migrations: Res<Migrations>,
+    backend: Res<B>,
+) {
+    let mut args = std::env::args().skip_while(|arg| arg != "--load-save");
+    if args.next().is_none() {
+        return;
+    }
+    let Some(path) = args.next() else {
+        warn!("--load-save needs a path");
+        return;
+    };
+    let slot = LOAD_SAVE_SLOT;
+    let imported = std::fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| import_into(&text, slot, &*backend, &mut index, &migrations));
+    match imported {
+        Ok(()) => {
+            info!("Imported '{path}' into slot {}.", slot.0);
+            slot_commands.write(crate::SlotCommand::Load(slot));
+        }
+        Err(e) => warn!("Could not load save '{path}': {e}"),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod platform {
+    use crate::storage::{SlotId, NATIVE_SAVE_DIR};
+
+    fn transfer_path() -> std::path::PathBuf {
+        std::path::Path::new(NATIVE_SAVE_DIR).join("export.txt")
+    }
+
+    pub(super) fn deliver_export(_slot: SlotId, text: &str) -> Result<String, String> {
+        let path = transfer_path();
+        std::fs::create_dir_all(NATIVE_SAVE_DIR)
+            .and_then(|()| std::fs::write(&path, text))
+            .map_err(|e| e.to_string())?;
+        Ok(path.display().to_string())
+    }
+
+    pub(super) fn fetch_import() -> Result<String, String> {
+        let path = transfer_path();
+        std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    use wasm_bindgen::{JsCast, JsValue};
+
+    use crate::storage::SlotId;
+
+    fn js_error(e: JsValue) -> String {
+        format!("{e:?}")
+    }
+
+    /// Offers the export as a text file download.
+    pub(super) fn deliver_export(slot: SlotId, text: &str) -> Result<String, String> {
+        let window = web_sys::window().ok_or_else(|| "no window".to_owned())?;
+        let document = window.document().ok_or_else(|| "no document".to_owned())?;
+
+        let parts = web_sys::js_sys::Array::of1(&JsValue::from_str(text));
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type("text/plain");
+        let blob =
+            web_sys::Blob::new_with_str_sequence_and_options(&parts, &options).map_err(js_error)?;
+        let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(js_error)?;
+
+        let file_name = format!("evergreen-slot-{}.txt", slot.0);
+        let anchor: web_sys::HtmlAnchorElement = document
+            .create_element("a")
+            .map_err(js_error)?
+            .dyn_into()
+            .map_err(|_| "could not create a download link".to_owned())?;
+        anchor.set_href(&url);
+        anchor.set_download(&file_name);
+        anchor.click();
+        web_sys::Url::revoke_object_url(&url).map_err(js_error)?;
+        Ok(file_name)
+    }
+
+    /// Asks the player to paste an export.
+    pub(super) fn fetch_import() -> Result<String, String> {
+        web_sys::window()
+            .ok_or_else(|| "no window".to_owned())?
+            .prompt_with_message("Paste an exported Evergreen save:")
+            .map_err(js_error)?
+            .filter(|text| !text.trim().is_empty())
+            .ok_or_else(|| "import cancelled".to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> SaveFile {
+        let mut file = SaveFile::new_slot();
+        file.slots
+            .insert("alignment".to_owned(), serde_json::json!({ "cities": 12 }));
+        file
+    }
+
+    #[test]
+    fn exports_round_trip() {
+        let text = export_string(&sample()).unwrap_or_default();
+        assert!(text.starts_with(EXPORT_PREFIX));
+
+        // Line wrapping picked up on the way doesn't matter.
+        let (head, tail) = text.split_at(text.len() / 2);
+        let imported = import_string(&format!("  {head}\n{tail}\n"));
+        assert_eq!(
+            imported
+                .ok()
+                .and_then(|file| file.slots.get("alignment").cloned()),
+            Some(serde_json::json!({ "cities": 12 }))
+        );
+    }
+
+    #[test]
+    fn rejects_foreign_damaged_and_newer_saves() {
+        assert!(import_string("hello").is_err());
+        assert!(import_string(&format!("{EXPORT_PREFIX}AAAA")).is_err());
+
+        let mut newer = sample();
+        newer.version = SAVE_VERSION + 1;
+        let text = export_string(&newer).unwrap_or_default();
+        assert!(import_string(&text).is_err_and(|e| e.contains("newer version")));
+    }
+}
@@ -1,6 +1,10 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
+use bevy::ui::ComputedNode;
 use dialog::events::{ChoiceMade, ChoicesReady, DialogueLineReady};
-use dialog::locale::LocaleMap;
+use dialog::locale::{LocaleKey, LocaleMap};
+use dialog::message::LocaleArgs;
+use dialog::runner::{BacklogEntry, DialogueRunner};
 use keybinds::action::Action;
 use keybinds::bindings::Keybinds;
 
@@ -31,6 +35,15 @@ const CHOICE_PADDING_V_PX: f32 = 8.0;
 const CHOICE_MARGIN_TOP_PX: f32 = 4.0;
 const CHOICE_RADIUS_PX: f32 = 4.0;
 
+const BACKLOG_TOP_PX: f32 = 32.0;
+const BACKLOG_GAP_PX: f32 = 16.0;
+const BACKLOG_TITLE_FONT_SIZE_PX: f32 = 14.0;
+const BACKLOG_TITLE_MARGIN_BOTTOM_PX: f32 = 8.0;
+const BACKLOG_ENTRY_FONT_SIZE_PX: f32 = 15.0;
+const BACKLOG_ENTRY_MARGIN_BOTTOM_PX: f32 = 6.0;
+/// Pixels scrolled per wheel notch or arrow-key press.
+const BACKLOG_SCROLL_STEP_PX: f32 = 40.0;
+
 // ---------------------------------------------------------------------------
 // Components
 // ---------------------------------------------------------------------------
@@ -54,6 +67,18 @@ pub(crate) struct DialogChoicesContainer;
 #[derive(Component)]
 pub(crate) struct ChoiceButton(usize);
 
+/// Scrollable history of the conversation, toggled with
+/// [`Action::DialogBacklog`]. Hidden (`Display::None`) until opened.
+#[derive(Component)]
+pub(crate) struct DialogBacklogPanel;
+
+#[derive(Component)]
+pub(crate) struct DialogBacklogList;
+
+/// One rendered [`BacklogEntry`].
+#[derive(Component)]
+pub(crate) struct DialogBacklogEntry;
+
 /// Tracks the keyboard-selected choice index. Reset when choices change.
 #[derive(Resource, Default)]
 pub(crate) struct SelectedChoice {
@@ -135,6 +160,53 @@ pub fn setup(mut commands: Commands, fonts: Res<UiFont>) {
                 },
             ));
         });
+
+    commands
+        .spawn((
+            DialogBox,
+            DialogBacklogPanel,
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                top: Val::Px(BACKLOG_TOP_PX),
+                bottom: Val::Px(BOX_BOTTOM_PX + BOX_HEIGHT_PX + BACKLOG_GAP_PX),
+                left: Val::Percent((100.0 - BOX_WIDTH_PERCENT) / 2.0),
+                width: Val::Percent(BOX_WIDTH_PERCENT),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::axes(Val::Px(BOX_PADDING_H_PX), Val::Px(BOX_PADDING_V_PX)),
+                border: UiRect::all(Val::Px(BOX_BORDER_PX)),
+                border_radius: BorderRadius::all(Val::Px(BOX_RADIUS_PX)),
+                ..Node::default()
+            },
+            BackgroundColor(theme::DIALOG_BG),
+            BorderColor::all(theme::DIALOG_BORDER),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                LocaleKey::new("ui.dialog.backlog.title"),
+                Text::default(),
+                TextColor(theme::DIALOG_SPEAKER),
+                TextFont {
+                    font: fonts.0.clone(),
+                    font_size: BACKLOG_TITLE_FONT_SIZE_PX,
+                    ..default()
+                },
+                Node {
+                    margin: UiRect::bottom(Val::Px(BACKLOG_TITLE_MARGIN_BOTTOM_PX)),
+                    ..Node::default()
+                },
+            ));
+            parent.spawn((
+                DialogBacklogList,
+                ScrollPosition::default(),
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    flex_grow: 1.0,
+                    overflow: Overflow::scroll_y(),
+                    ..Node::default()
+                },
+            ));
+        });
 }
 
 // ---------------------------------------------------------------------------
@@ -389,6 +461,141 @@ pub fn handle_choice_keyboard(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Backlog
+// ---------------------------------------------------------------------------
+
+/// Run condition: the backlog panel is showing.
+pub(crate) fn backlog_open(panel_q: Query<&Node, With<DialogBacklogPanel>>) -> bool {
+    panel_q.iter().any(|node| node.display != Display::None)
+}
+
+/// Show or hide the backlog panel. It opens scrolled to the latest line.
+pub fn toggle_backlog(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<Keybinds>,
+    mut panel_q: Query<&mut Node, With<DialogBacklogPanel>>,
+    mut list_q: Query<&mut ScrollPosition, With<DialogBacklogList>>,
+) {
+    if !keyboard.just_pressed(bindings.key(Action::DialogBacklog)) {
+        return;
+    }
+    for mut node in &mut panel_q {
+        node.display = if node.display == Display::None {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    // Layout clamps the offset to the bottom of the content; scrolling
+    // clamps it back into range.
+    for mut scroll in &mut list_q {
+        scroll.0.y = f32::MAX;
+    }
+}
+
+/// Rebuild the backlog list when the conversation grows or the language
+/// changes.
+pub fn sync_backlog(
+    mut commands: Commands,
+    runner: Res<DialogueRunner>,
+    locale: Res<LocaleMap>,
+    fonts: Res<UiFont>,
+    mut list_q: Query<(Entity, &mut ScrollPosition), With<DialogBacklogList>>,
+    entry_q: Query<Entity, With<DialogBacklogEntry>>,
+) {
+    let backlog = runner.backlog();
+    if entry_q.iter().count() == backlog.len() && !locale.is_changed() {
+        return;
+    }
+    let Ok((list, mut scroll)) = list_q.single_mut() else {
+        return;
+    };
+    for entity in &entry_q {
+        commands.entity(entity).despawn();
+    }
+
+    for entry in backlog {
+        let (text, color) = match entry {
+            BacklogEntry::Line {
+                speaker_key: Some(speaker_key),
+                text_key,
+            } => {
+                let args = LocaleArgs::new()
+                    .with("speaker", locale.get(speaker_key))
+                    .with("line", locale.get(text_key));
+                (
+                    locale.format("ui.dialog.backlog.line", &args),
+                    theme::DIALOG_TEXT,
+                )
+            }
+            BacklogEntry::Line {
+                speaker_key: None,
+                text_key,
+            } => (locale.get(text_key).to_owned(), theme::DIALOG_TEXT),
+            BacklogEntry::Choice { text_key } => {
+                let args = LocaleArgs::new().with("choice", locale.get(text_key));
+                (
+                    locale.format("ui.dialog.backlog.choice", &args),
+                    theme::DIALOG_SPEAKER,
+                )
+            }
+        };
+        commands.spawn((
+            DialogBacklogEntry,
+            Text::new(text),
+            TextColor(color),
+            TextFont {
+                font: fonts.0.clone(),
+                font_size: BACKLOG_ENTRY_FONT_SIZE_PX,
+                ..default()
+            },
+            Node {
+                margin: UiRect::bottom(Val::Px(BACKLOG_ENTRY_MARGIN_BOTTOM_PX)),
+                ..Node::default()
+            },
+            ChildOf(list),
+        ));
+    }
+    scroll.0.y = f32::MAX;
+}
+
+/// Scroll the open backlog with the mouse wheel or Up/Down.
+pub fn scroll_backlog(
+    mut wheel: MessageReader<MouseWheel>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<Keybinds>,
+    mut list_q: Query<(&mut ScrollPosition, &ComputedNode), With<DialogBacklogList>>,
+) {
+    let mut delta: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => -event.y * BACKLOG_SCROLL_STEP_PX,
+            MouseScrollUnit::Pixel => -event.y,
+        })
+        .sum();
+    if keyboard.just_pressed(bindings.key(Action::MoveUp))
+        || keyboard.just_pressed(KeyCode::ArrowUp)
+    {
+        delta -= BACKLOG_SCROLL_STEP_PX;
+    }
+    if keyboard.just_pressed(bindings.key(Action::MoveDown))
+        || keyboard.just_pressed(KeyCode::ArrowDown)
+    {
+        delta += BACKLOG_SCROLL_STEP_PX;
+    }
+    if delta.abs() < f32::EPSILON {
+        return;
+    }
+    for (mut scroll, computed) in &mut list_q {
+        // The offset may still be the "stick to bottom" sentinel.
+        let max = ((computed.content_size().y - computed.size().y)
+            * computed.inverse_scale_factor())
+        .max(0.0);
+        scroll.0.y = (scroll.0.y.min(max) + delta).clamp(0.0, max);
+    }
+}
+
 pub struct DialogBoxScreen;
 
 impl crate::screen::ScreenSetup for DialogBoxScreen {
@@ -406,7 +613,10 @@ impl crate::screen::ScreenSetup for DialogBoxScreen {
                     on_line_ready,
                     on_choices_ready,
                     handle_choice_interaction,
-                    handle_choice_keyboard,
+                    handle_choice_keyboard.run_if(not(backlog_open)),
+                    toggle_backlog,
+                    sync_backlog,
+                    scroll_backlog.run_if(backlog_open),
                 )
                     .run_if(in_state(GameState::Dialogue)),
             );
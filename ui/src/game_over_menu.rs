@@ -1,21 +1,101 @@
 use bevy::prelude::*;
 use dialog::locale::LocaleMap;
+use level::checkpoint::RespawnRequested;
+use models::game_states::GameState;
+
+use crate::fonts::UiFont;
+use crate::theme;
+use crate::widgets::ButtonBuilder;
+
+const TITLE_FONT_SIZE_PX: f32 = 48.0;
+const TITLE_MARGIN_BOTTOM_PX: f32 = 32.0;
 
 #[derive(Component)]
 pub struct GameOverMenu;
 
-pub fn setup(mut commands: Commands, locale: Res<LocaleMap>) {
+/// Gets the player back up at the current map's checkpoint.
+#[derive(Component)]
+pub(crate) struct RespawnButton;
+
+#[derive(Component)]
+pub(crate) struct GameOverQuitButton;
+
+pub fn setup(mut commands: Commands, fonts: Res<UiFont>, locale: Res<LocaleMap>) {
+    let root = commands
+        .spawn((
+            GameOverMenu,
+            Node {
+                position_type: PositionType::Absolute,
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..Node::default()
+            },
+            BackgroundColor(theme::OVERLAY),
+        ))
+        .id();
+
     commands.spawn((
-        GameOverMenu,
         Text::new(locale.get("ui.game_over.title").to_string()),
+        TextColor(theme::TITLE),
+        TextFont {
+            font: fonts.0.clone(),
+            font_size: TITLE_FONT_SIZE_PX,
+            ..default()
+        },
         Node {
-            position_type: PositionType::Absolute,
-            display: Display::Flex,
-            justify_content: JustifyContent::Center,
-            align_content: AlignContent::Center,
+            margin: UiRect::bottom(Val::Px(TITLE_MARGIN_BOTTOM_PX)),
             ..Node::default()
         },
+        ChildOf(root),
     ));
+
+    ButtonBuilder::new("", RespawnButton, fonts.0.clone())
+        .locale_key("ui.game_over.continue")
+        .spawn(&mut commands, root);
+    ButtonBuilder::new("", GameOverQuitButton, fonts.0.clone())
+        .locale_key("ui.game_over.quit_to_menu")
+        .spawn(&mut commands, root);
+}
+
+/// Respawn keeps the current map: `RespawnRequested` tells
+/// `regenerate_world` to skip regeneration on the way back into `Playing`.
+#[allow(clippy::type_complexity)]
+pub fn handle_respawn(
+    mut q: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<RespawnButton>)>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut respawn: ResMut<RespawnRequested>,
+) {
+    for (interaction, mut bg) in &mut q {
+        match interaction {
+            Interaction::Pressed => {
+                respawn.0 = true;
+                next_state.set(GameState::Playing);
+            }
+            Interaction::Hovered => *bg = BackgroundColor(theme::DIALOG_CHOICE_HOVER),
+            Interaction::None => *bg = BackgroundColor(theme::BUTTON_BG),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn handle_quit_to_menu(
+    mut q: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<GameOverQuitButton>),
+    >,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, mut bg) in &mut q {
+        match interaction {
+            Interaction::Pressed => next_state.set(GameState::MainMenu),
+            Interaction::Hovered => *bg = BackgroundColor(theme::DIALOG_CHOICE_HOVER),
+            Interaction::None => *bg = BackgroundColor(theme::BUTTON_BG),
+        }
+    }
 }
 
 pub struct GameOverScreen;
@@ -28,6 +108,10 @@ impl crate::screen::ScreenSetup for GameOverScreen {
             .add_systems(
                 OnExit(GameState::GameOver),
                 crate::despawn::despawn_all::<GameOverMenu>,
+            )
+            .add_systems(
+                Update,
+                (handle_respawn, handle_quit_to_menu).run_if(in_state(GameState::GameOver)),
             );
     }
 }
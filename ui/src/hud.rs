@@ -2,8 +2,9 @@ use bevy::math::Rot2;
 use bevy::prelude::*;
 use bevy::ui::UiTransform;
 use models::alignment::{AlignmentFaction, PlayerAlignment};
-use models::health::Health;
+use models::health::{Health, MaxHealth};
 use models::palette;
+use models::player::Player;
 use std::f32::consts::TAU;
 
 use crate::fonts::UiFont;
@@ -130,18 +131,31 @@ pub fn setup_alignment_bars(
 // Systems
 // ---------------------------------------------------------------------------
 
+/// Light one petal per point of `Health`, fade the ones lost to damage,
+/// and hide any beyond the player's `MaxHealth`.
+#[allow(clippy::type_complexity)]
 pub fn sync_petals(
-    health_query: Query<&Health, Changed<Health>>,
-    petal_query: Query<(Entity, &RosePetal)>,
-    mut commands: Commands,
+    health_query: Query<
+        (&Health, &MaxHealth),
+        (With<Player>, Or<(Changed<Health>, Changed<MaxHealth>)>),
+    >,
+    mut petal_query: Query<(&RosePetal, &mut ImageNode, &mut Visibility)>,
 ) {
-    let Ok(health) = health_query.single() else {
+    let Ok((health, max)) = health_query.single() else {
         return;
     };
-    petal_query
-        .iter()
-        .filter(|(_, petal)| petal.0 > health.0)
-        .for_each(|(entity, _)| commands.entity(entity).despawn());
+    for (petal, mut image, mut visibility) in &mut petal_query {
+        *visibility = if petal.0 > max.0 {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        image.color = if petal.0 > health.0 {
+            palette::PETAL_MISSING
+        } else {
+            palette::OPAQUE_WHITE
+        };
+    }
 }
 
 pub fn sync_alignment_bars(
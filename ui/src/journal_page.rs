@@ -0,0 +1,444 @@
+use bevy::prelude::*;
+use dialog::locale::LocaleMap;
+use dialog::message::LocaleArgs;
+use dialog::quest::{QuestDefinition, QuestEntry, QuestLog};
+use models::game_states::GameState;
+
+use crate::fonts::UiFont;
+use crate::theme;
+
+// ---------------------------------------------------------------------------
+// Layout constants
+// ---------------------------------------------------------------------------
+
+const PAGE_PADDING_PX: f32 = 40.0;
+const TITLE_FONT_SIZE_PX: f32 = 36.0;
+const TITLE_MARGIN_BOTTOM_PX: f32 = 24.0;
+
+const SIDEBAR_WIDTH_PX: f32 = 240.0;
+const SIDEBAR_GAP_PX: f32 = 16.0;
+
+const SECTION_FONT_SIZE_PX: f32 = 14.0;
+const SECTION_MARGIN_V_PX: f32 = 6.0;
+
+const QUEST_FONT_SIZE_PX: f32 = 16.0;
+const QUEST_PADDING_H_PX: f32 = 14.0;
+const QUEST_PADDING_V_PX: f32 = 8.0;
+const QUEST_MARGIN_PX: f32 = 3.0;
+const QUEST_RADIUS_PX: f32 = 4.0;
+
+const DETAIL_TITLE_FONT_SIZE_PX: f32 = 20.0;
+const DETAIL_TEXT_FONT_SIZE_PX: f32 = 14.0;
+const DETAIL_MARGIN_BOTTOM_PX: f32 = 12.0;
+
+const STAGE_PADDING_PX: f32 = 12.0;
+const STAGE_MARGIN_BOTTOM_PX: f32 = 12.0;
+const STAGE_RADIUS_PX: f32 = 6.0;
+const STAGE_BORDER_PX: f32 = 1.0;
+const OBJECTIVE_MARGIN_TOP_PX: f32 = 4.0;
+
+const EMPTY_FONT_SIZE_PX: f32 = 16.0;
+
+const BACK_FONT_SIZE_PX: f32 = 18.0;
+const BACK_PADDING_H_PX: f32 = 24.0;
+const BACK_PADDING_V_PX: f32 = 10.0;
+const BACK_MARGIN_TOP_PX: f32 = 16.0;
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+#[derive(Component)]
+pub struct JournalPage;
+
+#[derive(Component)]
+pub(crate) struct JournalQuestButton(String);
+
+#[derive(Component)]
+pub(crate) struct JournalBackButton;
+
+/// Marker for the quest details panel (right side).
+#[derive(Component)]
+pub(crate) struct JournalContentPanel;
+
+/// Marker for dynamically spawned content elements.
+#[derive(Component)]
+pub(crate) struct JournalContentItem;
+
+// ---------------------------------------------------------------------------
+// Setup / teardown
+// ---------------------------------------------------------------------------
+
+pub fn setup(
+    mut commands: Commands,
+    log: Res<QuestLog>,
+    quests: Res<Assets<QuestDefinition>>,
+    locale: Res<LocaleMap>,
+    fonts: Res<UiFont>,
+) {
+    let root = commands
+        .spawn((
+            JournalPage,
+            Node {
+                position_type: PositionType::Absolute,
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(PAGE_PADDING_PX)),
+                ..Node::default()
+            },
+            BackgroundColor(theme::DARK_BG),
+        ))
+        .id();
+
+    // Title
+    commands.spawn((
+        Text::new(locale.get("ui.journal.title").to_string()),
+        TextColor(theme::TITLE),
+        TextFont {
+            font: fonts.0.clone(),
+            font_size: TITLE_FONT_SIZE_PX,
+            ..default()
+        },
+        Node {
+            margin: UiRect::bottom(Val::Px(TITLE_MARGIN_BOTTOM_PX)),
+            ..Node::default()
+        },
+        ChildOf(root),
+    ));
+
+    // Main content area: quest list | details
+    let body = commands
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                flex_grow: 1.0,
+                column_gap: Val::Px(SIDEBAR_GAP_PX),
+                overflow: Overflow::clip(),
+                ..Node::default()
+            },
+            ChildOf(root),
+        ))
+        .id();
+
+    // Quest list, active quests first
+    let sidebar = commands
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                width: Val::Px(SIDEBAR_WIDTH_PX),
+                overflow: Overflow::scroll_y(),
+                ..Node::default()
+            },
+            ChildOf(body),
+        ))
+        .id();
+
+    let sections = [
+        ("ui.journal.active", log.active().collect::<Vec<_>>()),
+        ("ui.journal.completed", log.completed().collect()),
+    ];
+    for (heading_key, entries) in &sections {
+        if entries.is_empty() {
+            continue;
+        }
+        commands.spawn((
+            Text::new(locale.get(heading_key).to_string()),
+            TextColor(theme::TITLE),
+            TextFont {
+                font: fonts.0.clone(),
+                font_size: SECTION_FONT_SIZE_PX,
+                ..default()
+            },
+            Node {
+                margin: UiRect::vertical(Val::Px(SECTION_MARGIN_V_PX)),
+                ..Node::default()
+            },
+            ChildOf(sidebar),
+        ));
+        for entry in entries {
+            let label = find_quest(&quests, &entry.id)
+                .map_or(entry.id.as_str(), |quest| locale.get(&quest.title_key))
+                .to_string();
+            commands
+                .spawn((
+                    JournalQuestButton(entry.id.clone()),
+                    Button,
+                    Node {
+                        padding: UiRect::axes(
+                            Val::Px(QUEST_PADDING_H_PX),
+                            Val::Px(QUEST_PADDING_V_PX),
+                        ),
+                        margin: UiRect::bottom(Val::Px(QUEST_MARGIN_PX)),
+                        border_radius: BorderRadius::all(Val::Px(QUEST_RADIUS_PX)),
+                        ..Node::default()
+                    },
+                    BackgroundColor(theme::BUTTON_BG),
+                    ChildOf(sidebar),
+                ))
+                .with_child((
+                    Text::new(label),
+                    TextColor(theme::BUTTON_TEXT),
+                    TextFont {
+                        font: fonts.0.clone(),
+                        font_size: QUEST_FONT_SIZE_PX,
+                        ..default()
+                    },
+                ));
+        }
+    }
+
+    // Details panel (populated when a quest is selected)
+    let content = commands
+        .spawn((
+            JournalContentPanel,
+            Node {
+                flex_direction: FlexDirection::Column,
+                flex_grow: 1.0,
+                overflow: Overflow::scroll_y(),
+                ..Node::default()
+            },
+            ChildOf(body),
+        ))
+        .id();
+
+    let prompt_key = if log.quests.is_empty() {
+        "ui.journal.empty"
+    } else {
+        "ui.journal.select_quest"
+    };
+    commands.spawn((
+        JournalContentItem,
+        Text::new(locale.get(prompt_key).to_string()),
+        TextColor(theme::BUTTON_TEXT),
+        TextFont {
+            font: fonts.0.clone(),
+            font_size: EMPTY_FONT_SIZE_PX,
+            ..default()
+        },
+        ChildOf(content),
+    ));
+
+    // Back button
+    crate::widgets::ButtonBuilder::new(
+        locale.get("ui.journal.back").to_string(),
+        JournalBackButton,
+        fonts.0.clone(),
+    )
+    .padding(BACK_PADDING_H_PX, BACK_PADDING_V_PX)
+    .font_size(BACK_FONT_SIZE_PX)
+    .margin(BACK_MARGIN_TOP_PX, 0.0)
+    .spawn(&mut commands, root);
+}
+
+pub fn teardown(mut commands: Commands, query: Query<Entity, With<JournalPage>>) {
+    query
+        .iter()
+        .for_each(|entity| commands.entity(entity).despawn());
+}
+
+fn find_quest<'a>(quests: &'a Assets<QuestDefinition>, id: &str) -> Option<&'a QuestDefinition> {
+    quests
+        .iter()
+        .map(|(_, quest)| quest)
+        .find(|quest| quest.id == id)
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+pub fn handle_back_button(
+    interaction_q: Query<&Interaction, (Changed<Interaction>, With<JournalBackButton>)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    interaction_q
+        .iter()
+        .filter(|i| **i == Interaction::Pressed)
+        .for_each(|_| next_state.set(GameState::MainMenu));
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_quest_buttons(
+    mut interaction_q: Query<
+        (&Interaction, &JournalQuestButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    log: Res<QuestLog>,
+    quests: Res<Assets<QuestDefinition>>,
+    locale: Res<LocaleMap>,
+    fonts: Res<UiFont>,
+    content_panel_q: Query<Entity, With<JournalContentPanel>>,
+    content_item_q: Query<Entity, With<JournalContentItem>>,
+    mut commands: Commands,
+) {
+    let mut selected = None;
+
+    for (interaction, button, mut bg) in &mut interaction_q {
+        match interaction {
+            Interaction::Pressed => {
+                selected = Some(button.0.clone());
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(theme::DIALOG_CHOICE_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(theme::BUTTON_BG);
+            }
+        }
+    }
+
+    let Some(id) = selected else { return };
+    let (Some(entry), Some(quest)) = (log.get(&id), find_quest(&quests, &id)) else {
+        return;
+    };
+
+    // Clear details panel
+    for entity in &content_item_q {
+        commands.entity(entity).despawn();
+    }
+    let Ok(content) = content_panel_q.single() else {
+        return;
+    };
+
+    let text = |value: String, color: Color, font_size: f32| {
+        (
+            Text::new(value),
+            TextColor(color),
+            TextFont {
+                font: fonts.0.clone(),
+                font_size,
+                ..default()
+            },
+        )
+    };
+
+    // Quest title and summary
+    commands.spawn((
+        JournalContentItem,
+        text(
+            locale.get(&quest.title_key).to_string(),
+            theme::TITLE,
+            DETAIL_TITLE_FONT_SIZE_PX,
+        ),
+        Node {
+            margin: UiRect::bottom(Val::Px(DETAIL_MARGIN_BOTTOM_PX)),
+            ..Node::default()
+        },
+        ChildOf(content),
+    ));
+    commands.spawn((
+        JournalContentItem,
+        text(
+            locale.get(&quest.summary_key).to_string(),
+            theme::DIALOG_TEXT,
+            DETAIL_TEXT_FONT_SIZE_PX,
+        ),
+        Node {
+            margin: UiRect::bottom(Val::Px(DETAIL_MARGIN_BOTTOM_PX)),
+            ..Node::default()
+        },
+        ChildOf(content),
+    ));
+
+    // Stages reached so far, newest first
+    for (index, stage) in quest.stages.iter().enumerate().rev() {
+        let current = !entry.completed && index == entry.stage;
+        if !entry.completed && index > entry.stage {
+            continue;
+        }
+        let card = commands
+            .spawn((
+                JournalContentItem,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(STAGE_PADDING_PX)),
+                    margin: UiRect::bottom(Val::Px(STAGE_MARGIN_BOTTOM_PX)),
+                    border: UiRect::all(Val::Px(STAGE_BORDER_PX)),
+                    border_radius: BorderRadius::all(Val::Px(STAGE_RADIUS_PX)),
+                    ..Node::default()
+                },
+                BackgroundColor(theme::DIALOG_CHOICE_BG),
+                BorderColor::all(theme::DIALOG_BORDER),
+                ChildOf(content),
+            ))
+            .id();
+        let stage_color = if current {
+            theme::DIALOG_TEXT
+        } else {
+            theme::BUTTON_TEXT
+        };
+        commands.spawn((
+            text(
+                locale.get(&stage.text_key).to_string(),
+                stage_color,
+                DETAIL_TEXT_FONT_SIZE_PX,
+            ),
+            ChildOf(card),
+        ));
+        for (i, objective) in stage.objectives.iter().enumerate() {
+            let target = objective.goal.target();
+            let count = objective_count(entry, current, i, target);
+            let args = LocaleArgs::new()
+                .with("objective", locale.get(&objective.text_key))
+                .with("done", count >= target)
+                .with("count", count)
+                .with("target", target);
+            let key = if target > 1 {
+                "ui.journal.objective.count"
+            } else {
+                "ui.journal.objective"
+            };
+            commands.spawn((
+                text(
+                    locale.format(key, &args),
+                    theme::DIALOG_SPEAKER,
+                    DETAIL_TEXT_FONT_SIZE_PX,
+                ),
+                Node {
+                    margin: UiRect::top(Val::Px(OBJECTIVE_MARGIN_TOP_PX)),
+                    ..Node::default()
+                },
+                ChildOf(card),
+            ));
+        }
+    }
+
+    if entry.completed {
+        commands.spawn((
+            JournalContentItem,
+            text(
+                locale.get("ui.journal.quest_done").to_string(),
+                theme::TITLE,
+                DETAIL_TEXT_FONT_SIZE_PX,
+            ),
+            ChildOf(content),
+        ));
+    }
+}
+
+/// Progress shown for objective `index` of a stage. Only the current
+/// stage keeps counts; earlier stages were finished in full.
+fn objective_count(entry: &QuestEntry, current: bool, index: usize, target: u32) -> u32 {
+    if current {
+        entry.progress.get(index).copied().unwrap_or(0)
+    } else {
+        target
+    }
+}
+
+pub struct JournalScreen;
+
+impl crate::screen::ScreenSetup for JournalScreen {
+    fn register(app: &mut bevy::prelude::App) {
+        use bevy::prelude::*;
+        use models::game_states::GameState;
+        app.add_systems(OnEnter(GameState::Journal), setup)
+            .add_systems(OnExit(GameState::Journal), teardown)
+            .add_systems(
+                Update,
+                (handle_back_button, handle_quest_buttons).run_if(in_state(GameState::Journal)),
+            );
+    }
+}
@@ -6,6 +6,7 @@ mod focus;
 pub mod fonts;
 mod game_over_menu;
 mod hud;
+mod journal_page;
 mod keybind_screen;
 mod level_complete;
 mod lore_page;
@@ -15,6 +16,7 @@ mod pause_menu;
 pub mod plugin;
 pub mod screen;
 mod settings_screen;
+mod slot_picker;
 pub mod theme;
 pub mod widgets;
 pub mod window;
@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 use models::game_states::GameState;
+use save::{SlotCommand, SlotId, SlotIndex};
 
 use crate::fonts::UiFont;
 use crate::settings_screen::SettingsOrigin;
+use crate::slot_picker::SlotPickerMode;
 use crate::theme;
 use crate::widgets::ButtonBuilder;
 
@@ -21,19 +23,33 @@ const COG_BORDER_RADIUS_PX: f32 = 8.0;
 #[derive(Component)]
 pub struct MainMenu;
 
+#[derive(Component)]
+pub(crate) struct ContinueButton;
+
 #[derive(Component)]
 pub(crate) struct StartButton;
 
+#[derive(Component)]
+pub(crate) struct LoadButton;
+
 #[derive(Component)]
 pub(crate) struct LoreButton;
 
+#[derive(Component)]
+pub(crate) struct JournalButton;
+
 #[derive(Component)]
 pub(crate) struct CreditsButton;
 
 #[derive(Component)]
 pub(crate) struct MainMenuSettingsButton;
 
-pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>, fonts: Res<UiFont>) {
+pub fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    fonts: Res<UiFont>,
+    slots: Res<SlotIndex>,
+) {
     let root = commands
         .spawn((
             MainMenu,
@@ -90,16 +106,36 @@ pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>, fonts: Res<
         ChildOf(root),
     ));
 
+    // Continue and Load only make sense once something has been saved.
+    if !slots.is_empty() {
+        ButtonBuilder::new("", ContinueButton, fonts.0.clone())
+            .locale_key("ui.main_menu.continue")
+            .padding(MENU_BUTTON_PADDING_H_PX, MENU_BUTTON_PADDING_V_PX)
+            .font_size(MENU_BUTTON_FONT_SIZE_PX)
+            .spawn(&mut commands, root);
+    }
     ButtonBuilder::new("", StartButton, fonts.0.clone())
         .locale_key("ui.main_menu.start")
         .padding(MENU_BUTTON_PADDING_H_PX, MENU_BUTTON_PADDING_V_PX)
         .font_size(MENU_BUTTON_FONT_SIZE_PX)
         .spawn(&mut commands, root);
+    if !slots.is_empty() {
+        ButtonBuilder::new("", LoadButton, fonts.0.clone())
+            .locale_key("ui.main_menu.load")
+            .padding(MENU_BUTTON_PADDING_H_PX, MENU_BUTTON_PADDING_V_PX)
+            .font_size(MENU_BUTTON_FONT_SIZE_PX)
+            .spawn(&mut commands, root);
+    }
     ButtonBuilder::new("", LoreButton, fonts.0.clone())
         .locale_key("ui.main_menu.lore")
         .padding(MENU_BUTTON_PADDING_H_PX, MENU_BUTTON_PADDING_V_PX)
         .font_size(MENU_BUTTON_FONT_SIZE_PX)
         .spawn(&mut commands, root);
+    ButtonBuilder::new("", JournalButton, fonts.0.clone())
+        .locale_key("ui.main_menu.journal")
+        .padding(MENU_BUTTON_PADDING_H_PX, MENU_BUTTON_PADDING_V_PX)
+        .font_size(MENU_BUTTON_FONT_SIZE_PX)
+        .spawn(&mut commands, root);
     ButtonBuilder::new("", CreditsButton, fonts.0.clone())
         .locale_key("ui.main_menu.credits")
         .padding(MENU_BUTTON_PADDING_H_PX, MENU_BUTTON_PADDING_V_PX)
@@ -107,24 +143,63 @@ pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>, fonts: Res<
         .spawn(&mut commands, root);
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn button_system(
     mut next_state: ResMut<NextState<GameState>>,
     mut origin: ResMut<SettingsOrigin>,
+    mut picker_mode: ResMut<SlotPickerMode>,
+    mut slot_requests: MessageWriter<SlotCommand>,
+    slots: Res<SlotIndex>,
+    continue_q: Query<&Interaction, (Changed<Interaction>, With<ContinueButton>)>,
     start_q: Query<&Interaction, (Changed<Interaction>, With<StartButton>)>,
+    load_q: Query<&Interaction, (Changed<Interaction>, With<LoadButton>)>,
     lore_q: Query<&Interaction, (Changed<Interaction>, With<LoreButton>)>,
+    journal_q: Query<&Interaction, (Changed<Interaction>, With<JournalButton>)>,
     credits_q: Query<&Interaction, (Changed<Interaction>, With<CreditsButton>)>,
     settings_q: Query<&Interaction, (Changed<Interaction>, With<MainMenuSettingsButton>)>,
 ) {
+    continue_q
+        .iter()
+        .filter(|i| **i == Interaction::Pressed)
+        .for_each(|_| {
+            if let Some(slot) = slots.most_recent() {
+                slot_requests.write(SlotCommand::Load(slot));
+                next_state.set(GameState::Playing);
+            }
+        });
+
+    // With nothing saved yet there is no choice to make: start in slot 1.
     start_q
         .iter()
         .filter(|i| **i == Interaction::Pressed)
-        .for_each(|_| next_state.set(GameState::Playing));
+        .for_each(|_| {
+            if slots.is_empty() {
+                slot_requests.write(SlotCommand::NewGame(SlotId(1)));
+                next_state.set(GameState::Playing);
+            } else {
+                *picker_mode = SlotPickerMode::NewGame;
+                next_state.set(GameState::SlotPicker);
+            }
+        });
+
+    load_q
+        .iter()
+        .filter(|i| **i == Interaction::Pressed)
+        .for_each(|_| {
+            *picker_mode = SlotPickerMode::Load;
+            next_state.set(GameState::SlotPicker);
+        });
 
     lore_q
         .iter()
         .filter(|i| **i == Interaction::Pressed)
         .for_each(|_| next_state.set(GameState::LorePage));
 
+    journal_q
+        .iter()
+        .filter(|i| **i == Interaction::Pressed)
+        .for_each(|_| next_state.set(GameState::Journal));
+
     credits_q
         .iter()
         .filter(|i| **i == Interaction::Pressed)
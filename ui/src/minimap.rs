@@ -2,7 +2,7 @@
 //!
 //! Layout follows web-design conventions:
 //!   - rounded panel with a subtle drop shadow + 1px accent border
-//!   - small caption header (`MAP n · ALN x`)
+//!   - small caption header (`MAP #n · ALN x`, from `ui.minimap.*` keys)
 //!   - 5x5 grid of square cells with thin connectors
 //!   - icon legend at the bottom (NPC / Enemy / Portal)
 //!
@@ -10,6 +10,8 @@
 
 use bevy::math::IVec2;
 use bevy::prelude::*;
+use dialog::locale::LocaleKey;
+use dialog::message::LocaleArgs;
 use level::area::{AreaEvent, Direction};
 use level::world::WorldMap;
 use models::palette;
@@ -150,7 +152,8 @@ pub fn setup(
         ))
         .id();
     commands.spawn((
-        Text::new("MAP"),
+        Text::new(""),
+        LocaleKey::new("ui.minimap.title"),
         TextFont {
             font: fonts.0.clone(),
             font_size: tw::TEXT_XS_PX,
@@ -161,7 +164,8 @@ pub fn setup(
     ));
     commands.spawn((
         MinimapHeader,
-        Text::new(header_caption(&world)),
+        Text::new(""),
+        LocaleKey::with_args("ui.minimap.caption", header_args(&world)),
         TextFont {
             font: fonts.0.clone(),
             font_size: tw::TEXT_XS_PX,
@@ -226,7 +230,7 @@ pub fn refresh(
     world: Res<WorldMap>,
     grid_q: Query<Entity, With<MinimapGrid>>,
     cell_q: Query<Entity, With<MinimapCell>>,
-    mut header_q: Query<&mut Text, With<MinimapHeader>>,
+    mut header_q: Query<&mut LocaleKey, With<MinimapHeader>>,
 ) {
     if !world.is_changed() {
         return;
@@ -239,8 +243,8 @@ pub fn refresh(
 
     build_grid_cells(grid, &asset_server, &world, &mut commands);
 
-    if let Ok(mut text) = header_q.single_mut() {
-        *text = Text::new(header_caption(&world));
+    if let Ok(mut key) = header_q.single_mut() {
+        key.args = header_args(&world);
     }
 }
 
@@ -248,8 +252,11 @@ pub fn refresh(
 // Internals
 // ---------------------------------------------------------------------------
 
-fn header_caption(world: &WorldMap) -> String {
-    format!("#{:02} · ALN {:>3}", world.id.0, world.alignment)
+/// Numbers are pre-padded so the caption doesn't jitter as they change.
+fn header_args(world: &WorldMap) -> LocaleArgs {
+    LocaleArgs::new()
+        .with("map", format!("{:02}", world.id.0))
+        .with("alignment", format!("{:>3}", world.alignment))
 }
 
 fn build_grid_cells(
@@ -354,6 +361,10 @@ fn spawn_cell_marker(
     }
 
     if matches!(area.event, AreaEvent::Enemy { .. }) {
+        // A fully purified area has nothing left to fight.
+        if world.is_cleansed(area_pos) {
+            return;
+        }
         commands.spawn((
             MinimapCell,
             Node {
@@ -393,7 +404,7 @@ fn npc_dot_color(event: &AreaEvent, area_pos: IVec2) -> Option<Color> {
 fn connector_rect(dir: Direction, cell_left: u16, cell_top: u16) -> Option<(u16, u16, u16, u16)> {
     let half_cell = CELL_PX / 2;
     let lat_offset = (CELL_PX - CONNECTOR_THICKNESS_PX) / 2;
-    Some(match dir {
+    (half_cell > 0).then_some(match dir {
         Direction::North => (
             cell_left + lat_offset,
             cell_top.checked_sub(GAP_PX)?,
@@ -419,7 +430,6 @@ fn connector_rect(dir: Direction, cell_left: u16, cell_top: u16) -> Option<(u16,
             CONNECTOR_THICKNESS_PX,
         ),
     })
-    .filter(|_| half_cell > 0)
 }
 
 fn spawn_legend(
@@ -446,7 +456,7 @@ fn spawn_legend(
         asset_server,
         fonts,
         LegendIcon::Sprite(PORTAL_ICON_PATH),
-        "Portal",
+        "ui.minimap.legend.portal",
     );
     spawn_legend_row(
         legend,
@@ -454,7 +464,7 @@ fn spawn_legend(
         asset_server,
         fonts,
         LegendIcon::Sprite(ENEMY_ICON_PATH),
-        "Enemy",
+        "ui.minimap.legend.enemy",
     );
     spawn_legend_row(
         legend,
@@ -462,7 +472,7 @@ fn spawn_legend(
         asset_server,
         fonts,
         LegendIcon::Dot(tw::AMBER_400),
-        "NPC",
+        "ui.minimap.legend.npc",
     );
 }
 
@@ -477,7 +487,7 @@ fn spawn_legend_row(
     asset_server: &AssetServer,
     fonts: &UiFont,
     icon: LegendIcon,
-    label: &str,
+    label_key: &str,
 ) {
     let row = commands
         .spawn((
@@ -517,7 +527,8 @@ fn spawn_legend_row(
         }
     }
     commands.spawn((
-        Text::new(label.to_string()),
+        Text::new(""),
+        LocaleKey::new(label_key),
         TextFont {
             font: fonts.0.clone(),
             font_size: tw::TEXT_XS_PX,
@@ -6,6 +6,7 @@ use crate::focus;
 use crate::fonts;
 use crate::game_over_menu::GameOverScreen;
 use crate::hud::HudScreen;
+use crate::journal_page::JournalScreen;
 use crate::keybind_screen::KeybindScreenSetup;
 use crate::level_complete::LevelCompleteSetup;
 use crate::lore_page::LoreScreen;
@@ -13,6 +14,7 @@ use crate::main_menu::MainMenuScreen;
 use crate::pause_menu::{PauseScreen, QuitToMenuRequested};
 use crate::screen::ScreenSetup;
 use crate::settings_screen::SettingsScreenSetup;
+use crate::slot_picker::SlotPickerScreen;
 
 pub struct UiPlugin;
 
@@ -33,6 +35,8 @@ impl Plugin for UiPlugin {
         PauseScreen::register(app);
         DialogBoxScreen::register(app);
         LoreScreen::register(app);
+        JournalScreen::register(app);
+        SlotPickerScreen::register(app);
         SettingsScreenSetup::register(app);
         CreditsScreenSetup::register(app);
         KeybindScreenSetup::register(app);
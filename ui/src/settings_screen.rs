@@ -1,8 +1,9 @@
 use bevy::prelude::*;
 use bevy::window::{MonitorSelection, WindowMode};
-use dialog::locale::AVAILABLE_LOCALES;
+use dialog::locale::LocaleCatalog;
 use models::game_states::GameState;
 use models::settings::GameSettings;
+use save::{SaveTransfer, TransferOutcome};
 
 use crate::fonts::UiFont;
 use crate::theme;
@@ -31,6 +32,10 @@ const STEP_BTN_SIZE_PX: f32 = 32.0;
 const STEP_BTN_FONT_SIZE_PX: f32 = 18.0;
 const STEP_BTN_RADIUS_PX: f32 = 4.0;
 
+const STATUS_FONT_SIZE_PX: f32 = 14.0;
+const STATUS_MARGIN_RIGHT_PX: f32 = 12.0;
+const TRANSFER_BTN_GAP_PX: f32 = 6.0;
+
 const NAV_FONT_SIZE_PX: f32 = 18.0;
 const NAV_PADDING_H_PX: f32 = 24.0;
 const NAV_PADDING_V_PX: f32 = 10.0;
@@ -78,6 +83,16 @@ pub(crate) enum LangButton {
 #[derive(Component)]
 pub(crate) struct LangDisplay;
 
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransferButton {
+    Export,
+    Import,
+}
+
+/// Result of the last export or import.
+#[derive(Component)]
+pub(crate) struct TransferStatus;
+
 #[derive(Component)]
 pub(crate) struct KeybindsNavButton;
 
@@ -99,7 +114,12 @@ pub enum SettingsOrigin {
 // Setup
 // ---------------------------------------------------------------------------
 
-pub fn setup(mut commands: Commands, settings: Res<GameSettings>, fonts: Res<UiFont>) {
+pub fn setup(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    catalog: Res<LocaleCatalog>,
+    fonts: Res<UiFont>,
+) {
     let root = commands
         .spawn((
             SettingsScreen,
@@ -168,7 +188,15 @@ pub fn setup(mut commands: Commands, settings: Res<GameSettings>, fonts: Res<UiF
     spawn_fullscreen_row(&mut commands, root, settings.fullscreen, font.clone());
 
     spawn_section_header(&mut commands, root, "LANGUAGE", font.clone());
-    spawn_lang_row(&mut commands, root, &settings.language, font.clone());
+    spawn_lang_row(
+        &mut commands,
+        root,
+        catalog.display_name(&settings.language),
+        font.clone(),
+    );
+
+    spawn_section_header(&mut commands, root, "SAVE DATA", font.clone());
+    spawn_transfer_row(&mut commands, root, font.clone());
 
     // Bottom nav row
     let nav = commands
@@ -301,13 +329,52 @@ pub fn handle_back(
     }
 }
 
+pub fn handle_transfer_buttons(
+    mut q: Query<(&Interaction, &mut BackgroundColor, &TransferButton), Changed<Interaction>>,
+    mut requests: MessageWriter<SaveTransfer>,
+) {
+    for (interaction, mut bg, btn) in &mut q {
+        match interaction {
+            Interaction::Pressed => {
+                requests.write(match btn {
+                    TransferButton::Export => SaveTransfer::Export,
+                    TransferButton::Import => SaveTransfer::Import,
+                });
+            }
+            Interaction::Hovered => *bg = BackgroundColor(theme::DIALOG_CHOICE_HOVER),
+            Interaction::None => *bg = BackgroundColor(theme::DIALOG_CHOICE_BG),
+        }
+    }
+}
+
+/// Reports the outcome of an export or import under the buttons.
+pub fn show_transfer_outcome(
+    mut outcomes: MessageReader<TransferOutcome>,
+    mut status_q: Query<&mut Text, With<TransferStatus>>,
+) {
+    let Some(outcome) = outcomes.read().last() else {
+        return;
+    };
+    let message = match outcome {
+        TransferOutcome::Exported { to } => format!("Exported to {to}"),
+        TransferOutcome::Imported(slot) => format!("Imported into slot {}", slot.0),
+        TransferOutcome::Failed(reason) => format!("Failed: {reason}"),
+    };
+    for mut text in &mut status_q {
+        **text = message.clone();
+    }
+}
+
 pub fn handle_lang_buttons(
     mut q: Query<(&Interaction, &mut BackgroundColor, &LangButton), Changed<Interaction>>,
     mut settings: ResMut<GameSettings>,
+    catalog: Res<LocaleCatalog>,
 ) {
     for (interaction, mut bg, btn) in &mut q {
         match interaction {
-            Interaction::Pressed => cycle_language(&mut settings, *btn == LangButton::Next),
+            Interaction::Pressed => {
+                cycle_language(&mut settings, &catalog, *btn == LangButton::Next);
+            }
             Interaction::Hovered => *bg = BackgroundColor(theme::DIALOG_CHOICE_HOVER),
             Interaction::None => *bg = BackgroundColor(theme::DIALOG_CHOICE_BG),
         }
@@ -318,6 +385,7 @@ pub fn handle_lang_buttons(
 #[allow(clippy::type_complexity)]
 pub fn sync_displays(
     settings: Res<GameSettings>,
+    catalog: Res<LocaleCatalog>,
     mut vol_q: Query<(&mut Text, &VolumeDisplay)>,
     mut fs_q: Query<&mut Text, (With<FullscreenDisplay>, Without<VolumeDisplay>)>,
     mut lang_q: Query<
@@ -329,7 +397,7 @@ pub fn sync_displays(
         ),
     >,
 ) {
-    if !settings.is_changed() {
+    if !settings.is_changed() && !catalog.is_changed() {
         return;
     }
     for (mut text, display) in &mut vol_q {
@@ -344,7 +412,7 @@ pub fn sync_displays(
         **text = if settings.fullscreen { "On" } else { "Off" }.to_owned();
     }
     for mut text in &mut lang_q {
-        **text = lang_display_name(&settings.language).to_owned();
+        **text = catalog.display_name(&settings.language).to_owned();
     }
 }
 
@@ -366,29 +434,27 @@ pub fn apply_fullscreen(settings: Res<GameSettings>, mut window_q: Query<&mut Wi
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn cycle_language(settings: &mut GameSettings, forward: bool) {
-    let current = AVAILABLE_LOCALES
+fn cycle_language(settings: &mut GameSettings, catalog: &LocaleCatalog, forward: bool) {
+    let available = catalog.available();
+    let count = available.len();
+    if count == 0 {
+        return;
+    }
+    let current = available
         .iter()
         .position(|(code, _)| *code == settings.language)
         .unwrap_or(0);
-    let count = AVAILABLE_LOCALES.len();
     let next = if forward {
         (current + 1) % count
     } else {
         current.checked_sub(1).unwrap_or(count - 1)
     };
-    settings.language = AVAILABLE_LOCALES[next].0.to_owned();
-}
-
-fn lang_display_name(code: &str) -> &str {
-    AVAILABLE_LOCALES
-        .iter()
-        .find(|(c, _)| *c == code)
-        .map(|(_, name)| *name)
-        .unwrap_or(code)
+    if let Some((code, _)) = available.get(next) {
+        settings.language = (*code).to_owned();
+    }
 }
 
-fn spawn_lang_row(commands: &mut Commands, parent: Entity, current_code: &str, font: Handle<Font>) {
+fn spawn_lang_row(commands: &mut Commands, parent: Entity, current_name: &str, font: Handle<Font>) {
     let row = spawn_row(commands, parent);
     commands.spawn((
         Text::new("Language"),
@@ -407,7 +473,7 @@ fn spawn_lang_row(commands: &mut Commands, parent: Entity, current_code: &str, f
     spawn_step_btn(commands, row, LangButton::Prev, "<", font.clone());
     commands.spawn((
         LangDisplay,
-        Text::new(lang_display_name(current_code)),
+        Text::new(current_name),
         TextColor(theme::DIALOG_SPEAKER),
         TextFont {
             font: font.clone(),
@@ -559,6 +625,69 @@ fn spawn_fullscreen_row(
         ));
 }
 
+fn spawn_transfer_row(commands: &mut Commands, parent: Entity, font: Handle<Font>) {
+    let row = spawn_row(commands, parent);
+    commands.spawn((
+        Text::new("Portable save"),
+        TextColor(theme::DIALOG_TEXT),
+        TextFont {
+            font: font.clone(),
+            font_size: LABEL_FONT_SIZE_PX,
+            ..default()
+        },
+        Node {
+            flex_grow: 1.0,
+            ..Node::default()
+        },
+        ChildOf(row),
+    ));
+    commands.spawn((
+        TransferStatus,
+        Text::new(""),
+        TextColor(theme::DIALOG_SPEAKER),
+        TextFont {
+            font: font.clone(),
+            font_size: STATUS_FONT_SIZE_PX,
+            ..default()
+        },
+        Node {
+            margin: UiRect::right(Val::Px(STATUS_MARGIN_RIGHT_PX)),
+            ..Node::default()
+        },
+        ChildOf(row),
+    ));
+    for (marker, label) in [
+        (TransferButton::Export, "Export"),
+        (TransferButton::Import, "Import"),
+    ] {
+        commands
+            .spawn((
+                marker,
+                Button,
+                Node {
+                    min_width: Val::Px(STEP_BTN_SIZE_PX * 2.0),
+                    padding: UiRect::axes(Val::Px(STEP_BTN_SIZE_PX * 0.5), Val::ZERO),
+                    margin: UiRect::left(Val::Px(TRANSFER_BTN_GAP_PX)),
+                    border_radius: BorderRadius::all(Val::Px(STEP_BTN_RADIUS_PX)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Node::default()
+                },
+                BackgroundColor(theme::DIALOG_CHOICE_BG),
+                ChildOf(row),
+            ))
+            .with_child((
+                Text::new(label),
+                TextColor(theme::DIALOG_TEXT),
+                TextFont {
+                    font: font.clone(),
+                    font_size: VALUE_FONT_SIZE_PX,
+                    ..default()
+                },
+            ));
+    }
+}
+
 fn spawn_row(commands: &mut Commands, parent: Entity) -> Entity {
     commands
         .spawn((
@@ -648,6 +777,8 @@ impl crate::screen::ScreenSetup for SettingsScreenSetup {
                     handle_keybinds_nav,
                     handle_reset,
                     handle_back,
+                    handle_transfer_buttons,
+                    show_transfer_outcome,
                     sync_displays,
                 )
                     .run_if(in_state(GameState::Settings)),
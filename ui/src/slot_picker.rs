@@ -0,0 +1,400 @@
+use bevy::prelude::*;
+use dialog::locale::LocaleMap;
+use dialog::message::LocaleArgs;
+use models::alignment::AlignmentFaction;
+use models::game_states::GameState;
+use save::{calendar_date, SlotCommand, SlotId, SlotIndex, SlotMeta};
+
+use crate::fonts::UiFont;
+use crate::theme;
+use crate::widgets::ButtonBuilder;
+
+// ---------------------------------------------------------------------------
+// Layout constants
+// ---------------------------------------------------------------------------
+
+const PAGE_PADDING_PX: f32 = 40.0;
+const TITLE_FONT_SIZE_PX: f32 = 36.0;
+const TITLE_MARGIN_BOTTOM_PX: f32 = 24.0;
+
+const LIST_WIDTH_PX: f32 = 560.0;
+const ROW_GAP_PX: f32 = 10.0;
+const ROW_MARGIN_BOTTOM_PX: f32 = 10.0;
+
+const SLOT_PADDING_H_PX: f32 = 16.0;
+const SLOT_PADDING_V_PX: f32 = 10.0;
+const SLOT_RADIUS_PX: f32 = 6.0;
+const SLOT_NAME_FONT_SIZE_PX: f32 = 18.0;
+const SLOT_DETAIL_FONT_SIZE_PX: f32 = 13.0;
+const SLOT_DETAIL_MARGIN_TOP_PX: f32 = 4.0;
+
+const ROW_BUTTON_FONT_SIZE_PX: f32 = 14.0;
+const ROW_BUTTON_PADDING_H_PX: f32 = 12.0;
+const ROW_BUTTON_PADDING_V_PX: f32 = 8.0;
+
+const BACK_FONT_SIZE_PX: f32 = 18.0;
+const BACK_PADDING_H_PX: f32 = 24.0;
+const BACK_PADDING_V_PX: f32 = 10.0;
+const BACK_MARGIN_TOP_PX: f32 = 16.0;
+
+const SECS_PER_HOUR: u64 = 3600;
+const SECS_PER_MINUTE: u64 = 60;
+
+// ---------------------------------------------------------------------------
+// Resources / components
+// ---------------------------------------------------------------------------
+
+/// What picking a slot does. Set by the main menu before switching to
+/// [`GameState::SlotPicker`].
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SlotPickerMode {
+    /// Resume an existing save.
+    #[default]
+    Load,
+    /// Start a fresh game, overwriting the slot if it is taken.
+    NewGame,
+}
+
+/// Destructive actions wait for a second press on the same button.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PendingConfirm(Option<(SlotId, ConfirmAction)>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConfirmAction {
+    Overwrite,
+    Delete,
+}
+
+#[derive(Component)]
+pub struct SlotPickerPage;
+
+/// Container the slot rows are rebuilt into.
+#[derive(Component)]
+pub(crate) struct SlotList;
+
+/// Marker for dynamically spawned rows.
+#[derive(Component)]
+pub(crate) struct SlotRow;
+
+#[derive(Component)]
+pub(crate) struct SlotButton(SlotId);
+
+#[derive(Component)]
+pub(crate) struct SlotCopyButton(SlotId);
+
+#[derive(Component)]
+pub(crate) struct SlotDeleteButton(SlotId);
+
+#[derive(Component)]
+pub(crate) struct SlotPickerBackButton;
+
+// ---------------------------------------------------------------------------
+// Setup / teardown
+// ---------------------------------------------------------------------------
+
+pub fn setup(
+    mut commands: Commands,
+    mode: Res<SlotPickerMode>,
+    mut confirm: ResMut<PendingConfirm>,
+    locale: Res<LocaleMap>,
+    fonts: Res<UiFont>,
+) {
+    *confirm = PendingConfirm::default();
+
+    let root = commands
+        .spawn((
+            SlotPickerPage,
+            Node {
+                position_type: PositionType::Absolute,
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(PAGE_PADDING_PX)),
+                ..Node::default()
+            },
+            BackgroundColor(theme::DARK_BG),
+        ))
+        .id();
+
+    let title_key = match *mode {
+        SlotPickerMode::Load => "ui.slots.title.load",
+        SlotPickerMode::NewGame => "ui.slots.title.new",
+    };
+    commands.spawn((
+        Text::new(locale.get(title_key).to_string()),
+        TextColor(theme::TITLE),
+        TextFont {
+            font: fonts.0.clone(),
+            font_size: TITLE_FONT_SIZE_PX,
+            ..default()
+        },
+        Node {
+            margin: UiRect::bottom(Val::Px(TITLE_MARGIN_BOTTOM_PX)),
+            ..Node::default()
+        },
+        ChildOf(root),
+    ));
+
+    // Rows are filled in by `rebuild_slot_list` once the list exists.
+    commands.spawn((
+        SlotList,
+        Node {
+            flex_direction: FlexDirection::Column,
+            width: Val::Px(LIST_WIDTH_PX),
+            ..Node::default()
+        },
+        ChildOf(root),
+    ));
+
+    ButtonBuilder::new(
+        locale.get("ui.slots.back").to_string(),
+        SlotPickerBackButton,
+        fonts.0.clone(),
+    )
+    .padding(BACK_PADDING_H_PX, BACK_PADDING_V_PX)
+    .font_size(BACK_FONT_SIZE_PX)
+    .margin(BACK_MARGIN_TOP_PX, 0.0)
+    .spawn(&mut commands, root);
+}
+
+pub fn teardown(mut commands: Commands, query: Query<Entity, With<SlotPickerPage>>) {
+    query
+        .iter()
+        .for_each(|entity| commands.entity(entity).despawn());
+}
+
+/// Respawns one row per slot whenever the list first appears, the index
+/// changes (copy, delete) or a confirmation is armed.
+#[allow(clippy::too_many_arguments)]
+pub fn rebuild_slot_list(
+    mut commands: Commands,
+    list_q: Query<(Entity, Ref<SlotList>)>,
+    row_q: Query<Entity, With<SlotRow>>,
+    index: Res<SlotIndex>,
+    mode: Res<SlotPickerMode>,
+    confirm: Res<PendingConfirm>,
+    locale: Res<LocaleMap>,
+    fonts: Res<UiFont>,
+) {
+    let Ok((list, list_ref)) = list_q.single() else {
+        return;
+    };
+    if !list_ref.is_added() && !index.is_changed() && !confirm.is_changed() {
+        return;
+    }
+    for entity in &row_q {
+        commands.entity(entity).despawn();
+    }
+
+    let text = |value: String, color: Color, font_size: f32| {
+        (
+            Text::new(value),
+            TextColor(color),
+            TextFont {
+                font: fonts.0.clone(),
+                font_size,
+                ..default()
+            },
+        )
+    };
+
+    for slot in SlotId::all() {
+        let meta = index.get(slot);
+        let row = commands
+            .spawn((
+                SlotRow,
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(ROW_GAP_PX),
+                    margin: UiRect::bottom(Val::Px(ROW_MARGIN_BOTTOM_PX)),
+                    ..Node::default()
+                },
+                ChildOf(list),
+            ))
+            .id();
+
+        // Empty slots can't be loaded, so they get no `Button`.
+        let pickable = meta.is_some() || *mode == SlotPickerMode::NewGame;
+        let card = commands
+            .spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    flex_grow: 1.0,
+                    padding: UiRect::axes(Val::Px(SLOT_PADDING_H_PX), Val::Px(SLOT_PADDING_V_PX)),
+                    border_radius: BorderRadius::all(Val::Px(SLOT_RADIUS_PX)),
+                    ..Node::default()
+                },
+                BackgroundColor(theme::BUTTON_BG),
+                ChildOf(row),
+            ))
+            .id();
+        if pickable {
+            commands.entity(card).insert((SlotButton(slot), Button));
+        }
+
+        let name = locale.format("ui.slots.name", &LocaleArgs::new().with("slot", slot.0));
+        commands.spawn((
+            text(name, theme::BUTTON_TEXT, SLOT_NAME_FONT_SIZE_PX),
+            ChildOf(card),
+        ));
+        let detail = if confirm.0 == Some((slot, ConfirmAction::Overwrite)) {
+            locale.get("ui.slots.confirm_overwrite").to_string()
+        } else {
+            meta.map_or_else(
+                || locale.get("ui.slots.empty").to_string(),
+                |meta| slot_details(&locale, meta),
+            )
+        };
+        commands.spawn((
+            text(detail, theme::DIALOG_SPEAKER, SLOT_DETAIL_FONT_SIZE_PX),
+            Node {
+                margin: UiRect::top(Val::Px(SLOT_DETAIL_MARGIN_TOP_PX)),
+                ..Node::default()
+            },
+            ChildOf(card),
+        ));
+
+        if meta.is_none() {
+            continue;
+        }
+        if index.first_free().is_some() {
+            ButtonBuilder::new(
+                locale.get("ui.slots.copy").to_string(),
+                SlotCopyButton(slot),
+                fonts.0.clone(),
+            )
+            .padding(ROW_BUTTON_PADDING_H_PX, ROW_BUTTON_PADDING_V_PX)
+            .font_size(ROW_BUTTON_FONT_SIZE_PX)
+            .margin(0.0, 0.0)
+            .spawn(&mut commands, row);
+        }
+        let delete_key = if confirm.0 == Some((slot, ConfirmAction::Delete)) {
+            "ui.slots.confirm_delete"
+        } else {
+            "ui.slots.delete"
+        };
+        ButtonBuilder::new(
+            locale.get(delete_key).to_string(),
+            SlotDeleteButton(slot),
+            fonts.0.clone(),
+        )
+        .padding(ROW_BUTTON_PADDING_H_PX, ROW_BUTTON_PADDING_V_PX)
+        .font_size(ROW_BUTTON_FONT_SIZE_PX)
+        .margin(0.0, 0.0)
+        .spawn(&mut commands, row);
+    }
+}
+
+/// One-line summary of a slot: faction, progress, playtime and last played.
+fn slot_details(locale: &LocaleMap, meta: &SlotMeta) -> String {
+    #[allow(clippy::as_conversions)]
+    let playtime = meta.playtime_secs.max(0.0) as u64;
+    let (year, month, day) = calendar_date(meta.updated);
+    let faction = meta.dominant.map_or("", AlignmentFaction::as_str);
+    let args = LocaleArgs::new()
+        .with("faction", faction)
+        .with("maps", meta.maps_traversed)
+        .with("hours", playtime / SECS_PER_HOUR)
+        .with("minutes", playtime % SECS_PER_HOUR / SECS_PER_MINUTE)
+        .with("date", format!("{year:04}-{month:02}-{day:02}"));
+    locale.format("ui.slots.details", &args)
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+pub fn handle_slot_buttons(
+    mut interaction_q: Query<
+        (&Interaction, &SlotButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    index: Res<SlotIndex>,
+    mode: Res<SlotPickerMode>,
+    mut confirm: ResMut<PendingConfirm>,
+    mut requests: MessageWriter<SlotCommand>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, button, mut bg) in &mut interaction_q {
+        match interaction {
+            Interaction::Pressed => {
+                let slot = button.0;
+                let request = match *mode {
+                    SlotPickerMode::Load => SlotCommand::Load(slot),
+                    SlotPickerMode::NewGame => {
+                        let armed = confirm.0 == Some((slot, ConfirmAction::Overwrite));
+                        if index.get(slot).is_some() && !armed {
+                            confirm.0 = Some((slot, ConfirmAction::Overwrite));
+                            continue;
+                        }
+                        SlotCommand::NewGame(slot)
+                    }
+                };
+                requests.write(request);
+                next_state.set(GameState::Playing);
+            }
+            Interaction::Hovered => *bg = BackgroundColor(theme::DIALOG_CHOICE_HOVER),
+            Interaction::None => *bg = BackgroundColor(theme::BUTTON_BG),
+        }
+    }
+}
+
+pub fn handle_copy_delete_buttons(
+    copy_q: Query<(&Interaction, &SlotCopyButton), Changed<Interaction>>,
+    delete_q: Query<(&Interaction, &SlotDeleteButton), Changed<Interaction>>,
+    index: Res<SlotIndex>,
+    mut confirm: ResMut<PendingConfirm>,
+    mut requests: MessageWriter<SlotCommand>,
+) {
+    for (_, button) in copy_q.iter().filter(|(i, _)| **i == Interaction::Pressed) {
+        if let Some(to) = index.first_free() {
+            requests.write(SlotCommand::Copy { from: button.0, to });
+        }
+    }
+
+    for (_, button) in delete_q.iter().filter(|(i, _)| **i == Interaction::Pressed) {
+        let slot = button.0;
+        if confirm.0 == Some((slot, ConfirmAction::Delete)) {
+            confirm.0 = None;
+            requests.write(SlotCommand::Delete(slot));
+        } else {
+            confirm.0 = Some((slot, ConfirmAction::Delete));
+        }
+    }
+}
+
+pub fn handle_back_button(
+    interaction_q: Query<&Interaction, (Changed<Interaction>, With<SlotPickerBackButton>)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    interaction_q
+        .iter()
+        .filter(|i| **i == Interaction::Pressed)
+        .for_each(|_| next_state.set(GameState::MainMenu));
+}
+
+pub struct SlotPickerScreen;
+
+impl crate::screen::ScreenSetup for SlotPickerScreen {
+    fn register(app: &mut bevy::prelude::App) {
+        use bevy::prelude::*;
+        use models::game_states::GameState;
+        app.init_resource::<SlotPickerMode>()
+            .init_resource::<PendingConfirm>()
+            .add_systems(OnEnter(GameState::SlotPicker), setup)
+            .add_systems(OnExit(GameState::SlotPicker), teardown)
+            .add_systems(
+                Update,
+                (
+                    rebuild_slot_list,
+                    handle_slot_buttons,
+                    handle_copy_delete_buttons,
+                    handle_back_button,
+                )
+                    .run_if(in_state(GameState::SlotPicker)),
+            );
+    }
+}